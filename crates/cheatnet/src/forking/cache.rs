@@ -1,81 +1,94 @@
 use anyhow::{Context, Result};
 use camino::{Utf8Path, Utf8PathBuf};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use foundry_ui::UI;
+use foundry_ui::components::warning::WarningMessage;
 use fs2::FileExt;
 use regex::Regex;
 use runtime::starknet::context::SerializableBlockInfo;
 use serde::{Deserialize, Serialize};
 use starknet::core::types::ContractClass;
 use starknet_api::block::{BlockInfo, BlockNumber};
-use starknet_api::core::{ClassHash, ContractAddress, Nonce};
+use starknet_api::core::{ChainId, ClassHash, ContractAddress, Nonce};
 use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Seek, Write};
-use std::string::ToString;
 use url::Url;
 
-#[must_use]
-pub fn cache_version() -> String {
-    env!("CARGO_PKG_VERSION").replace('.', "_")
-}
+/// Version of the fork cache file format.
+/// Cache files in other formats are discarded, and caches saved as JSON by older versions of `snforge` are migrated.
+pub const FORK_CACHE_VERSION: u32 = 1;
+
+const CACHE_FILE_MAGIC: &[u8; 4] = b"SNFC";
+const CACHE_FILE_HEADER_LEN: usize = 8;
+/// Number of records after which the cache file is compacted into a single record
+const MAX_CACHE_FILE_RECORDS: usize = 64;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ForkCacheContent {
-    cache_version: String,
-    storage_at: HashMap<ContractAddress, HashMap<StorageKey, Felt>>,
-    nonce_at: HashMap<ContractAddress, Nonce>,
-    class_hash_at: HashMap<ContractAddress, ClassHash>,
-    compiled_contract_class: HashMap<ClassHash, ContractClass>,
-    block_info: Option<SerializableBlockInfo>,
+    pub(crate) storage_at: HashMap<ContractAddress, HashMap<StorageKey, Felt>>,
+    pub(crate) nonce_at: HashMap<ContractAddress, Nonce>,
+    pub(crate) class_hash_at: HashMap<ContractAddress, ClassHash>,
+    pub(crate) compiled_contract_class: HashMap<ClassHash, ContractClass>,
+    pub(crate) block_info: Option<SerializableBlockInfo>,
+    pub(crate) chain_id: Option<ChainId>,
 }
 
-impl Default for ForkCacheContent {
-    fn default() -> Self {
-        Self {
-            cache_version: cache_version(),
-            storage_at: HashMap::default(),
-            nonce_at: HashMap::default(),
-            class_hash_at: HashMap::default(),
-            compiled_contract_class: HashMap::default(),
-            block_info: Option::default(),
+impl ForkCacheContent {
+    /// Loads the content of the cache of the fork, `None` if the fork wasn't cached yet
+    pub fn load(
+        url: &Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+    ) -> Result<Option<Self>> {
+        let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
+        if !cache_file.exists() {
+            return Ok(None);
         }
+
+        let mut file = File::open(&cache_file).context("Could not open cache file")?;
+        fs2::FileExt::lock_shared(&file).context("Could not lock on cache file")?;
+
+        let mut bytes = Vec::new();
+        let read_result = file.read_to_end(&mut bytes);
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+        read_result.context("Could not read cache file")?;
+
+        Ok(CacheFileContent::decode(&bytes).map(|decoded| decoded.content))
     }
-}
 
-impl ForkCacheContent {
-    fn from_str(serialized: &str) -> Self {
-        let cache: Self =
-            serde_json::from_str(serialized).expect("Could not deserialize cache from json");
-
-        assert_eq!(
-            cache.cache_version,
-            cache_version(),
-            "Expected the Version {}",
-            cache_version()
-        );
-
-        cache
+    fn is_empty(&self) -> bool {
+        self.storage_at.is_empty()
+            && self.nonce_at.is_empty()
+            && self.class_hash_at.is_empty()
+            && self.compiled_contract_class.is_empty()
+            && self.block_info.is_none()
+            && self.chain_id.is_none()
     }
 
-    fn extend(&mut self, other: &Self) {
+    fn extend(&mut self, other: Self) {
         // storage_at
-        for (other_contract_address, other_storage) in &other.storage_at {
-            if let Some(self_contract_storage) = self.storage_at.get_mut(other_contract_address) {
-                self_contract_storage.extend(other_storage.clone());
-            } else {
-                self.storage_at
-                    .insert(*other_contract_address, other_storage.clone());
-            }
+        for (other_contract_address, other_storage) in other.storage_at {
+            self.storage_at
+                .entry(other_contract_address)
+                .or_default()
+                .extend(other_storage);
         }
 
-        self.nonce_at.extend(other.nonce_at.clone());
-        self.class_hash_at.extend(other.class_hash_at.clone());
+        self.nonce_at.extend(other.nonce_at);
+        self.class_hash_at.extend(other.class_hash_at);
         self.compiled_contract_class
-            .extend(other.compiled_contract_class.clone());
+            .extend(other.compiled_contract_class);
         if other.block_info.is_some() {
-            self.block_info.clone_from(&other.block_info);
+            self.block_info = other.block_info;
+        }
+        if other.chain_id.is_some() {
+            self.chain_id = other.chain_id;
         }
     }
 
@@ -84,22 +97,103 @@ impl ForkCacheContent {
     }
 }
 
-#[expect(clippy::to_string_trait_impl)]
-impl ToString for ForkCacheContent {
-    fn to_string(&self) -> String {
-        serde_json::to_string(self).expect("Could not serialize json cache")
+/// Content of the cache file, which consists of a header with the format version
+/// followed by records with gzip compressed content, each prefixed with its length.
+/// Records are appended by every run reading new values from the fork and merged in order.
+///
+/// Records are neither keyed nor indexed, so creating a fork decompresses and parses the whole file.
+/// This is what the fork state needs anyway, as all cached values are kept in memory for the whole test,
+/// and compaction keeps the number of records low. Reading single values from the file without loading it
+/// would require a custom indexed format, which is not worth it for caches of the sizes seen in practice.
+struct CacheFileContent {
+    content: ForkCacheContent,
+    records: usize,
+    /// Whether all records were read, the last one can be cut off by an interrupted write
+    is_complete: bool,
+}
+
+impl CacheFileContent {
+    /// Decodes the cache file, `None` if it is empty or in a different format
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let (header, mut rest) = bytes.split_at_checked(CACHE_FILE_HEADER_LEN)?;
+        if header != cache_file_header() {
+            return None;
+        }
+
+        let mut content = ForkCacheContent::default();
+        let mut records = 0;
+        while !rest.is_empty() {
+            let Some(record) = decode_record(&mut rest) else {
+                return Some(Self {
+                    content,
+                    records,
+                    is_complete: false,
+                });
+            };
+            content.extend(record);
+            records += 1;
+        }
+
+        Some(Self {
+            content,
+            records,
+            is_complete: true,
+        })
+    }
+
+    fn needs_compaction(&self) -> bool {
+        !self.is_complete || self.records > MAX_CACHE_FILE_RECORDS
     }
 }
 
+fn cache_file_header() -> [u8; CACHE_FILE_HEADER_LEN] {
+    let mut header = [0; CACHE_FILE_HEADER_LEN];
+    header[..4].copy_from_slice(CACHE_FILE_MAGIC);
+    header[4..].copy_from_slice(&FORK_CACHE_VERSION.to_le_bytes());
+    header
+}
+
+fn decode_record(rest: &mut &[u8]) -> Option<ForkCacheContent> {
+    let bytes = *rest;
+    let (len, tail) = bytes.split_first_chunk::<4>()?;
+    let len = usize::try_from(u32::from_le_bytes(*len)).ok()?;
+    let (record, tail) = tail.split_at_checked(len)?;
+    *rest = tail;
+
+    serde_json::from_reader(GzDecoder::new(record)).ok()
+}
+
+fn encode_record(content: &ForkCacheContent) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, content).context("Could not serialize cache")?;
+    let compressed = encoder.finish().context("Could not compress cache")?;
+
+    let len = u32::try_from(compressed.len()).context("Cache record is too large")?;
+    let mut record = len.to_le_bytes().to_vec();
+    record.extend(compressed);
+    Ok(record)
+}
+
 #[derive(Debug)]
 pub struct ForkCache {
     fork_cache_content: ForkCacheContent,
+    /// Content read from the fork since the cache was loaded, appended to the cache file on drop
+    new_content: ForkCacheContent,
     cache_file: Utf8PathBuf,
 }
 
 impl Drop for ForkCache {
     fn drop(&mut self) {
-        self.save();
+        if self.new_content.is_empty() {
+            return;
+        }
+        // Failing to save the cache only makes later runs fetch the values from the fork again
+        if let Err(err) = self.save() {
+            UI::default().eprintln(&WarningMessage::new(&format!(
+                "Failed to save fork cache to {}: {err:#}",
+                self.cache_file
+            )));
+        }
     }
 }
 
@@ -130,51 +224,70 @@ impl ForkCache {
             .open(&cache_file)
             .context("Could not open cache file")?;
 
-        let mut cache_file_content = String::new();
-        file.read_to_string(&mut cache_file_content)
+        file.lock_exclusive()
+            .context("Could not lock on cache file")?;
+
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)
             .context("Could not read cache file")?;
 
-        // File was just created
-        let fork_cache_content = if cache_file_content.is_empty() {
-            ForkCacheContent::default()
-        } else {
-            ForkCacheContent::from_str(cache_file_content.as_str())
+        let fork_cache_content = match CacheFileContent::decode(&bytes) {
+            Some(decoded) if !decoded.needs_compaction() => decoded.content,
+            Some(decoded) => {
+                rewrite_cache_file(&mut file, &decoded.content)?;
+                decoded.content
+            }
+            // File was just created or is in a different format
+            None => {
+                let content = migrate_legacy_caches(url, block_number, cache_dir)?;
+                rewrite_cache_file(&mut file, &content)?;
+                content
+            }
         };
 
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
+
         Ok(ForkCache {
             fork_cache_content,
+            new_content: ForkCacheContent::default(),
             cache_file,
         })
     }
 
-    fn save(&self) {
+    fn save(&self) -> Result<()> {
         let mut file = OpenOptions::new()
-            .write(true)
-            .read(true)
+            .append(true)
             .create(true)
-            .truncate(false)
             .open(&self.cache_file)
-            .unwrap();
-
-        file.lock_exclusive().expect("Could not lock on cache file");
+            .context("Could not open cache file")?;
 
-        let mut cache_file_content = String::new();
-        file.read_to_string(&mut cache_file_content)
-            .expect("Should have been able to read the cache");
+        file.lock_exclusive()
+            .context("Could not lock on cache file")?;
+        let write_result = self.append_new_content(&mut file);
+        fs2::FileExt::unlock(&file).context("Could not unlock cache file")?;
 
-        let output = if cache_file_content.is_empty() {
-            self.fork_cache_content.to_string()
-        } else {
-            let mut fs_fork_cache_content = ForkCacheContent::from_str(&cache_file_content);
-            fs_fork_cache_content.extend(&self.fork_cache_content);
-            fs_fork_cache_content.to_string()
-        };
+        write_result
+    }
 
-        file.clear().expect("Failed to clear file");
-        file.write_all(output.as_bytes())
-            .expect("Could not write cache to file");
+    fn append_new_content(&self, file: &mut File) -> Result<()> {
+        let cache_file_len = file
+            .metadata()
+            .context("Could not read cache file metadata")?
+            .len();
+        // Cache file was removed after the cache was loaded
+        if cache_file_len == 0 {
+            file.write_all(&cache_file_header())
+                .context("Could not write cache to file")?;
+        }
+        let record = encode_record(&self.new_content)?;
+        file.write_all(&record)
+            .context("Could not write cache to file")
+    }
 
-        fs2::FileExt::unlock(&file).unwrap();
+    /// Adds the content to the cache, it's saved to the cache file once the cache is dropped
+    pub(crate) fn extend(&mut self, content: ForkCacheContent) {
+        self.fork_cache_content.extend(content.clone());
+        self.new_content.extend(content);
     }
 
     pub(crate) fn get_storage_at(
@@ -195,6 +308,11 @@ impl ForkCache {
         key: StorageKey,
         value: Felt,
     ) {
+        self.new_content
+            .storage_at
+            .entry(contract_address)
+            .or_default()
+            .insert(key, value);
         self.fork_cache_content
             .storage_at
             .entry(contract_address)
@@ -207,6 +325,7 @@ impl ForkCache {
     }
 
     pub(crate) fn cache_get_nonce_at(&mut self, contract_address: ContractAddress, nonce: Nonce) {
+        self.new_content.nonce_at.insert(contract_address, nonce);
         self.fork_cache_content
             .nonce_at
             .insert(contract_address, nonce);
@@ -230,6 +349,9 @@ impl ForkCache {
         contract_address: ContractAddress,
         class_hash: ClassHash,
     ) {
+        self.new_content
+            .class_hash_at
+            .insert(contract_address, class_hash);
         self.fork_cache_content
             .class_hash_at
             .insert(contract_address, class_hash);
@@ -249,6 +371,9 @@ impl ForkCache {
         class_hash: ClassHash,
         contract_class: ContractClass,
     ) -> &ContractClass {
+        self.new_content
+            .compiled_contract_class
+            .insert(class_hash, contract_class.clone());
         self.fork_cache_content
             .compiled_contract_class
             .entry(class_hash)
@@ -260,23 +385,103 @@ impl ForkCache {
     }
 
     pub(crate) fn cache_get_block_info(&mut self, block_info: BlockInfo) {
-        self.fork_cache_content.block_info = Some(block_info.into());
+        let block_info: SerializableBlockInfo = block_info.into();
+        self.new_content.block_info = Some(block_info.clone());
+        self.fork_cache_content.block_info = Some(block_info);
+    }
+
+    pub(crate) fn get_chain_id(&self) -> Option<ChainId> {
+        self.fork_cache_content.chain_id.clone()
+    }
+
+    pub(crate) fn cache_get_chain_id(&mut self, chain_id: ChainId) {
+        self.new_content.chain_id = Some(chain_id.clone());
+        self.fork_cache_content.chain_id = Some(chain_id);
     }
 }
 
-fn cache_file_path_from_fork_config(
+/// Rewrites the cache file with the content saved in a single record
+fn rewrite_cache_file(file: &mut File, content: &ForkCacheContent) -> Result<()> {
+    file.clear()?;
+    file.write_all(&cache_file_header())
+        .context("Could not write cache to file")?;
+    if !content.is_empty() {
+        file.write_all(&encode_record(content)?)
+            .context("Could not write cache to file")?;
+    }
+    Ok(())
+}
+
+/// Migrates the caches of the fork saved by older versions of `snforge`:
+/// - caches saved as JSON (`_v<snforge version>.json` files) are merged, the ones which can't be read are discarded
+/// - caches saved in other versions of the binary format (`_v<format version>.cache` files) are discarded
+///
+/// All of them are removed afterwards, returns the migrated content.
+fn migrate_legacy_caches(
     url: &Url,
-    BlockNumber(block_number): BlockNumber,
+    block_number: BlockNumber,
     cache_dir: &Utf8Path,
-) -> Result<Utf8PathBuf> {
+) -> Result<ForkCacheContent> {
+    let legacy_cache_file_re = Regex::new(&format!(
+        r"^{}_v(?:[0-9_]+\.json|([0-9]+)\.cache)$",
+        regex::escape(&cache_file_stem(url, block_number))
+    ))
+    .unwrap();
+
+    let mut legacy_cache_files = vec![];
+    for entry in fs::read_dir(cache_dir).context("Could not read fork cache directory")? {
+        let path = entry.context("Could not read fork cache directory")?.path();
+        let is_legacy_cache = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .and_then(|file_name| legacy_cache_file_re.captures(file_name))
+            .is_some_and(|captures| {
+                captures
+                    .get(1)
+                    .is_none_or(|version| version.as_str() != FORK_CACHE_VERSION.to_string())
+            });
+        if is_legacy_cache {
+            legacy_cache_files.push(path);
+        }
+    }
+
+    let mut content = ForkCacheContent::default();
+    for legacy_cache_file in legacy_cache_files {
+        if legacy_cache_file
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let legacy_content = fs::read_to_string(&legacy_cache_file)
+                .ok()
+                .and_then(|legacy_content| serde_json::from_str(&legacy_content).ok());
+            if let Some(legacy_content) = legacy_content {
+                content.extend(legacy_content);
+            }
+        }
+        // Legacy caches are never read again, so failing to remove one is harmless
+        let _ = fs::remove_file(&legacy_cache_file);
+    }
+
+    Ok(content)
+}
+
+fn cache_file_stem(url: &Url, BlockNumber(block_number): BlockNumber) -> String {
     let re = Regex::new(r"[^a-zA-Z0-9]").unwrap();
 
     // replace non-alphanumeric characters with underscores
     let sanitized_path = re.replace_all(url.as_str(), "_");
 
+    format!("{sanitized_path}_{block_number}")
+}
+
+fn cache_file_path_from_fork_config(
+    url: &Url,
+    block_number: BlockNumber,
+    cache_dir: &Utf8Path,
+) -> Result<Utf8PathBuf> {
     let cache_file_path = cache_dir.join(format!(
-        "{sanitized_path}_{block_number}_v{}.json",
-        cache_version()
+        "{}_v{FORK_CACHE_VERSION}.cache",
+        cache_file_stem(url, block_number)
     ));
 
     fs::create_dir_all(cache_file_path.parent().unwrap())
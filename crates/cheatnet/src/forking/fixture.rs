@@ -0,0 +1,132 @@
+use crate::forking::cache::{ForkCache, ForkCacheContent};
+use crate::forking::state::ForkStateReader;
+use anyhow::{Context, Result, anyhow, bail, ensure};
+use camino::Utf8Path;
+use runtime::starknet::context::SerializableBlockInfo;
+use serde::{Deserialize, Serialize};
+use starknet::core::types::ContractClass;
+use starknet_api::block::BlockNumber;
+use starknet_api::core::{ChainId, ClassHash, ContractAddress, Nonce};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::collections::BTreeMap;
+use std::fs;
+use url::Url;
+
+/// Version of the fork fixture format.
+/// Unlike the fork cache, fixtures don't depend on the snforge version,
+/// so this is changed only when the format itself changes.
+pub const FORK_FIXTURE_VERSION: u32 = 1;
+
+/// State of the fork read by tests, which can be checked into a repository
+/// and imported into the fork cache to run fork tests without connecting to the node.
+/// Maps are ordered, so exporting the same state always gives the same file.
+/// Fixtures are identified by the chain and block, not the url of the node, which may contain credentials
+/// and differ between the users of the fixture.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ForkFixture {
+    fixture_version: u32,
+    pub chain_id: ChainId,
+    pub block_number: BlockNumber,
+    storage_at: BTreeMap<ContractAddress, BTreeMap<StorageKey, Felt>>,
+    nonce_at: BTreeMap<ContractAddress, Nonce>,
+    class_hash_at: BTreeMap<ContractAddress, ClassHash>,
+    compiled_contract_class: BTreeMap<ClassHash, ContractClass>,
+    block_info: Option<SerializableBlockInfo>,
+}
+
+#[derive(Deserialize)]
+struct FixtureVersion {
+    fixture_version: u32,
+}
+
+impl ForkFixture {
+    /// Creates a fixture from the fork cache of the fork with given url and block number.
+    /// Fetches the chain id from the node if tests didn't read it.
+    pub fn export(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+        let mut content =
+            ForkCacheContent::load(&url, block_number, cache_dir)?.ok_or_else(|| {
+                anyhow!(
+                    "Fork cache for url = {url} and block number = {} not found, run fork tests to create it",
+                    block_number.0
+                )
+            })?;
+        let chain_id = match content.chain_id.take() {
+            Some(chain_id) => chain_id,
+            None => ForkStateReader::new(url, block_number, cache_dir)?
+                .chain_id()
+                .context("Failed to get the chain id of the fork")?,
+        };
+
+        Ok(Self {
+            fixture_version: FORK_FIXTURE_VERSION,
+            chain_id,
+            block_number,
+            storage_at: content
+                .storage_at
+                .into_iter()
+                .map(|(contract_address, storage)| {
+                    (contract_address, storage.into_iter().collect())
+                })
+                .collect(),
+            nonce_at: content.nonce_at.into_iter().collect(),
+            class_hash_at: content.class_hash_at.into_iter().collect(),
+            compiled_contract_class: content.compiled_contract_class.into_iter().collect(),
+            block_info: content.block_info,
+        })
+    }
+
+    /// Adds the state from the fixture to the fork cache of the fork with given url, at the block of the fixture.
+    pub fn import(self, url: &Url, cache_dir: &Utf8Path) -> Result<()> {
+        let mut cache = ForkCache::load_or_new(url, self.block_number, cache_dir)
+            .context("Could not create fork cache")?;
+
+        if let Some(chain_id) = cache.get_chain_id() {
+            if chain_id != self.chain_id {
+                bail!(
+                    "Fork fixture is for chain {}, but the fork with url = {url} is on chain {chain_id}",
+                    self.chain_id
+                );
+            }
+        }
+
+        cache.extend(ForkCacheContent {
+            storage_at: self
+                .storage_at
+                .into_iter()
+                .map(|(contract_address, storage)| {
+                    (contract_address, storage.into_iter().collect())
+                })
+                .collect(),
+            nonce_at: self.nonce_at.into_iter().collect(),
+            class_hash_at: self.class_hash_at.into_iter().collect(),
+            compiled_contract_class: self.compiled_contract_class.into_iter().collect(),
+            block_info: self.block_info,
+            chain_id: Some(self.chain_id),
+        });
+
+        Ok(())
+    }
+
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read fork fixture from {path}"))?;
+
+        let FixtureVersion { fixture_version } = serde_json::from_str(&content)
+            .with_context(|| format!("File {path} is not a fork fixture"))?;
+        ensure!(
+            fixture_version == FORK_FIXTURE_VERSION,
+            "Fork fixture {path} has unsupported version {fixture_version}, expected version {FORK_FIXTURE_VERSION}"
+        );
+
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to deserialize fork fixture from {path}"))
+    }
+
+    pub fn save(&self, path: &Utf8Path) -> Result<()> {
+        let content =
+            serde_json::to_string_pretty(self).context("Failed to serialize fork fixture")?;
+
+        fs::write(path, content).with_context(|| format!("Failed to write fork fixture to {path}"))
+    }
+}
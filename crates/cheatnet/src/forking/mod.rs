@@ -1,3 +1,4 @@
 pub mod cache;
 pub mod data;
+pub mod fixture;
 pub mod state;
@@ -10,6 +10,7 @@ use blockifier::state::state_api::{StateReader, StateResult};
 use cairo_lang_utils::bigint::BigUintAsHex;
 use cairo_vm::types::program::Program;
 use camino::Utf8Path;
+use conversions::string::IntoHexStr;
 use conversions::{FromConv, IntoConv};
 use flate2::read::GzDecoder;
 use num_bigint::BigUint;
@@ -33,7 +34,8 @@ use url::Url;
 
 #[derive(Debug)]
 pub struct ForkStateReader {
-    client: SyncClient,
+    /// Client used for fetching values missing in the cache, `None` in offline mode
+    client: Option<SyncClient>,
     cache: RefCell<ForkCache>,
 }
 
@@ -44,14 +46,40 @@ impl ForkStateReader {
                 ForkCache::load_or_new(&url, block_number, cache_dir)
                     .context("Could not create fork cache")?,
             ),
-            client: SyncClient::new(url, block_number),
+            client: Some(SyncClient::new(url, block_number)),
+        })
+    }
+
+    /// Creates a reader which serves the fork state only from the cache, without connecting to the node.
+    pub fn new_offline(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+        Ok(ForkStateReader {
+            cache: RefCell::new(
+                ForkCache::load_or_new(&url, block_number, cache_dir)
+                    .context("Could not create fork cache")?,
+            ),
+            client: None,
         })
     }
 
     pub fn chain_id(&self) -> Result<ChainId> {
-        let id = self.client.chain_id()?;
-        let id = parse_cairo_short_string(&id)?;
-        Ok(ChainId::from(id))
+        if let Some(cache_hit) = self.cache.borrow().get_chain_id() {
+            return Ok(cache_hit);
+        }
+
+        let id = self.client(|| "Chain id".to_string())?.chain_id()?;
+        let id = ChainId::from(parse_cairo_short_string(&id)?);
+        self.cache.borrow_mut().cache_get_chain_id(id.clone());
+        Ok(id)
+    }
+
+    /// Returns the client used for fetching `missing` value, which is not in the cache.
+    fn client(&self, missing: impl FnOnce() -> String) -> StateResult<&SyncClient> {
+        self.client.as_ref().ok_or_else(|| {
+            StateReadError(format!(
+                "{} is missing in the fork cache and can't be fetched in offline mode",
+                missing()
+            ))
+        })
     }
 
     pub fn compiled_contract_class_map(&self) -> Ref<HashMap<ClassHash, ContractClassStarknet>> {
@@ -80,7 +108,10 @@ impl BlockInfoReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.client.get_block_with_tx_hashes() {
+        match self
+            .client(|| "Block info".to_string())?
+            .get_block_with_tx_hashes()
+        {
             Ok(MaybePendingBlockWithTxHashes::Block(block)) => {
                 let block_info = BlockInfo {
                     block_number: BlockNumber(block.block_number),
@@ -118,7 +149,13 @@ impl StateReader for ForkStateReader {
         }
 
         match self
-            .client
+            .client(|| {
+                format!(
+                    "Storage of contract {} at key {}",
+                    contract_address.into_hex_string(),
+                    key.0.key().to_hex_string()
+                )
+            })?
             .get_storage_at(Felt::from_(contract_address), Felt::from_(*key.0.key()))
         {
             Ok(value) => {
@@ -148,7 +185,10 @@ impl StateReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.client.get_nonce(Felt::from_(contract_address)) {
+        match self
+            .client(|| format!("Nonce of contract {}", contract_address.into_hex_string()))?
+            .get_nonce(Felt::from_(contract_address))
+        {
             Ok(nonce) => {
                 let nonce = nonce.into_();
                 self.cache
@@ -174,7 +214,15 @@ impl StateReader for ForkStateReader {
             return Ok(cache_hit);
         }
 
-        match self.client.get_class_hash_at(Felt::from_(contract_address)) {
+        match self
+            .client(|| {
+                format!(
+                    "Class hash of contract {}",
+                    contract_address.into_hex_string()
+                )
+            })?
+            .get_class_hash_at(Felt::from_(contract_address))
+        {
             Ok(class_hash) => {
                 let class_hash = class_hash.into_();
                 self.cache
@@ -202,7 +250,10 @@ impl StateReader for ForkStateReader {
             if let Some(cache_hit) = cache.get_compiled_contract_class(&class_hash) {
                 Ok(cache_hit)
             } else {
-                match self.client.get_class(Felt::from_(class_hash)) {
+                match self
+                    .client(|| format!("Class {}", class_hash.into_hex_string()))?
+                    .get_class(Felt::from_(class_hash))
+                {
                     Ok(contract_class) => {
                         Ok(cache.insert_compiled_contract_class(class_hash, contract_class))
                     }
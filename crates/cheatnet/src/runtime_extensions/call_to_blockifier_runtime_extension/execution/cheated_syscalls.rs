@@ -267,9 +267,13 @@ pub fn storage_read(
             syscall_handler.base.call.storage_address = original_storage_address;
         })?;
 
+    cheatnet_state.record_storage_read(syscall_handler.base.call.storage_address, request.address);
+
     // Restore the original storage_address
     syscall_handler.base.call.storage_address = original_storage_address;
 
+    cheatnet_state.record_seen_storage_value(value);
+
     Ok(StorageReadResponse { value })
 }
 
@@ -292,9 +296,13 @@ pub fn storage_write(
             syscall_handler.base.call.storage_address = original_storage_address;
         })?;
 
+    cheatnet_state.record_storage_write(syscall_handler.base.call.storage_address, request.address);
+
     // Restore the original storage_address
     syscall_handler.base.call.storage_address = original_storage_address;
 
+    cheatnet_state.record_seen_storage_value(request.value);
+
     Ok(StorageWriteResponse {})
 }
 
@@ -3,7 +3,7 @@ use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{AddressOrClassHash, CallResult};
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::CheatnetState;
 use crate::runtime_extensions::common::{get_relocated_vm_trace, get_syscalls_gas_consumed, sum_syscall_usage};
-use crate::state::CheatStatus;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::MockResult;
 use blockifier::execution::call_info::{CallExecution, Retdata, StorageAccessTracker};
 use crate::runtime_extensions::forge_runtime_extension::{get_nested_calls_syscalls_sierra_gas, get_nested_calls_syscalls_vm_resources};
 use blockifier::execution::contract_class::{RunnableCompiledClass, TrackedResource};
@@ -29,7 +29,6 @@ use blockifier::{
 };
 use cairo_vm::vm::runners::cairo_runner::{CairoRunner, ExecutionResources};
 use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
-use conversions::FromConv;
 use conversions::string::TryFromHexStr;
 use shared::vm::VirtualMachineExt;
 use starknet_api::{
@@ -79,33 +78,32 @@ pub fn execute_call_entry_point(
         .trace_data
         .enter_nested_call(entry_point.clone(), cheated_data);
 
-    if let Some(cheat_status) = get_mocked_function_cheat_status(entry_point, cheatnet_state) {
-        if let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone() {
-            cheat_status.decrement_cheat_span();
-            let ret_data_f252: Vec<Felt> =
-                ret_data.iter().map(|datum| Felt::from_(*datum)).collect();
-            cheatnet_state.trace_data.exit_nested_call(
-                ExecutionResources::default(),
-                u64::default(),
-                SyscallUsageMap::default(),
-                SyscallUsageMap::default(),
-                CallResult::Success {
-                    ret_data: ret_data_f252,
-                },
-                &[],
-                None,
-            );
-            let tracked_resource = *context
-                .tracked_resource_stack
-                .last()
-                .expect("Unexpected empty tracked resource.");
-
-            return Ok(mocked_call_info(
-                entry_point.clone(),
-                ret_data.clone(),
-                tracked_resource,
-            ));
+    if let Some(mock_result) = get_mocked_call_result(entry_point, cheatnet_state) {
+        let tracked_resource = *context
+            .tracked_resource_stack
+            .last()
+            .expect("Unexpected empty tracked resource.");
+        let call_info = mocked_call_info(entry_point.clone(), mock_result, tracked_resource);
+
+        cheatnet_state.trace_data.exit_nested_call(
+            ExecutionResources::default(),
+            u64::default(),
+            SyscallUsageMap::default(),
+            SyscallUsageMap::default(),
+            CallResult::from_non_error(&call_info),
+            &[],
+            None,
+        );
+
+        if call_info.execution.failed && !is_revertable {
+            return Err(EntryPointExecutionError::ExecutionFailed {
+                error_trace: extract_trailing_cairo1_revert_trace(
+                    &call_info,
+                    Cairo1RevertHeader::Execution,
+                ),
+            });
         }
+        return Ok(call_info);
     }
     // endregion
 
@@ -408,36 +406,42 @@ pub fn execute_constructor_entry_point(
     // endregion
 }
 
-fn get_mocked_function_cheat_status<'a>(
+fn get_mocked_call_result(
     call: &CallEntryPoint,
-    cheatnet_state: &'a mut CheatnetState,
-) -> Option<&'a mut CheatStatus<Vec<Felt>>> {
+    cheatnet_state: &mut CheatnetState,
+) -> Option<MockResult> {
     if call.call_type == CallType::Delegate {
         return None;
     }
 
-    cheatnet_state
-        .mocked_functions
-        .get_mut(&call.storage_address)
-        .and_then(|contract_functions| contract_functions.get_mut(&call.entry_point_selector))
+    cheatnet_state.get_mocked_call_result(
+        call.storage_address,
+        call.entry_point_selector,
+        &call.calldata.0,
+    )
 }
 
 fn mocked_call_info(
     call: CallEntryPoint,
-    ret_data: Vec<Felt>,
+    mock_result: MockResult,
     tracked_resource: TrackedResource,
 ) -> CallInfo {
+    let (retdata, failed) = match mock_result {
+        MockResult::Success(ret_data) => (ret_data, false),
+        MockResult::Panic(panic_data) => (panic_data, true),
+    };
+
     CallInfo {
         call: CallEntryPoint {
             class_hash: Some(call.class_hash.unwrap_or_default()),
             ..call
         },
         execution: CallExecution {
-            retdata: Retdata(ret_data),
+            retdata: Retdata(retdata),
             events: vec![],
             l2_to_l1_messages: vec![],
             cairo_native: false,
-            failed: false,
+            failed,
             gas_consumed: 0,
         },
         resources: ExecutionResources::default(),
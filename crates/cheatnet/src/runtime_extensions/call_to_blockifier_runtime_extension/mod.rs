@@ -1,5 +1,3 @@
-use std::marker::PhantomData;
-
 use blockifier::execution::entry_point::{CallEntryPoint, CallType};
 use blockifier::execution::execution_utils::felt_from_ptr;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
@@ -11,16 +9,23 @@ use blockifier::execution::{
     execution_utils::ReadOnlySegment,
     syscalls::vm_syscall_utils::{SyscallRequest, SyscallResponse, SyscallResponseWrapper},
 };
+use blockifier::state::state_api::StateReader;
 use cairo_vm::types::relocatable::MaybeRelocatable;
 use cairo_vm::vm::{errors::hint_errors::HintError, vm_core::VirtualMachine};
 use runtime::{ExtendedRuntime, ExtensionLogic, SyscallHandlingResult, SyscallPtrAccess};
 use starknet_api::contract_class::EntryPointType;
-use starknet_api::core::ContractAddress;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
 
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::panic_data::{
+    failure_panic_data, format_panic_data, is_matching_panic_data,
+};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect_revert::value_returning_entry_point;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use crate::state::CheatnetState;
 
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
-    AddressOrClassHash, call_entry_point,
+    AddressOrClassHash, call_contract, call_entry_point,
 };
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::{
     execution::cheated_syscalls::SingleSegmentResponse,
@@ -36,7 +41,7 @@ pub mod panic_data;
 pub mod rpc;
 
 pub struct CallToBlockifierExtension<'a> {
-    pub lifetime: &'a PhantomData<()>,
+    pub contracts_data: &'a ContractsData,
 }
 
 pub type CallToBlockifierRuntime<'a> = ExtendedRuntime<CallToBlockifierExtension<'a>>;
@@ -57,7 +62,7 @@ impl<'a> ExtensionLogic for CallToBlockifierExtension<'a> {
             // since call errors cannot be handled on real starknet
             // https://docs.starknet.io/architecture-and-concepts/smart-contracts/system-calls-cairo1/#call_contract
             SyscallSelector::CallContract => {
-                execute_syscall::<CallContractRequest>(vm, extended_runtime)?;
+                execute_syscall::<CallContractRequest>(vm, extended_runtime, self.contracts_data)?;
 
                 extended_runtime
                     .extended_runtime
@@ -67,7 +72,7 @@ impl<'a> ExtensionLogic for CallToBlockifierExtension<'a> {
                 Ok(SyscallHandlingResult::Handled)
             }
             SyscallSelector::LibraryCall => {
-                execute_syscall::<LibraryCallRequest>(vm, extended_runtime)?;
+                execute_syscall::<LibraryCallRequest>(vm, extended_runtime, self.contracts_data)?;
 
                 extended_runtime
                     .extended_runtime
@@ -90,6 +95,12 @@ where
         syscall_handler: &mut SyscallHintProcessor,
         cheatnet_state: &mut CheatnetState,
     ) -> CallResult;
+
+    /// Class hash of the called contract and the called entry point
+    fn called_entry_point(
+        &self,
+        syscall_handler: &mut SyscallHintProcessor,
+    ) -> Option<(ClassHash, EntryPointSelector)>;
 }
 
 impl ExecuteCall for CallContractRequest {
@@ -98,27 +109,27 @@ impl ExecuteCall for CallContractRequest {
         syscall_handler: &mut SyscallHintProcessor,
         cheatnet_state: &mut CheatnetState,
     ) -> CallResult {
-        let contract_address = self.contract_address;
-
-        let entry_point = CallEntryPoint {
-            class_hash: None,
-            code_address: Some(contract_address),
-            entry_point_type: EntryPointType::External,
-            entry_point_selector: self.function_selector,
-            calldata: self.calldata,
-            storage_address: contract_address,
-            caller_address: TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap(),
-            call_type: CallType::Call,
-            initial_gas: i64::MAX as u64,
-        };
-
-        call_entry_point(
+        call_contract(
             syscall_handler,
             cheatnet_state,
-            entry_point,
-            &AddressOrClassHash::ContractAddress(contract_address),
+            &self.contract_address,
+            self.function_selector,
+            &self.calldata.0,
         )
     }
+
+    fn called_entry_point(
+        &self,
+        syscall_handler: &mut SyscallHintProcessor,
+    ) -> Option<(ClassHash, EntryPointSelector)> {
+        let class_hash = syscall_handler
+            .base
+            .state
+            .get_class_hash_at(self.contract_address)
+            .ok()?;
+
+        Some((class_hash, self.function_selector))
+    }
 }
 
 impl ExecuteCall for LibraryCallRequest {
@@ -148,11 +159,19 @@ impl ExecuteCall for LibraryCallRequest {
             &AddressOrClassHash::ClassHash(class_hash),
         )
     }
+
+    fn called_entry_point(
+        &self,
+        _syscall_handler: &mut SyscallHintProcessor,
+    ) -> Option<(ClassHash, EntryPointSelector)> {
+        Some((self.class_hash, self.function_selector))
+    }
 }
 
 fn execute_syscall<Request: ExecuteCall + SyscallRequest>(
     vm: &mut VirtualMachine,
     cheatable_starknet_runtime: &mut CheatableStarknetRuntime,
+    contracts_data: &ContractsData,
 ) -> Result<(), HintError> {
     let _selector = felt_from_ptr(vm, cheatable_starknet_runtime.get_mut_syscall_ptr())?;
 
@@ -167,11 +186,56 @@ fn execute_syscall<Request: ExecuteCall + SyscallRequest>(
     let cheatnet_state = &mut *cheatable_starknet_runtime.extension.cheatnet_state;
     let syscall_handler = &mut cheatable_starknet_runtime.extended_runtime.hint_handler;
 
-    let call_result = request.execute_call(syscall_handler, cheatnet_state);
+    if cheatnet_state.expected_revert.is_some() {
+        let value_returning_function = request.called_entry_point(syscall_handler).and_then(
+            |(class_hash, entry_point_selector)| {
+                value_returning_entry_point(contracts_data, &class_hash, entry_point_selector)
+            },
+        );
+        if let Some(function_name) = value_returning_function {
+            return Err(HintError::CustomHint(Box::from(format!(
+                "`expect_revert` can't be used for the call to `{function_name}`, as it returns a value which can't be decoded after the call reverts. Use a safe dispatcher to check the revert instead"
+            ))));
+        }
+    }
+
+    let mut call_result = request.execute_call(syscall_handler, cheatnet_state);
+    if let Some(expected_panic_data) = cheatnet_state.expected_revert.take() {
+        call_result = apply_expected_revert(call_result, &expected_panic_data)?;
+    }
+
     write_call_response(syscall_handler, vm, gas_counter, call_result)?;
     Ok(())
 }
 
+/// Checks the call against the revert expected with `expect_revert`.
+/// A call reverted with the expected panic data succeeds with no return data, any other result fails the test.
+fn apply_expected_revert(
+    call_result: CallResult,
+    expected_panic_data: &[Felt],
+) -> Result<CallResult, HintError> {
+    let expected = format_panic_data(expected_panic_data);
+
+    let msg = match &call_result {
+        CallResult::Success { .. } => {
+            format!("Expected the call to revert with panic data {expected}, but it succeeded")
+        }
+        CallResult::Failure(failure) => match failure_panic_data(failure) {
+            Some(panic_data) if is_matching_panic_data(&panic_data, expected_panic_data) => {
+                return Ok(CallResult::Success { ret_data: vec![] });
+            }
+            Some(panic_data) => format!(
+                "Expected the call to revert with panic data {expected}, but it reverted with panic data {}",
+                format_panic_data(&panic_data)
+            ),
+            // Errors fail the test regardless of the expectation
+            None => return Ok(call_result),
+        },
+    };
+
+    Err(HintError::CustomHint(Box::from(msg)))
+}
+
 fn write_call_response(
     syscall_handler: &mut SyscallHintProcessor<'_>,
     vm: &mut VirtualMachine,
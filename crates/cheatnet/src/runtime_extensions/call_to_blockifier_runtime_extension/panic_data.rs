@@ -1,3 +1,4 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallFailure;
 use conversions::byte_array::ByteArray;
 use regex::Regex;
 use starknet_types_core::felt::Felt;
@@ -59,6 +60,39 @@ pub fn try_extract_panic_data(err: &str) -> Option<Vec<Felt>> {
     None
 }
 
+/// Returns the panic data of the failed call, extracting it from the error message
+/// if the call failed in a nested contract.
+#[must_use]
+pub fn failure_panic_data(failure: &CallFailure) -> Option<Vec<Felt>> {
+    match failure {
+        CallFailure::Panic { panic_data } => Some(panic_data.clone()),
+        CallFailure::Error { msg } => try_extract_panic_data(&msg.to_string()),
+    }
+}
+
+/// Checks if the panic data matches the expected one, the same way as for `#[should_panic]`.
+/// If both are `ByteArray`s, the expected one must be a substring, otherwise they must be equal.
+#[must_use]
+pub fn is_matching_panic_data(panic_data: &[Felt], expected: &[Felt]) -> bool {
+    let as_string = |data: &[Felt]| {
+        ByteArray::deserialize_with_magic(data).map(|byte_array| byte_array.to_string())
+    };
+
+    match (as_string(panic_data), as_string(expected)) {
+        (Ok(panic_data), Ok(expected)) => panic_data.contains(&expected),
+        _ => panic_data == expected,
+    }
+}
+
+/// Formats the panic data for messages, showing `ByteArray`s as strings
+#[must_use]
+pub fn format_panic_data(panic_data: &[Felt]) -> String {
+    match ByteArray::deserialize_with_magic(panic_data) {
+        Ok(byte_array) => format!("\"{byte_array}\""),
+        Err(_) => starknet_api::execution_utils::format_panic_data(panic_data),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -162,4 +196,23 @@ mod test {
     fn extracting_string_panic_data(data: &str, expected: Option<Vec<Felt>>) {
         assert_eq!(try_extract_panic_data(data), expected);
     }
+
+    #[test]
+    fn matching_panic_data() {
+        let byte_array = |string: &str| ByteArray::from(string).serialize_with_magic();
+
+        assert!(is_matching_panic_data(&[Felt::from(1)], &[Felt::from(1)]));
+        assert!(!is_matching_panic_data(
+            &[Felt::from(1), Felt::from(2)],
+            &[Felt::from(1)]
+        ));
+        assert!(is_matching_panic_data(
+            &byte_array("long panic message"),
+            &byte_array("panic")
+        ));
+        assert!(!is_matching_panic_data(
+            &byte_array("long panic message"),
+            &byte_array("other")
+        ));
+    }
 }
@@ -16,7 +16,12 @@ use blockifier::execution::{
 };
 use blockifier::state::errors::StateError;
 use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
-use conversions::{byte_array::ByteArray, serde::serialize::CairoSerialize, string::IntoHexStr};
+use conversions::{
+    byte_array::ByteArray,
+    serde::serialize::CairoSerialize,
+    string::{IntoHexStr, TryFromHexStr},
+};
+use runtime::starknet::constants::TEST_ADDRESS;
 use shared::utils::build_readable_text;
 use starknet_api::execution_resources::GasAmount;
 use starknet_api::{
@@ -199,6 +204,35 @@ pub fn call_l1_handler(
     )
 }
 
+pub fn call_contract(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    contract_address: &ContractAddress,
+    entry_point_selector: EntryPointSelector,
+    calldata: &[Felt],
+) -> CallResult {
+    let calldata = create_execute_calldata(calldata);
+
+    let entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(*contract_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector,
+        calldata,
+        storage_address: *contract_address,
+        caller_address: TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap(),
+        call_type: CallType::Call,
+        initial_gas: i64::MAX as u64,
+    };
+
+    call_entry_point(
+        syscall_handler,
+        cheatnet_state,
+        entry_point,
+        &AddressOrClassHash::ContractAddress(*contract_address),
+    )
+}
+
 pub fn call_entry_point(
     syscall_handler: &mut SyscallHintProcessor,
     cheatnet_state: &mut CheatnetState,
@@ -27,6 +27,7 @@ use cairo_vm::{
 use conversions::string::TryFromHexStr;
 use runtime::{ExtendedRuntime, ExtensionLogic, StarknetRuntime, SyscallHandlingResult};
 use starknet_types_core::felt::Felt;
+use std::sync::atomic::Ordering;
 
 pub struct CheatableStarknetRuntimeExtension<'a> {
     pub cheatnet_state: &'a mut CheatnetState,
@@ -127,6 +128,10 @@ impl<'a> ExtensionLogic for CheatableStarknetRuntimeExtension<'a> {
             _ => {}
         }
     }
+
+    fn is_interrupted(&self) -> bool {
+        self.cheatnet_state.timed_out.load(Ordering::Relaxed)
+    }
 }
 
 pub fn felt_from_ptr_immutable(
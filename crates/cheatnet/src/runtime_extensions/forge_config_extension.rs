@@ -33,6 +33,8 @@ impl<'a> ExtensionLogic for ForgeConfigExtension<'a> {
             "set_config_disable_contracts" => config_cheatcode!(disable_predeployed_contracts),
             "set_config_should_panic" => config_cheatcode!(should_panic),
             "set_config_fuzzer" => config_cheatcode!(fuzzer),
+            "set_config_invariant" => config_cheatcode!(invariant),
+            "set_config_timeout" => config_cheatcode!(timeout),
             "is_config_mode" => Ok(CheatcodeHandlingResult::from_serializable(true)),
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         }
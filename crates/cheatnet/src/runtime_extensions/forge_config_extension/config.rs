@@ -145,6 +145,14 @@ pub struct RawFuzzerConfig {
     pub seed: Option<u64>,
 }
 
+// invariant
+
+#[derive(Debug, Clone, CairoDeserialize, PartialEq)]
+pub struct RawInvariantConfig {
+    pub runs: Option<NonZeroU32>,
+    pub seed: Option<u64>,
+}
+
 // should panic
 
 #[derive(Debug, Clone, CairoDeserialize)]
@@ -174,6 +182,13 @@ pub struct RawPredeployedContractsConfig {
     pub is_disabled: bool,
 }
 
+// timeout
+
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct RawTimeoutConfig {
+    pub seconds: u64,
+}
+
 // config
 
 #[derive(Debug, Default, Clone)]
@@ -183,5 +198,7 @@ pub struct RawForgeConfig {
     pub ignore: Option<RawIgnoreConfig>,
     pub should_panic: Option<RawShouldPanicConfig>,
     pub fuzzer: Option<RawFuzzerConfig>,
+    pub invariant: Option<RawInvariantConfig>,
     pub disable_predeployed_contracts: Option<RawPredeployedContractsConfig>,
+    pub timeout: Option<RawTimeoutConfig>,
 }
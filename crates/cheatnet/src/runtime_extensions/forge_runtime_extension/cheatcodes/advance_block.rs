@@ -0,0 +1,54 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    BlockInfoMockOperations, ExecutionInfoMockOperations, Operation,
+};
+use anyhow::{Result, anyhow};
+use starknet::core::crypto::pedersen_hash;
+use starknet_types_core::felt::Felt;
+
+impl CheatnetState {
+    /// Moves the global block number forward by `blocks`, each produced `block_time` seconds after the previous one.
+    /// Starts from the global cheats if they are active, or from the block the test runs in otherwise.
+    pub fn advance_block(&mut self, blocks: u64, block_time: u64) -> Result<()> {
+        let seconds = blocks.checked_mul(block_time).ok_or_else(|| {
+            anyhow!("Block timestamp overflowed after advancing by {blocks} blocks of {block_time} seconds")
+        })?;
+
+        let global_block_info = &self.global_cheated_execution_info.block_info;
+        let block_number = global_block_info
+            .block_number
+            .as_value()
+            .unwrap_or(self.block_info.block_number.0)
+            .checked_add(blocks)
+            .ok_or_else(|| anyhow!("Block number overflowed after advancing by {blocks} blocks"))?;
+        let block_timestamp = global_block_info
+            .block_timestamp
+            .as_value()
+            .unwrap_or(self.block_info.block_timestamp.0)
+            .checked_add(seconds)
+            .ok_or_else(|| {
+                anyhow!("Block timestamp overflowed after advancing by {seconds} seconds")
+            })?;
+
+        self.cheat_execution_info(ExecutionInfoMockOperations {
+            block_info: BlockInfoMockOperations {
+                block_number: Operation::StartGlobal(block_number),
+                block_timestamp: Operation::StartGlobal(block_timestamp),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        self.advanced_block_number = Some(block_number);
+
+        Ok(())
+    }
+}
+
+/// Hash of a block passed with `roll` or `warp`, derived from its number
+#[must_use]
+pub fn advanced_block_hash(block_number: u64) -> Felt {
+    pedersen_hash(
+        &Felt::from_bytes_be_slice(b"SNFORGE_BLOCK_HASH"),
+        &Felt::from(block_number),
+    )
+}
@@ -1,13 +1,16 @@
 use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::advance_block::advanced_block_hash;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
     CheatArguments, Operation,
 };
 use crate::state::CheatSpan;
+use blockifier::abi::constants;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
 use blockifier::execution::syscalls::syscall_base::SyscallResult;
 use starknet_api::block::BlockHash;
 use starknet_api::core::ContractAddress;
 use starknet_api::hash::StarkHash;
+use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
 use std::num::NonZeroUsize;
 
@@ -119,6 +122,25 @@ impl CheatnetState {
             }
         }
 
+        // Range of available block hashes is checked against the block the test runs in,
+        // so hashes of blocks passed with `roll` or `warp` are read from the block hash contract directly.
+        // Passed blocks are never written there, so unless the test stored one, their hash is derived from the number.
+        if let Some(advanced_block_number) = self.advanced_block_number {
+            if block_number >= self.block_info.block_number.0
+                && block_number.saturating_add(constants::STORED_BLOCK_HASH_BUFFER)
+                    <= advanced_block_number
+            {
+                let block_hash = syscall_handler.base.state.get_storage_at(
+                    ContractAddress::from(constants::BLOCK_HASH_CONTRACT_ADDRESS),
+                    StorageKey::from(block_number),
+                )?;
+                if block_hash == Felt::ZERO {
+                    return Ok(BlockHash(advanced_block_hash(block_number)));
+                }
+                return Ok(BlockHash(block_hash));
+            }
+        }
+
         Ok(BlockHash(
             syscall_handler.base.get_block_hash(block_number)?,
         ))
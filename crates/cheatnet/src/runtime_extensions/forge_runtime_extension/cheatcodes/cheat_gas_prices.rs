@@ -0,0 +1,36 @@
+use crate::CheatnetState;
+use anyhow::{Result, anyhow};
+use conversions::serde::deserialize::CairoDeserialize;
+use starknet_api::block::{GasPrice, GasPriceVector, NonzeroGasPrice};
+
+/// Prices of the block gas, in FRI per unit of gas
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct GasPrices {
+    pub l1_gas_price: u128,
+    pub l1_data_gas_price: u128,
+    pub l2_gas_price: u128,
+}
+
+impl CheatnetState {
+    /// Sets the gas prices of the block the test runs in, used for calculating the fee of the test.
+    pub fn cheat_gas_prices(&mut self, gas_prices: GasPrices) -> Result<()> {
+        self.cheated_gas_prices = Some(GasPriceVector {
+            l1_gas_price: nonzero_gas_price(gas_prices.l1_gas_price, "l1_gas_price")?,
+            l1_data_gas_price: nonzero_gas_price(
+                gas_prices.l1_data_gas_price,
+                "l1_data_gas_price",
+            )?,
+            l2_gas_price: nonzero_gas_price(gas_prices.l2_gas_price, "l2_gas_price")?,
+        });
+
+        Ok(())
+    }
+
+    pub fn stop_cheat_gas_prices(&mut self) {
+        self.cheated_gas_prices = None;
+    }
+}
+
+fn nonzero_gas_price(price: u128, name: &str) -> Result<NonzeroGasPrice> {
+    NonzeroGasPrice::new(GasPrice(price)).map_err(|_| anyhow!("`{name}` must be greater than 0"))
+}
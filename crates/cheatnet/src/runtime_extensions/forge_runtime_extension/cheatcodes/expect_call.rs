@@ -0,0 +1,127 @@
+use crate::CheatnetState;
+use crate::state::CallTrace;
+use blockifier::execution::entry_point::CallType;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::string::IntoHexStr;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+use std::fmt::Write;
+
+/// Call which must be made by the test a given number of times
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedCall {
+    pub contract_address: ContractAddress,
+    pub function_selector: EntryPointSelector,
+    pub calldata: Vec<Felt>,
+    pub count: usize,
+}
+
+/// Expected call together with the number of calls made by the test before it was registered,
+/// which are not counted towards the expectation
+#[derive(Clone, Debug)]
+pub struct RegisteredExpectedCall {
+    pub expected_call: ExpectedCall,
+    pub calls_made_before: usize,
+}
+
+impl CheatnetState {
+    pub fn expect_call(&mut self, expected_call: ExpectedCall) {
+        let calls_made_before =
+            count_calls(&self.trace_data.current_call_stack.borrow_full_trace());
+
+        self.expected_calls.push(RegisteredExpectedCall {
+            expected_call,
+            calls_made_before,
+        });
+    }
+}
+
+/// Checks the expected calls against the calls made by the test after registering them.
+/// Returns a message describing every expectation that was not met.
+#[must_use]
+pub fn verify_expected_calls(
+    expected_calls: &[RegisteredExpectedCall],
+    call_trace: &CallTrace,
+) -> Option<String> {
+    if expected_calls.is_empty() {
+        return None;
+    }
+
+    let made_calls = collect_calls(call_trace);
+
+    let mut message = String::new();
+    for RegisteredExpectedCall {
+        expected_call,
+        calls_made_before,
+    } in expected_calls
+    {
+        let calls_to_entry_point = made_calls[*calls_made_before..]
+            .iter()
+            .filter(|(contract_address, function_selector, _)| {
+                *contract_address == expected_call.contract_address
+                    && *function_selector == expected_call.function_selector
+            })
+            .map(|(_, _, calldata)| calldata)
+            .collect::<Vec<_>>();
+        let count = calls_to_entry_point
+            .iter()
+            .filter(|calldata| ***calldata == expected_call.calldata)
+            .count();
+
+        if count == expected_call.count {
+            continue;
+        }
+
+        writeln!(
+            message,
+            "Expected call to contract {} with selector {} was made {count} time(s), expected {}",
+            expected_call.contract_address.into_hex_string(),
+            expected_call.function_selector.into_hex_string(),
+            expected_call.count,
+        )
+        .unwrap();
+        writeln!(message, "- {}", format_calldata(&expected_call.calldata)).unwrap();
+        for calldata in calls_to_entry_point {
+            writeln!(message, "+ {}", format_calldata(calldata)).unwrap();
+        }
+    }
+
+    (!message.is_empty()).then_some(message)
+}
+
+/// Collects address, selector and calldata of all calls nested in the trace, in the order they were made
+fn collect_calls(call_trace: &CallTrace) -> Vec<(ContractAddress, EntryPointSelector, Vec<Felt>)> {
+    let mut made_calls = vec![];
+    call_trace.visit_nested_calls(&mut |nested_call| {
+        if is_call_to_contract(nested_call) {
+            let entry_point = &nested_call.entry_point;
+            made_calls.push((
+                entry_point.storage_address,
+                entry_point.entry_point_selector,
+                entry_point.calldata.0.to_vec(),
+            ));
+        }
+    });
+
+    made_calls
+}
+
+/// Counts the calls nested in the trace which are collected by [`collect_calls`]
+fn count_calls(call_trace: &CallTrace) -> usize {
+    let mut count = 0;
+    call_trace.visit_nested_calls(&mut |nested_call| {
+        count += usize::from(is_call_to_contract(nested_call));
+    });
+
+    count
+}
+
+fn is_call_to_contract(call_trace: &CallTrace) -> bool {
+    // Library calls execute code of a class, not of the contract they are made to
+    call_trace.entry_point.call_type == CallType::Call
+}
+
+fn format_calldata(calldata: &[Felt]) -> String {
+    let calldata: Vec<_> = calldata.iter().map(Felt::to_hex_string).collect();
+    format!("calldata: [{}]", calldata.join(", "))
+}
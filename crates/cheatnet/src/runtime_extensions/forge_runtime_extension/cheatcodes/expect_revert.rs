@@ -0,0 +1,57 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::panic_data::format_panic_data;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use starknet::core::types::contract::{AbiEntry, SierraClass};
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::core::{ClassHash, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+
+impl CheatnetState {
+    /// Expects the next call made by the test to revert with `panic_data`.
+    /// The expectation is checked in `CallToBlockifierExtension` once the call finishes.
+    pub fn expect_revert(&mut self, panic_data: Vec<Felt>) {
+        self.expected_revert = Some(panic_data);
+    }
+}
+
+/// Returns a message describing the expected revert if the test finished before making any call after it
+#[must_use]
+pub fn verify_expected_revert(expected_revert: Option<&[Felt]>) -> Option<String> {
+    expected_revert.map(|panic_data| {
+        format!(
+            "Expected the call to revert with panic data {}, but no call was made after `expect_revert`",
+            format_panic_data(panic_data)
+        )
+    })
+}
+
+/// Returns the name of the entry point if the ABI of the contract declares it returns a value.
+/// A call reverted as expected has no return data, so dispatchers can't decode it for such entry points.
+/// Contracts not declared from the tested package are assumed to return no value, as their ABI is unknown.
+#[must_use]
+pub fn value_returning_entry_point(
+    contracts_data: &ContractsData,
+    class_hash: &ClassHash,
+    entry_point_selector: EntryPointSelector,
+) -> Option<String> {
+    let artifacts = contracts_data.get_artifacts(contracts_data.get_contract_name(class_hash)?)?;
+    let sierra_class: SierraClass = serde_json::from_str(&artifacts.sierra).ok()?;
+
+    sierra_class
+        .abi
+        .iter()
+        .flat_map(|entry| match entry {
+            AbiEntry::Interface(interface) => interface.items.iter().collect(),
+            _ => vec![entry],
+        })
+        .find_map(|entry| match entry {
+            AbiEntry::Function(function)
+                if !function.outputs.is_empty()
+                    && get_selector_from_name(&function.name)
+                        .is_ok_and(|selector| selector == entry_point_selector.0) =>
+            {
+                Some(function.name.clone())
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,231 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    CallFailure, CallResult, call_contract,
+};
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use crate::runtime_extensions::forge_runtime_extension::fuzzer::{FuzzerSource, draw_value};
+use crate::state::CheatnetState;
+use anyhow::{Context, Result, anyhow, ensure};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use blockifier::state::state_api::StateReader;
+use conversions::FromConv;
+use conversions::string::IntoHexStr;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use starknet::core::types::contract::{AbiEntry, AbiFunction, SierraClass, StateMutability};
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Every step of an invariant test draws a value from `0..=MAX_STEP_DRAW` and makes no call if it is 0.
+/// This way shrinking removes calls which are not needed to break the invariant.
+const MAX_STEP_DRAW: u8 = 7;
+
+/// ABIs of invariant target contracts, parsed once per class hash and shared between all runs of the test.
+#[derive(Debug, Default)]
+pub struct AbiCache(HashMap<ClassHash, Arc<Vec<AbiEntry>>>);
+
+impl AbiCache {
+    fn get_or_parse(
+        &mut self,
+        class_hash: ClassHash,
+        contracts_data: &ContractsData,
+    ) -> Result<Arc<Vec<AbiEntry>>> {
+        if let Some(abi) = self.0.get(&class_hash) {
+            return Ok(abi.clone());
+        }
+
+        let contract_name = contracts_data
+            .get_contract_name(&class_hash)
+            .context("Invariant target contract must be declared from the tested package")?;
+        let artifacts = contracts_data
+            .get_artifacts(contract_name)
+            .expect("Artifacts must exist for a declared contract");
+        let sierra_class: SierraClass = serde_json::from_str(&artifacts.sierra)?;
+        let abi = Arc::new(sierra_class.abi);
+        self.0.insert(class_hash, abi.clone());
+
+        Ok(abi)
+    }
+}
+
+/// Calls a random external function of the target contract with random arguments.
+///
+/// The call is saved as a fuzzer argument, so the sequence of calls is reported when the invariant breaks.
+/// Calls that panic are reverted and do not stop the sequence.
+pub fn invariant_call(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    contracts_data: &ContractsData,
+    mut fuzzer_source: Option<&mut FuzzerSource>,
+    contract_address: ContractAddress,
+) -> Result<()> {
+    let step = draw_value(
+        fuzzer_source.as_deref_mut(),
+        &BigInt::zero(),
+        &MAX_STEP_DRAW.into(),
+    );
+    if step.is_zero() {
+        return Ok(());
+    }
+
+    let class_hash = syscall_handler
+        .base
+        .state
+        .get_class_hash_at(contract_address)?;
+    let abi_cache = fuzzer_source
+        .as_deref()
+        .map_or_else(Arc::default, FuzzerSource::abi_cache);
+    let abi = abi_cache
+        .lock()
+        .expect("Failed to acquire lock on invariant abi_cache")
+        .get_or_parse(class_hash, contracts_data)
+        .with_context(|| {
+            format!(
+                "Failed to get ABI of invariant target contract at address {}",
+                contract_address.into_hex_string()
+            )
+        })?;
+    let functions = callable_functions(&abi);
+    ensure!(
+        !functions.is_empty(),
+        "Invariant target contract has no external functions with supported argument types"
+    );
+
+    let index = draw_value(
+        fuzzer_source.as_deref_mut(),
+        &BigInt::zero(),
+        &(functions.len() - 1).into(),
+    );
+    let function = functions[usize::try_from(&index)?];
+
+    let (deployed_contracts, declared_classes) = {
+        let state_writes = cheatnet_state.state_writes.borrow();
+        let deployed_contracts: Vec<Felt> = state_writes
+            .deployed_contracts()
+            .into_iter()
+            .map(Felt::from_)
+            .collect();
+        let declared_classes: Vec<Felt> = state_writes
+            .declared_classes()
+            .into_iter()
+            .map(|class_hash| class_hash.0)
+            .collect();
+        (deployed_contracts, declared_classes)
+    };
+
+    let arguments = function
+        .inputs
+        .iter()
+        .map(|input| {
+            let known_values = match type_name(&input.r#type) {
+                Some("ContractAddress") => deployed_contracts.as_slice(),
+                Some("ClassHash") => declared_classes.as_slice(),
+                _ => &[],
+            };
+            if !known_values.is_empty() {
+                let index = draw_value(
+                    fuzzer_source.as_deref_mut(),
+                    &BigInt::zero(),
+                    &(known_values.len() - 1).into(),
+                );
+                return known_values[usize::try_from(&index)
+                    .expect("Index is drawn from the range of known values")]
+                .to_string();
+            }
+
+            let (min, max) = argument_range(&input.r#type)
+                .expect("Only functions with supported argument types are called");
+            let value = draw_value(fuzzer_source.as_deref_mut(), &min, &max);
+
+            if is_bool(&input.r#type) {
+                (value == BigInt::one()).to_string()
+            } else {
+                value.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let selector = get_selector_from_name(&function.name)?;
+    let calldata = data_transformer::transform(&arguments, &abi, &selector)?;
+    let call = format!("{}({arguments})", function.name);
+
+    match call_contract(
+        syscall_handler,
+        cheatnet_state,
+        &contract_address,
+        EntryPointSelector(selector),
+        &calldata,
+    ) {
+        CallResult::Success { .. } => cheatnet_state.update_fuzzer_args(call),
+        CallResult::Failure(CallFailure::Panic { .. }) => {
+            cheatnet_state.update_fuzzer_args(format!("{call} (reverted)"));
+        }
+        CallResult::Failure(CallFailure::Error { msg }) => return Err(anyhow!(msg.to_string())),
+    }
+
+    Ok(())
+}
+
+/// External functions from the ABI, including the ones from interfaces, that take only supported arguments.
+fn callable_functions(abi: &[AbiEntry]) -> Vec<&AbiFunction> {
+    abi.iter()
+        .flat_map(|entry| match entry {
+            AbiEntry::Interface(interface) => interface.items.iter().collect(),
+            _ => vec![entry],
+        })
+        .filter_map(|entry| match entry {
+            AbiEntry::Function(function)
+                if matches!(function.state_mutability, StateMutability::External)
+                    && function
+                        .inputs
+                        .iter()
+                        .all(|input| argument_range(&input.r#type).is_some()) =>
+            {
+                Some(function)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Inclusive range of values of the argument type, or `None` if the type is not supported.
+///
+/// Addresses and class hashes are drawn from this range only if no contract was deployed or declared in the test.
+fn argument_range(ty: &str) -> Option<(BigInt, BigInt)> {
+    let unsigned = |bits: u32| (BigInt::zero(), (BigInt::one() << bits) - 1);
+    let signed = |bits: u32| {
+        let bound = BigInt::one() << (bits - 1);
+        (-bound.clone(), bound - 1)
+    };
+
+    match type_name(ty)? {
+        "bool" => Some(unsigned(1)),
+        "u8" => Some(unsigned(8)),
+        "u16" => Some(unsigned(16)),
+        "u32" => Some(unsigned(32)),
+        "u64" => Some(unsigned(64)),
+        "u128" => Some(unsigned(128)),
+        "u256" => Some(unsigned(256)),
+        "i8" => Some(signed(8)),
+        "i16" => Some(signed(16)),
+        "i32" => Some(signed(32)),
+        "i64" => Some(signed(64)),
+        "i128" => Some(signed(128)),
+        "felt252" | "ContractAddress" | "ClassHash" => {
+            Some((BigInt::zero(), Felt::MAX.to_bigint()))
+        }
+        _ => None,
+    }
+}
+
+fn is_bool(ty: &str) -> bool {
+    type_name(ty) == Some("bool")
+}
+
+/// Name of the type without its module path.
+fn type_name(ty: &str) -> Option<&str> {
+    ty.rsplit("::").next()
+}
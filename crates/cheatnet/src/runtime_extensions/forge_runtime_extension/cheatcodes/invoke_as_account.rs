@@ -0,0 +1,218 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    AddressOrClassHash, CallFailure, CallResult, call_entry_point,
+};
+use crate::runtime_extensions::common::create_execute_calldata;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    ExecutionInfoMockOperations, Operation, TxInfoMock, TxInfoMockOperations,
+};
+use anyhow::Result;
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use conversions::felt::ToShortString;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::string::IntoHexStr;
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+/// Version of the INVOKE transactions built by `invoke_as_account`
+const INVOKE_TRANSACTION_VERSION: u8 = 3;
+
+/// Value `__validate__` and `is_valid_signature` of Cairo 1 accounts return when the transaction
+/// or the signature is valid
+const VALIDATED: Felt = Felt::from_hex_unchecked("0x56414c4944"); // 'VALID'
+
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct InvokeOptions {
+    pub transaction_hash: Felt,
+    pub signature: Vec<Felt>,
+    pub skip_signature_verification: bool,
+}
+
+/// Runs an INVOKE transaction sent by the account through its `__validate__` and `__execute__` entry points.
+/// All calls made during the transaction see its transaction info, regardless of the active transaction info cheats.
+pub fn invoke_as_account(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account_address: ContractAddress,
+    execute_calldata: &[Felt],
+    options: InvokeOptions,
+) -> Result<CallResult> {
+    let nonce = syscall_handler.base.state.get_nonce_at(account_address)?;
+
+    let global_tx_info = cheatnet_state.global_cheated_execution_info.tx_info.clone();
+    let contracts_tx_info: HashMap<ContractAddress, TxInfoMock> = cheatnet_state
+        .cheated_execution_info_contracts
+        .iter()
+        .map(|(contract_address, execution_info)| {
+            (*contract_address, execution_info.tx_info.clone())
+        })
+        .collect();
+
+    cheatnet_state.cheat_execution_info(ExecutionInfoMockOperations {
+        tx_info: TxInfoMockOperations {
+            version: Operation::StartGlobal(Felt::from(INVOKE_TRANSACTION_VERSION)),
+            account_contract_address: Operation::StartGlobal(account_address.into_()),
+            signature: Operation::StartGlobal(options.signature.clone()),
+            transaction_hash: Operation::StartGlobal(options.transaction_hash),
+            nonce: Operation::StartGlobal(nonce.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    let result = validate_and_execute(
+        syscall_handler,
+        cheatnet_state,
+        account_address,
+        execute_calldata,
+        &options,
+    );
+
+    cheatnet_state.global_cheated_execution_info.tx_info = global_tx_info.clone();
+    for (contract_address, execution_info) in &mut cheatnet_state.cheated_execution_info_contracts {
+        execution_info.tx_info = contracts_tx_info
+            .get(contract_address)
+            .cloned()
+            .unwrap_or_else(|| global_tx_info.clone());
+    }
+
+    result
+}
+
+fn validate_and_execute(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account_address: ContractAddress,
+    execute_calldata: &[Felt],
+    options: &InvokeOptions,
+) -> Result<CallResult> {
+    let validation_failure = match call_account(
+        syscall_handler,
+        cheatnet_state,
+        account_address,
+        "__validate__",
+        execute_calldata,
+    ) {
+        CallResult::Success { ret_data } if ret_data == [VALIDATED] => None,
+        CallResult::Success { .. } => {
+            let msg = format!(
+                "`__validate__` of account {} did not return 'VALID'",
+                account_address.into_hex_string()
+            );
+            Some(CallResult::Failure(CallFailure::Error {
+                msg: ByteArray::from(msg.as_str()),
+            }))
+        }
+        failure @ CallResult::Failure(_) => Some(failure),
+    };
+
+    if let Some(failure) = validation_failure {
+        // Only the signature verification is skipped, other validation failures are still returned
+        let is_signature_failure = options.skip_signature_verification
+            && match &failure {
+                CallResult::Failure(CallFailure::Panic { panic_data }) => {
+                    is_signature_error(panic_data)
+                }
+                CallResult::Success { .. } => {
+                    is_valid_signature(syscall_handler, cheatnet_state, account_address, options)
+                        == Some(false)
+                }
+                CallResult::Failure(CallFailure::Error { .. }) => false,
+            };
+        if !is_signature_failure {
+            return Ok(failure);
+        }
+    }
+
+    // Nonce is incremented once the transaction is validated, even if its execution fails
+    syscall_handler
+        .base
+        .state
+        .increment_nonce(account_address)?;
+
+    Ok(call_account(
+        syscall_handler,
+        cheatnet_state,
+        account_address,
+        "__execute__",
+        execute_calldata,
+    ))
+}
+
+/// Checks if `__validate__` panicked because of the signature.
+/// Account presets panic with a short string mentioning it, e.g. 'Account: invalid signature' of the OpenZeppelin account.
+fn is_signature_error(panic_data: &[Felt]) -> bool {
+    let Some(Ok(error)) = panic_data.first().map(ToShortString::to_short_string) else {
+        return false;
+    };
+
+    error
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| word == "sig" || word.starts_with("signature"))
+}
+
+/// Checks the signature from `options` with `is_valid_signature` of the account (SRC-6),
+/// returns `None` if the account doesn't implement it.
+fn is_valid_signature(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account_address: ContractAddress,
+    options: &InvokeOptions,
+) -> Option<bool> {
+    let mut calldata = vec![
+        options.transaction_hash,
+        Felt::from(options.signature.len()),
+    ];
+    calldata.extend(&options.signature);
+
+    match call_account(
+        syscall_handler,
+        cheatnet_state,
+        account_address,
+        "is_valid_signature",
+        &calldata,
+    ) {
+        CallResult::Success { ret_data } => Some(ret_data == [VALIDATED]),
+        CallResult::Failure(_) => None,
+    }
+}
+
+fn call_account(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    account_address: ContractAddress,
+    entry_point_name: &str,
+    calldata: &[Felt],
+) -> CallResult {
+    let entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(account_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: get_selector_from_name(entry_point_name).unwrap().into_(),
+        calldata: create_execute_calldata(calldata),
+        storage_address: account_address,
+        // Account entry points are called by the protocol
+        caller_address: ContractAddress::default(),
+        call_type: CallType::Call,
+        initial_gas: syscall_handler
+            .base
+            .context
+            .gas_costs()
+            .base
+            .default_initial_gas_cost,
+    };
+
+    call_entry_point(
+        syscall_handler,
+        cheatnet_state,
+        entry_point,
+        &AddressOrClassHash::ContractAddress(account_address),
+    )
+}
@@ -1,9 +1,48 @@
 use crate::CheatnetState;
 use crate::state::{CheatSpan, CheatStatus};
+use conversions::serde::deserialize::CairoDeserialize;
 use starknet_api::core::{ContractAddress, EntryPointSelector};
 use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
 use std::collections::hash_map::Entry;
 
+/// Calldata of calls affected by the mock
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MockCalldata {
+    Any,
+    Exact(Vec<Felt>),
+    Prefix(Vec<Felt>),
+}
+
+impl MockCalldata {
+    fn matches(&self, calldata: &[Felt]) -> bool {
+        match self {
+            MockCalldata::Any => true,
+            MockCalldata::Exact(expected) => expected == calldata,
+            MockCalldata::Prefix(prefix) => calldata.starts_with(prefix),
+        }
+    }
+
+    /// When many mocks match the call, the most specific one is used
+    fn specificity(&self) -> (usize, bool) {
+        match self {
+            MockCalldata::Any => (0, false),
+            MockCalldata::Prefix(prefix) => (prefix.len(), false),
+            MockCalldata::Exact(expected) => (expected.len(), true),
+        }
+    }
+}
+
+/// Result of the mocked call
+#[derive(CairoDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum MockResult {
+    Success(Vec<Felt>),
+    Panic(Vec<Felt>),
+}
+
+/// Mocks of a single entry point, by the calldata they apply to
+pub type CallMocks = HashMap<MockCalldata, CheatStatus<MockResult>>;
+
 impl CheatnetState {
     pub fn mock_call(
         &mut self,
@@ -12,14 +51,31 @@ impl CheatnetState {
         ret_data: &[Felt],
         span: CheatSpan,
     ) {
-        let contract_mocked_functions = self.mocked_functions.entry(contract_address).or_default();
-
-        contract_mocked_functions.insert(
+        self.mock_call_with(
+            contract_address,
             function_selector,
-            CheatStatus::Cheated(ret_data.to_vec(), span),
+            MockCalldata::Any,
+            MockResult::Success(ret_data.to_vec()),
+            span,
         );
     }
 
+    pub fn mock_call_with(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+        calldata: MockCalldata,
+        result: MockResult,
+        span: CheatSpan,
+    ) {
+        self.mocked_functions
+            .entry(contract_address)
+            .or_default()
+            .entry(function_selector)
+            .or_default()
+            .insert(calldata, CheatStatus::Cheated(result, span));
+    }
+
     pub fn start_mock_call(
         &mut self,
         contract_address: ContractAddress,
@@ -34,6 +90,7 @@ impl CheatnetState {
         );
     }
 
+    /// Cancels all mocks of the entry point, regardless of the calldata they apply to
     pub fn stop_mock_call(
         &mut self,
         contract_address: ContractAddress,
@@ -44,4 +101,26 @@ impl CheatnetState {
             contract_mocked_functions.remove(&function_selector);
         }
     }
+
+    /// Returns result of the most specific active mock matching the call and decrements its span
+    pub fn get_mocked_call_result(
+        &mut self,
+        contract_address: ContractAddress,
+        function_selector: EntryPointSelector,
+        calldata: &[Felt],
+    ) -> Option<MockResult> {
+        let (_, cheat_status) = self
+            .mocked_functions
+            .get_mut(&contract_address)?
+            .get_mut(&function_selector)?
+            .iter_mut()
+            .filter(|(mock_calldata, cheat_status)| {
+                matches!(cheat_status, CheatStatus::Cheated(..)) && mock_calldata.matches(calldata)
+            })
+            .max_by_key(|(mock_calldata, _)| mock_calldata.specificity())?;
+
+        let result = cheat_status.as_value();
+        cheat_status.decrement_cheat_span();
+        result
+    }
 }
@@ -3,23 +3,32 @@ use cairo_vm::vm::errors::hint_errors::HintError;
 use runtime::EnhancedHintError;
 use starknet_types_core::felt::Felt;
 
+pub mod advance_block;
 pub mod cheat_block_hash;
 pub mod cheat_block_number;
 pub mod cheat_block_timestamp;
 pub mod cheat_caller_address;
 pub mod cheat_execution_info;
+pub mod cheat_gas_prices;
 pub mod cheat_sequencer_address;
 pub mod declare;
 pub mod deploy;
+pub mod expect_call;
+pub mod expect_revert;
 pub mod generate_random_felt;
 pub mod get_class_hash;
+pub mod invariant_call;
+pub mod invoke_as_account;
 pub mod l1_handler_execute;
 pub mod mock_call;
 pub mod precalculate_address;
 pub mod replace_bytecode;
+pub mod snapshot_state;
+pub mod spy_calls;
 pub mod spy_events;
 pub mod spy_messages_to_l1;
 pub mod storage;
+pub mod storage_access;
 
 /// A structure used for returning cheatcode errors in tests
 #[derive(Debug)]
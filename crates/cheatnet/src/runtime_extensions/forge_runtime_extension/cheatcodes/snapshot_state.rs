@@ -0,0 +1,283 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::ExecutionInfoMock;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect_call::RegisteredExpectedCall;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::CallMocks;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_access::StorageAccesses;
+use crate::state::CheatSpan;
+use anyhow::{Result, anyhow};
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::state_api::{State, StateReader, StateResult};
+use indexmap::IndexMap;
+use starknet_api::block::GasPriceVector;
+use starknet_api::core::{
+    ClassHash, CompiledClassHash, ContractAddress, EntryPointSelector, Nonce,
+};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Writes made to the state during the test.
+/// They are kept apart from the underlying state, so they can be saved in a snapshot and restored later.
+#[derive(Clone, Debug, Default)]
+pub struct StateWrites {
+    storage: HashMap<(ContractAddress, StorageKey), Felt>,
+    nonces: HashMap<ContractAddress, Nonce>,
+    class_hashes: HashMap<ContractAddress, ClassHash>,
+    compiled_classes: HashMap<ClassHash, RunnableCompiledClass>,
+    compiled_class_hashes: HashMap<ClassHash, CompiledClassHash>,
+}
+
+/// State used during the test, which keeps all writes in [`StateWrites`] shared with [`CheatnetState`].
+/// Reads of values that were not written fall back to the wrapped state.
+pub struct SnapshotableState<'a> {
+    state: &'a mut dyn State,
+    writes: Rc<RefCell<StateWrites>>,
+}
+
+impl StateWrites {
+    /// Addresses of contracts deployed during the test, sorted so they are listed in the same order in every run.
+    #[must_use]
+    pub fn deployed_contracts(&self) -> Vec<ContractAddress> {
+        let mut contract_addresses: Vec<_> = self.class_hashes.keys().copied().collect();
+        contract_addresses.sort();
+        contract_addresses
+    }
+
+    /// Class hashes declared during the test, sorted so they are listed in the same order in every run.
+    #[must_use]
+    pub fn declared_classes(&self) -> Vec<ClassHash> {
+        let mut class_hashes: Vec<_> = self.compiled_classes.keys().copied().collect();
+        class_hashes.sort();
+        class_hashes
+    }
+}
+
+impl<'a> SnapshotableState<'a> {
+    pub fn new(state: &'a mut dyn State, writes: Rc<RefCell<StateWrites>>) -> Self {
+        Self { state, writes }
+    }
+
+    /// Applies all writes to the wrapped state, so it can be used for calculating the state diff.
+    pub fn commit(self) -> StateResult<()> {
+        let writes = self.writes.take();
+
+        for ((contract_address, key), value) in writes.storage {
+            self.state.set_storage_at(contract_address, key, value)?;
+        }
+        for (contract_address, nonce) in writes.nonces {
+            while self.state.get_nonce_at(contract_address)? < nonce {
+                self.state.increment_nonce(contract_address)?;
+            }
+        }
+        for (contract_address, class_hash) in writes.class_hashes {
+            self.state.set_class_hash_at(contract_address, class_hash)?;
+        }
+        for (class_hash, compiled_class) in writes.compiled_classes {
+            self.state.set_contract_class(class_hash, compiled_class)?;
+        }
+        for (class_hash, compiled_class_hash) in writes.compiled_class_hashes {
+            self.state
+                .set_compiled_class_hash(class_hash, compiled_class_hash)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl StateReader for SnapshotableState<'_> {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        match self.writes.borrow().storage.get(&(contract_address, key)) {
+            Some(value) => Ok(*value),
+            None => self.state.get_storage_at(contract_address, key),
+        }
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        match self.writes.borrow().nonces.get(&contract_address) {
+            Some(nonce) => Ok(*nonce),
+            None => self.state.get_nonce_at(contract_address),
+        }
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        match self.writes.borrow().class_hashes.get(&contract_address) {
+            Some(class_hash) => Ok(*class_hash),
+            None => self.state.get_class_hash_at(contract_address),
+        }
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        match self.writes.borrow().compiled_classes.get(&class_hash) {
+            Some(compiled_class) => Ok(compiled_class.clone()),
+            None => self.state.get_compiled_class(class_hash),
+        }
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        match self.writes.borrow().compiled_class_hashes.get(&class_hash) {
+            Some(compiled_class_hash) => Ok(*compiled_class_hash),
+            None => self.state.get_compiled_class_hash(class_hash),
+        }
+    }
+}
+
+impl State for SnapshotableState<'_> {
+    fn set_storage_at(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        value: Felt,
+    ) -> StateResult<()> {
+        self.writes
+            .borrow_mut()
+            .storage
+            .insert((contract_address, key), value);
+        Ok(())
+    }
+
+    fn increment_nonce(&mut self, contract_address: ContractAddress) -> StateResult<()> {
+        let nonce = self.get_nonce_at(contract_address)?.try_increment()?;
+        self.writes
+            .borrow_mut()
+            .nonces
+            .insert(contract_address, nonce);
+        Ok(())
+    }
+
+    fn set_class_hash_at(
+        &mut self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+    ) -> StateResult<()> {
+        self.writes
+            .borrow_mut()
+            .class_hashes
+            .insert(contract_address, class_hash);
+        Ok(())
+    }
+
+    fn set_contract_class(
+        &mut self,
+        class_hash: ClassHash,
+        contract_class: RunnableCompiledClass,
+    ) -> StateResult<()> {
+        self.writes
+            .borrow_mut()
+            .compiled_classes
+            .insert(class_hash, contract_class);
+        Ok(())
+    }
+
+    fn set_compiled_class_hash(
+        &mut self,
+        class_hash: ClassHash,
+        compiled_class_hash: CompiledClassHash,
+    ) -> StateResult<()> {
+        self.writes
+            .borrow_mut()
+            .compiled_class_hashes
+            .insert(class_hash, compiled_class_hash);
+        Ok(())
+    }
+}
+
+/// State of the test saved by `snapshot_state` cheatcode
+#[derive(Clone)]
+pub struct StateSnapshot {
+    writes: StateWrites,
+    cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
+    global_cheated_execution_info: ExecutionInfoMock,
+    mocked_functions: HashMap<ContractAddress, HashMap<EntryPointSelector, CallMocks>>,
+    replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
+    detected_events: Vec<Event>,
+    detected_messages_to_l1: Vec<MessageToL1>,
+    deploy_salt_base: u32,
+    block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
+    global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    advanced_block_number: Option<u64>,
+    cheated_gas_prices: Option<GasPriceVector>,
+    expected_calls: Vec<RegisteredExpectedCall>,
+    expected_revert: Option<Vec<Felt>>,
+    storage_access_recording_targets: HashSet<ContractAddress>,
+    storage_accesses: IndexMap<ContractAddress, StorageAccesses>,
+}
+
+impl CheatnetState {
+    /// Saves the current state and returns id of the snapshot.
+    pub fn snapshot_state(&mut self) -> usize {
+        self.state_snapshots.push(StateSnapshot {
+            writes: self.state_writes.borrow().clone(),
+            cheated_execution_info_contracts: self.cheated_execution_info_contracts.clone(),
+            global_cheated_execution_info: self.global_cheated_execution_info.clone(),
+            mocked_functions: self.mocked_functions.clone(),
+            replaced_bytecode_contracts: self.replaced_bytecode_contracts.clone(),
+            detected_events: self.detected_events.clone(),
+            detected_messages_to_l1: self.detected_messages_to_l1.clone(),
+            deploy_salt_base: self.deploy_salt_base,
+            block_hash_contracts: self.block_hash_contracts.clone(),
+            global_block_hash: self.global_block_hash.clone(),
+            advanced_block_number: self.advanced_block_number,
+            cheated_gas_prices: self.cheated_gas_prices.clone(),
+            expected_calls: self.expected_calls.clone(),
+            expected_revert: self.expected_revert.clone(),
+            storage_access_recording_targets: self.storage_access_recording_targets.clone(),
+            storage_accesses: self.storage_accesses.clone(),
+        });
+
+        self.state_snapshots.len() - 1
+    }
+
+    /// Restores the state saved in the snapshot.
+    /// The snapshot is kept, so the state can be reverted to it multiple times.
+    pub fn revert_to_snapshot(&mut self, snapshot_id: usize) -> Result<()> {
+        let StateSnapshot {
+            writes,
+            cheated_execution_info_contracts,
+            global_cheated_execution_info,
+            mocked_functions,
+            replaced_bytecode_contracts,
+            detected_events,
+            detected_messages_to_l1,
+            deploy_salt_base,
+            block_hash_contracts,
+            global_block_hash,
+            advanced_block_number,
+            cheated_gas_prices,
+            expected_calls,
+            expected_revert,
+            storage_access_recording_targets,
+            storage_accesses,
+        } = self
+            .state_snapshots
+            .get(snapshot_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Snapshot with id = {snapshot_id} does not exist"))?;
+
+        self.state_writes.replace(writes);
+        self.cheated_execution_info_contracts = cheated_execution_info_contracts;
+        self.global_cheated_execution_info = global_cheated_execution_info;
+        self.mocked_functions = mocked_functions;
+        self.replaced_bytecode_contracts = replaced_bytecode_contracts;
+        self.detected_events = detected_events;
+        self.detected_messages_to_l1 = detected_messages_to_l1;
+        self.deploy_salt_base = deploy_salt_base;
+        self.block_hash_contracts = block_hash_contracts;
+        self.global_block_hash = global_block_hash;
+        self.advanced_block_number = advanced_block_number;
+        self.cheated_gas_prices = cheated_gas_prices;
+        self.expected_calls = expected_calls;
+        self.expected_revert = expected_revert;
+        self.storage_access_recording_targets = storage_access_recording_targets;
+        self.storage_accesses = storage_accesses;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallResult;
+use crate::state::CallTrace;
+use blockifier::execution::entry_point::CallType;
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+
+/// Represents a call made during the test, taken from its call trace
+#[derive(CairoSerialize, Debug, Clone)]
+pub struct SpiedCall {
+    pub caller_address: ContractAddress,
+    pub contract_address: ContractAddress,
+    /// Class whose code is executed by a library call, `None` for regular calls
+    pub class_hash: Option<ClassHash>,
+    pub entry_point_selector: EntryPointSelector,
+    pub calldata: Vec<Felt>,
+    pub call_type: CallType,
+    pub result: CallResult,
+}
+
+impl CheatnetState {
+    /// Returns all calls made during the test, in the order they were made, skipping the first `call_offset` ones
+    #[must_use]
+    pub fn get_calls(&self, call_offset: usize) -> Vec<SpiedCall> {
+        let mut calls = vec![];
+        let mut call_index = 0;
+        self.trace_data
+            .current_call_stack
+            .borrow_full_trace()
+            .visit_nested_calls(&mut |call_trace| {
+                if call_index >= call_offset {
+                    calls.push(SpiedCall::from(call_trace));
+                }
+                call_index += 1;
+            });
+
+        calls
+    }
+
+    #[must_use]
+    pub fn count_calls(&self) -> usize {
+        let mut count = 0;
+        self.trace_data
+            .current_call_stack
+            .borrow_full_trace()
+            .visit_nested_calls(&mut |_| count += 1);
+
+        count
+    }
+}
+
+impl From<&CallTrace> for SpiedCall {
+    fn from(call_trace: &CallTrace) -> Self {
+        let entry_point = &call_trace.entry_point;
+
+        Self {
+            caller_address: entry_point.caller_address,
+            contract_address: entry_point.storage_address,
+            class_hash: match entry_point.call_type {
+                CallType::Call => None,
+                CallType::Delegate => entry_point.class_hash,
+            },
+            entry_point_selector: entry_point.entry_point_selector,
+            calldata: entry_point.calldata.0.to_vec(),
+            call_type: entry_point.call_type,
+            result: call_trace.result.clone(),
+        }
+    }
+}
@@ -40,6 +40,10 @@ impl Event {
 
 impl CheatnetState {
     pub fn get_events(&mut self, event_offset: usize) -> Vec<Event> {
-        self.detected_events[event_offset..].to_vec()
+        // Spies created after a snapshot may point past the events left after reverting to it
+        self.detected_events
+            .get(event_offset..)
+            .unwrap_or_default()
+            .to_vec()
     }
 }
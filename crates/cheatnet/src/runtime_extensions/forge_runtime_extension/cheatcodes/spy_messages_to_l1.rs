@@ -35,6 +35,10 @@ impl MessageToL1 {
 impl CheatnetState {
     #[must_use]
     pub fn get_messages_to_l1(&self, message_offset: usize) -> Vec<MessageToL1> {
-        self.detected_messages_to_l1[message_offset..].to_vec()
+        // Spies created after a snapshot may point past the messages left after reverting to it
+        self.detected_messages_to_l1
+            .get(message_offset..)
+            .unwrap_or_default()
+            .to_vec()
     }
 }
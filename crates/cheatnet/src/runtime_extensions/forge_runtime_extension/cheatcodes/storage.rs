@@ -1,3 +1,4 @@
+use anyhow::anyhow;
 use blockifier::state::state_api::State;
 use conversions::{FromConv, IntoConv};
 use starknet::core::crypto::pedersen_hash;
@@ -70,6 +71,63 @@ pub fn calculate_variable_address(selector: Felt, key: Option<&[Felt]>) -> Felt
     }
 }
 
+/// Calculates the address of a storage variable from its path, e.g. `balances[0x123].amount`.
+/// The path starts with the name of the variable, followed by any number of segments:
+/// - `.member` - member of a storage node
+/// - `[key_part, ...]` - entry of a `Map` with the serialized key, or element of a `Vec` at the index
+///
+/// Key parts are decimal or hex (`0x` prefixed) felts.
+pub fn calculate_storage_path_address(path: &str) -> Result<Felt, anyhow::Error> {
+    let invalid_path = |reason: String| anyhow!("Invalid storage path `{path}`: {reason}");
+
+    let (var_name, mut remaining) = split_name(path);
+    if var_name.is_empty() {
+        return Err(invalid_path("expected a storage variable name".to_string()));
+    }
+    let mut address: Felt = selector_from_name(var_name).into_();
+
+    while !remaining.is_empty() {
+        if let Some(member_path) = remaining.strip_prefix('.') {
+            let (member, rest) = split_name(member_path);
+            if member.is_empty() {
+                return Err(invalid_path("expected a member name after `.`".to_string()));
+            }
+            address = pedersen_hash(&address, &selector_from_name(member).into_());
+            remaining = rest;
+        } else if let Some(key_path) = remaining.strip_prefix('[') {
+            let (key, rest) = key_path
+                .split_once(']')
+                .ok_or_else(|| invalid_path("missing closing `]`".to_string()))?;
+            for key_part in key.split(',').map(str::trim) {
+                let key_part = parse_key_part(key_part)
+                    .ok_or_else(|| invalid_path(format!("invalid key part `{key_part}`")))?;
+                address = pedersen_hash(&address, &key_part);
+            }
+            remaining = rest;
+        } else {
+            return Err(invalid_path(format!("unexpected `{remaining}`")));
+        }
+    }
+
+    Ok(normalize_storage_address(address))
+}
+
+/// Splits the path into the leading identifier and the rest of it
+fn split_name(path: &str) -> (&str, &str) {
+    let end = path
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(path.len());
+    path.split_at(end)
+}
+
+fn parse_key_part(key_part: &str) -> Option<Felt> {
+    if key_part.starts_with("0x") {
+        Felt::from_hex(key_part).ok()
+    } else {
+        Felt::from_dec_str(key_part).ok()
+    }
+}
+
 #[must_use]
 pub fn variable_address(var_name: &str) -> Felt {
     calculate_variable_address(selector_from_name(var_name).into_(), None)
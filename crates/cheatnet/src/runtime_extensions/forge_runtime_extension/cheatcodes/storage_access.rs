@@ -0,0 +1,72 @@
+use crate::CheatnetState;
+use conversions::serde::serialize::{BufferWriter, CairoSerialize};
+use indexmap::IndexSet;
+use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+
+/// Storage keys read and written by a contract, in order of their first access
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageAccesses {
+    pub reads: IndexSet<Felt>,
+    pub writes: IndexSet<Felt>,
+}
+
+impl CairoSerialize for StorageAccesses {
+    fn serialize(&self, output: &mut BufferWriter) {
+        for keys in [&self.reads, &self.writes] {
+            keys.len().serialize(output);
+            for key in keys {
+                key.serialize(output);
+            }
+        }
+    }
+}
+
+impl CheatnetState {
+    /// Starts recording storage accesses of the contract, discarding the ones recorded before
+    pub fn start_record_storage_access(&mut self, target: ContractAddress) {
+        self.storage_access_recording_targets.insert(target);
+        self.storage_accesses
+            .insert(target, StorageAccesses::default());
+    }
+
+    /// Stops recording storage accesses of the contract, keeping the ones already recorded
+    pub fn stop_record_storage_access(&mut self, target: ContractAddress) {
+        self.storage_access_recording_targets.remove(&target);
+    }
+
+    #[must_use]
+    pub fn get_storage_accesses(&self) -> Vec<(ContractAddress, StorageAccesses)> {
+        self.storage_accesses
+            .iter()
+            .map(|(contract_address, accesses)| (*contract_address, accesses.clone()))
+            .collect()
+    }
+
+    pub fn record_storage_read(&mut self, contract_address: ContractAddress, key: StorageKey) {
+        if let Some(accesses) = self.recorded_storage_accesses_mut(contract_address) {
+            accesses.reads.insert(*key.0.key());
+        }
+    }
+
+    pub fn record_storage_write(&mut self, contract_address: ContractAddress, key: StorageKey) {
+        if let Some(accesses) = self.recorded_storage_accesses_mut(contract_address) {
+            accesses.writes.insert(*key.0.key());
+        }
+    }
+
+    fn recorded_storage_accesses_mut(
+        &mut self,
+        contract_address: ContractAddress,
+    ) -> Option<&mut StorageAccesses> {
+        if !self
+            .storage_access_recording_targets
+            .contains(&contract_address)
+        {
+            return None;
+        }
+
+        Some(self.storage_accesses.entry(contract_address).or_default())
+    }
+}
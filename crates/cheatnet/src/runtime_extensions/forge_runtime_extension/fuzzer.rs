@@ -1,11 +1,128 @@
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::invariant_call::AbiCache;
 use anyhow::ensure;
-use num_bigint::RandBigInt;
+use dictionary::FuzzerDictionary;
+use num_bigint::{BigInt, RandBigInt};
+use rand::Rng;
 use rand::prelude::StdRng;
+use serde::{Deserialize, Serialize};
 use starknet_types_core::felt::Felt;
+use std::collections::VecDeque;
 use std::sync::{Arc, Mutex};
 
+pub mod dictionary;
+pub mod guided;
+pub mod shrinking;
+
+/// Single value returned by `generate_arg` together with the range it was drawn from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FuzzerDraw {
+    pub min: BigInt,
+    pub max: BigInt,
+    pub value: BigInt,
+}
+
+/// On average one in `DICTIONARY_DRAW_RATIO` values drawn from the fuzzer rng is taken from the dictionary,
+/// if it contains a value in the requested range.
+const DICTIONARY_DRAW_RATIO: u32 = 4;
+
+/// Source of values returned by `generate_arg` during a single fuzz test run.
+///
+/// Values scheduled for replay are returned first (clamped to the requested range),
+/// after they are exhausted values are drawn from the fuzzer rng, mixed with values from the dictionary.
+/// Every returned value is recorded, so the run can be reproduced later.
+#[derive(Debug)]
+pub struct FuzzerSource {
+    rng: Arc<Mutex<StdRng>>,
+    dictionary: Arc<Mutex<FuzzerDictionary>>,
+    abi_cache: Arc<Mutex<AbiCache>>,
+    replay: VecDeque<BigInt>,
+    draws: Vec<FuzzerDraw>,
+}
+
+impl FuzzerSource {
+    #[must_use]
+    pub fn random(
+        rng: Arc<Mutex<StdRng>>,
+        dictionary: Arc<Mutex<FuzzerDictionary>>,
+        abi_cache: Arc<Mutex<AbiCache>>,
+    ) -> Self {
+        Self {
+            rng,
+            dictionary,
+            abi_cache,
+            replay: VecDeque::new(),
+            draws: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn replay(
+        rng: Arc<Mutex<StdRng>>,
+        dictionary: Arc<Mutex<FuzzerDictionary>>,
+        abi_cache: Arc<Mutex<AbiCache>>,
+        draws: &[FuzzerDraw],
+    ) -> Self {
+        Self {
+            rng,
+            dictionary,
+            abi_cache,
+            replay: draws.iter().map(|draw| draw.value.clone()).collect(),
+            draws: vec![],
+        }
+    }
+
+    /// Adds values seen during the run to the dictionary shared with other runs of the test.
+    pub fn extend_dictionary(&self, values: impl IntoIterator<Item = Felt>) {
+        self.dictionary
+            .lock()
+            .expect("Failed to acquire lock on fuzzer_dictionary")
+            .extend_with_storage_values(values);
+    }
+
+    /// ABIs of invariant target contracts shared with other runs of the test.
+    pub(crate) fn abi_cache(&self) -> Arc<Mutex<AbiCache>> {
+        self.abi_cache.clone()
+    }
+
+    #[must_use]
+    pub fn into_draws(self) -> Vec<FuzzerDraw> {
+        self.draws
+    }
+
+    fn draw(&mut self, min: &BigInt, max: &BigInt) -> BigInt {
+        let value = match self.replay.pop_front() {
+            Some(value) => value.clamp(min.clone(), max.clone()),
+            None => {
+                let mut rng = self
+                    .rng
+                    .lock()
+                    .expect("Failed to acquire lock on fuzzer_rng");
+
+                let dictionary_value = if rng.gen_ratio(1, DICTIONARY_DRAW_RATIO) {
+                    self.dictionary
+                        .lock()
+                        .expect("Failed to acquire lock on fuzzer_dictionary")
+                        .pick(min, max, &mut rng)
+                } else {
+                    None
+                };
+
+                dictionary_value.unwrap_or_else(|| rng.gen_bigint_range(min, &(max + 1)))
+            }
+        };
+
+        self.draws.push(FuzzerDraw {
+            min: min.clone(),
+            max: max.clone(),
+            value: value.clone(),
+        });
+
+        value
+    }
+}
+
 pub(crate) fn generate_arg(
-    fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    fuzzer_source: Option<&mut FuzzerSource>,
     min_value: Felt,
     max_value: Felt,
 ) -> anyhow::Result<Felt> {
@@ -26,15 +143,21 @@ pub(crate) fn generate_arg(
         )
     );
 
-    let value = if let Some(fuzzer_rng) = fuzzer_rng {
-        fuzzer_rng
-            .lock()
-            .expect("Failed to acquire lock on fuzzer_rng")
-            .gen_bigint_range(&min_big_int, &(max_big_int + 1))
-    } else {
-        // `generate_arg` cheatcode can be also used outside the fuzzer context
-        rand::thread_rng().gen_bigint_range(&min_big_int, &(max_big_int + 1))
-    };
+    let value = draw_value(fuzzer_source, &min_big_int, &max_big_int);
 
     Ok(Felt::from(value))
 }
+
+/// Draws a value from the inclusive range using the fuzzer source of the current run.
+pub(crate) fn draw_value(
+    fuzzer_source: Option<&mut FuzzerSource>,
+    min: &BigInt,
+    max: &BigInt,
+) -> BigInt {
+    if let Some(fuzzer_source) = fuzzer_source {
+        fuzzer_source.draw(min, max)
+    } else {
+        // Cheatcodes drawing values can be also used outside the fuzzer context
+        rand::thread_rng().gen_bigint_range(min, &(max + 1))
+    }
+}
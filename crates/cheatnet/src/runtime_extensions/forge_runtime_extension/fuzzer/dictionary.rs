@@ -0,0 +1,218 @@
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cairo_lang_starknet_classes::casm_contract_class::CasmContractClass;
+use indexmap::IndexSet;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use rand::Rng;
+use rand::prelude::StdRng;
+use starknet_types_core::felt::Felt;
+
+/// Maximal number of constants from the compiled code kept in the dictionary.
+const MAX_PROGRAM_VALUES: usize = 3072;
+
+/// Maximal number of storage values kept in the dictionary, space for them is reserved
+/// so they are not crowded out by constants from the compiled code.
+pub const MAX_STORAGE_VALUES: usize = 1024;
+
+/// Flag of a CASM instruction indicating that its next word is an immediate value.
+const OP1_IMM_FLAG_BIT: u64 = 50;
+
+/// Instruction words are smaller than 2^63, anything bigger is data.
+const MAX_INSTRUCTION_BITS: u64 = 63;
+
+/// Values which are likely to be meaningful for the tested code, like selectors, addresses or constants.
+///
+/// The dictionary is seeded with constants found in the compiled code of the test and declared contracts,
+/// and extended with values read from and written to the storage during fuzz test runs.
+#[derive(Debug, Default)]
+pub struct FuzzerDictionary {
+    program_values: IndexSet<Felt>,
+    storage_values: IndexSet<Felt>,
+    /// Every value both as a felt and as a negative number, sorted so values in a range form a slice
+    candidates: Vec<BigInt>,
+}
+
+impl FuzzerDictionary {
+    /// Creates a dictionary with immediate values of the test bytecode and the CASM of declared contracts.
+    #[must_use]
+    pub fn from_programs(test_bytecode: &[BigInt], contracts_data: &ContractsData) -> Self {
+        let mut dictionary = Self::default();
+        dictionary.extend_with_program_values(immediates(test_bytecode));
+
+        // Contracts are sorted, so the dictionary is the same for every run with the same seed
+        let mut contract_names = contracts_data.contracts.keys().collect::<Vec<_>>();
+        contract_names.sort();
+        for contract_name in contract_names {
+            let casm = &contracts_data.contracts[contract_name].artifacts.casm;
+            if let Ok(casm_contract_class) = serde_json::from_str::<CasmContractClass>(casm) {
+                let bytecode = casm_contract_class
+                    .bytecode
+                    .into_iter()
+                    .map(|word| BigInt::from(word.value))
+                    .collect::<Vec<_>>();
+                dictionary.extend_with_program_values(immediates(&bytecode));
+            }
+        }
+
+        dictionary
+    }
+
+    fn extend_with_program_values(&mut self, values: impl IntoIterator<Item = Felt>) {
+        for value in values {
+            if self.program_values.len() >= MAX_PROGRAM_VALUES {
+                break;
+            }
+            if self.program_values.insert(value) {
+                self.insert_candidates(value);
+            }
+        }
+    }
+
+    /// Adds values read from and written to the storage, up to the space reserved for them.
+    pub fn extend_with_storage_values(&mut self, values: impl IntoIterator<Item = Felt>) {
+        for value in values {
+            if self.storage_values.len() >= MAX_STORAGE_VALUES {
+                break;
+            }
+            if !self.program_values.contains(&value) && self.storage_values.insert(value) {
+                self.insert_candidates(value);
+            }
+        }
+    }
+
+    fn insert_candidates(&mut self, value: Felt) {
+        let prime = Felt::MAX.to_bigint() + 1;
+        let value = value.to_bigint();
+        let negative = &value - &prime;
+
+        for candidate in [value, negative] {
+            let index = self.candidates.partition_point(|other| *other < candidate);
+            self.candidates.insert(index, candidate);
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.program_values.len() + self.storage_values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Picks a random value from the dictionary that lies in the inclusive range.
+    /// Felts from the upper half of the field are also treated as negative numbers.
+    pub(crate) fn pick(&self, min: &BigInt, max: &BigInt, rng: &mut StdRng) -> Option<BigInt> {
+        let start = self.candidates.partition_point(|value| value < min);
+        let end = self.candidates.partition_point(|value| value <= max);
+
+        if start >= end {
+            return None;
+        }
+
+        Some(self.candidates[rng.gen_range(start..end)].clone())
+    }
+}
+
+/// Collects immediate operands of the instructions in the bytecode.
+fn immediates(bytecode: &[BigInt]) -> Vec<Felt> {
+    let max_instruction = BigInt::one() << MAX_INSTRUCTION_BITS;
+    let op1_imm_flag = BigInt::one() << OP1_IMM_FLAG_BIT;
+
+    let mut values = vec![];
+    let mut index = 0;
+    while index < bytecode.len() {
+        let word = &bytecode[index];
+        if *word < max_instruction && !(word & &op1_imm_flag).is_zero() {
+            if let Some(immediate) = bytecode.get(index + 1) {
+                values.push(Felt::from(immediate.clone()));
+            }
+            index += 2;
+        } else {
+            index += 1;
+        }
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    // `[ap + 0] = 12345, ap++`
+    const ASSERT_EQ_IMM: u64 = 0x4806_8001_7fff_8000;
+    // `ret`
+    const RET: u64 = 0x208b_7fff_7fff_7ffe;
+
+    fn dictionary(values: &[i64]) -> FuzzerDictionary {
+        let mut dictionary = FuzzerDictionary::default();
+        dictionary.extend_with_program_values(values.iter().map(|value| Felt::from(*value)));
+        dictionary
+    }
+
+    #[test]
+    fn collects_immediates() {
+        let bytecode = [
+            BigInt::from(ASSERT_EQ_IMM),
+            BigInt::from(12345),
+            BigInt::from(RET),
+            BigInt::from(ASSERT_EQ_IMM),
+            Felt::from(-7).to_bigint(),
+        ];
+
+        assert_eq!(
+            immediates(&bytecode),
+            vec![Felt::from(12345), Felt::from(-7)]
+        );
+    }
+
+    #[test]
+    fn picks_only_values_in_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let dictionary = dictionary(&[1, 50, 1000, -20]);
+
+        for _ in 0..100 {
+            let value = dictionary
+                .pick(&BigInt::from(-100), &BigInt::from(100), &mut rng)
+                .unwrap();
+            assert!([1, 50, -20].map(BigInt::from).contains(&value));
+        }
+    }
+
+    #[test]
+    fn picks_nothing_without_values_in_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let dictionary = dictionary(&[1000, -20]);
+
+        assert!(
+            dictionary
+                .pick(&BigInt::from(0), &BigInt::from(100), &mut rng)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn size_is_limited() {
+        let mut dictionary = FuzzerDictionary::default();
+        dictionary.extend_with_program_values((0..2 * MAX_PROGRAM_VALUES).map(Felt::from));
+        dictionary.extend_with_storage_values((0..2 * MAX_PROGRAM_VALUES).map(Felt::from));
+
+        assert_eq!(dictionary.len(), MAX_PROGRAM_VALUES + MAX_STORAGE_VALUES);
+    }
+
+    #[test]
+    fn storage_values_are_added_to_full_dictionary() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut dictionary = FuzzerDictionary::default();
+        dictionary.extend_with_program_values((0..2 * MAX_PROGRAM_VALUES).map(Felt::from));
+        dictionary.extend_with_storage_values([Felt::from(-1)]);
+
+        assert_eq!(
+            dictionary.pick(&BigInt::from(-1), &BigInt::from(-1), &mut rng),
+            Some(BigInt::from(-1))
+        );
+    }
+}
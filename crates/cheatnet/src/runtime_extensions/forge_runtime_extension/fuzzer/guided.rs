@@ -0,0 +1,218 @@
+use super::FuzzerDraw;
+use crate::state::{CallTrace, CallTraceNode};
+use num_bigint::RandBigInt;
+use rand::Rng;
+use rand::prelude::StdRng;
+use starknet_api::core::ClassHash;
+use std::collections::HashSet;
+
+/// Program counters reached during a test run, together with the class they were executed in.
+pub type Coverage = HashSet<(Option<ClassHash>, usize)>;
+
+/// Maximal number of values changed in a single mutation of an input.
+const MAX_MUTATIONS: usize = 4;
+
+/// Maximal distance from the current value for the small step mutation.
+const MAX_STEP: i64 = 16;
+
+/// Values drawn in a passed fuzz test run together with the code reached by it.
+#[derive(Debug, Clone)]
+pub struct FuzzerFeedback {
+    pub draws: Vec<FuzzerDraw>,
+    pub coverage: Coverage,
+}
+
+#[derive(Debug)]
+struct InterestingInput {
+    draws: Vec<FuzzerDraw>,
+    new_coverage: usize,
+    times_mutated: usize,
+}
+
+impl InterestingInput {
+    /// Inputs that reached more new code are mutated more often,
+    /// but the priority decreases each time an input is picked.
+    fn priority(&self) -> usize {
+        self.new_coverage.div_ceil(self.times_mutated + 1)
+    }
+}
+
+/// Chooses inputs for consecutive runs of a fuzz test based on the coverage reached by previous runs.
+///
+/// Inputs of passed runs that reached code not covered before are saved and then mutated
+/// in later runs, the rest of runs draw fresh random values.
+#[derive(Debug, Default)]
+pub struct GuidedFuzzer {
+    covered: Coverage,
+    interesting_inputs: Vec<InterestingInput>,
+}
+
+impl GuidedFuzzer {
+    /// Returns draws to be replayed in the next run, or `None` if fresh random values should be used.
+    pub fn next_input(&mut self, rng: &mut StdRng) -> Option<Vec<FuzzerDraw>> {
+        // Keep exploring with random values in a quarter of runs
+        if rng.gen_ratio(1, 4) {
+            return None;
+        }
+
+        let input = self
+            .interesting_inputs
+            .iter_mut()
+            .max_by_key(|input| input.priority())?;
+        input.times_mutated += 1;
+
+        Some(mutate(&input.draws, rng))
+    }
+
+    /// Saves the input if it reached code that was not covered before.
+    /// Returns `true` if the input was saved.
+    pub fn add_feedback(&mut self, FuzzerFeedback { draws, coverage }: FuzzerFeedback) -> bool {
+        let new_coverage = coverage.difference(&self.covered).count();
+        if new_coverage == 0 {
+            return false;
+        }
+
+        self.covered.extend(coverage);
+        self.interesting_inputs.push(InterestingInput {
+            draws,
+            new_coverage,
+            times_mutated: 0,
+        });
+
+        true
+    }
+}
+
+/// Changes a few randomly chosen values of the input, keeping every value within its range.
+#[must_use]
+pub fn mutate(draws: &[FuzzerDraw], rng: &mut StdRng) -> Vec<FuzzerDraw> {
+    let mut draws = draws.to_vec();
+    if draws.is_empty() {
+        return draws;
+    }
+
+    for _ in 0..rng.gen_range(1..=MAX_MUTATIONS) {
+        let index = rng.gen_range(0..draws.len());
+        let FuzzerDraw { min, max, value } = draws[index].clone();
+
+        let new_value = match rng.gen_range(0..5) {
+            0 => min.clone(),
+            1 => max.clone(),
+            2 => value + rng.gen_range(-MAX_STEP..=MAX_STEP),
+            // Reusing a value drawn for another argument helps to pass equality checks
+            3 => draws[rng.gen_range(0..draws.len())].value.clone(),
+            _ => rng.gen_bigint_range(&min, &(&max + 1)),
+        };
+
+        draws[index].value = new_value.clamp(min, max);
+    }
+
+    draws
+}
+
+/// Collects program counters from the VM traces of the call and all its nested calls.
+#[must_use]
+pub fn collect_coverage(call_trace: &CallTrace) -> Coverage {
+    let mut coverage = Coverage::new();
+    add_coverage(call_trace, &mut coverage);
+    coverage
+}
+
+fn add_coverage(call_trace: &CallTrace, coverage: &mut Coverage) {
+    let class_hash = call_trace.entry_point.class_hash;
+    if let Some(vm_trace) = &call_trace.vm_trace {
+        coverage.extend(vm_trace.iter().map(|entry| (class_hash, entry.pc)));
+    }
+
+    for nested_call in call_trace
+        .nested_calls
+        .iter()
+        .filter_map(CallTraceNode::extract_entry_point_call)
+    {
+        add_coverage(&nested_call.borrow(), coverage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn draw(min: i64, max: i64, value: i64) -> FuzzerDraw {
+        FuzzerDraw {
+            min: min.into(),
+            max: max.into(),
+            value: value.into(),
+        }
+    }
+
+    fn coverage(pcs: &[usize]) -> Coverage {
+        pcs.iter().map(|pc| (None, *pc)).collect()
+    }
+
+    #[test]
+    fn mutated_values_stay_in_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let draws = vec![draw(0, 10, 5), draw(-3, 3, 0), draw(100, 100, 100)];
+
+        for _ in 0..1000 {
+            let mutated = mutate(&draws, &mut rng);
+
+            assert_eq!(mutated.len(), draws.len());
+            for (draw, original) in mutated.iter().zip(&draws) {
+                assert_eq!((&draw.min, &draw.max), (&original.min, &original.max));
+                assert!(draw.min <= draw.value && draw.value <= draw.max);
+            }
+        }
+    }
+
+    #[test]
+    fn saves_only_inputs_with_new_coverage() {
+        let mut fuzzer = GuidedFuzzer::default();
+
+        assert!(fuzzer.add_feedback(FuzzerFeedback {
+            draws: vec![draw(0, 10, 1)],
+            coverage: coverage(&[1, 2, 3]),
+        }));
+        assert!(!fuzzer.add_feedback(FuzzerFeedback {
+            draws: vec![draw(0, 10, 2)],
+            coverage: coverage(&[1, 3]),
+        }));
+        assert!(fuzzer.add_feedback(FuzzerFeedback {
+            draws: vec![draw(0, 10, 3)],
+            coverage: coverage(&[1, 4]),
+        }));
+
+        assert_eq!(fuzzer.interesting_inputs.len(), 2);
+        assert_eq!(fuzzer.covered, coverage(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn random_values_are_used_without_interesting_inputs() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut fuzzer = GuidedFuzzer::default();
+
+        for _ in 0..100 {
+            assert!(fuzzer.next_input(&mut rng).is_none());
+        }
+    }
+
+    #[test]
+    fn mutates_inputs_with_most_new_coverage_first() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut fuzzer = GuidedFuzzer::default();
+        fuzzer.add_feedback(FuzzerFeedback {
+            draws: vec![draw(0, 10, 1)],
+            coverage: coverage(&[1]),
+        });
+        fuzzer.add_feedback(FuzzerFeedback {
+            draws: vec![draw(0, 10, 2)],
+            coverage: coverage(&[2, 3, 4, 5]),
+        });
+
+        while fuzzer.next_input(&mut rng).is_none() {}
+
+        assert_eq!(fuzzer.interesting_inputs[0].times_mutated, 0);
+        assert_eq!(fuzzer.interesting_inputs[1].times_mutated, 1);
+    }
+}
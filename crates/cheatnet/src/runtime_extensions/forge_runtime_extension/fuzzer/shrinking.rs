@@ -0,0 +1,170 @@
+use super::FuzzerDraw;
+use num_bigint::BigInt;
+use num_traits::{One, Signed, Zero};
+
+/// Limit of test executions spent on shrinking a single failing fuzz test case.
+pub const MAX_SHRINK_RUNS: usize = 512;
+
+impl FuzzerDraw {
+    /// Value from the draw range that is considered the simplest one, i.e. closest to zero.
+    #[must_use]
+    pub fn shrink_target(&self) -> BigInt {
+        if self.min > BigInt::zero() {
+            self.min.clone()
+        } else if self.max < BigInt::zero() {
+            self.max.clone()
+        } else {
+            BigInt::zero()
+        }
+    }
+}
+
+/// Shrinks values drawn during a failing fuzz test run toward a minimal counterexample.
+///
+/// Every draw is first replaced with its shrink target and, if the test does not fail anymore,
+/// a binary search between the target and the current value is performed.
+/// Arrays and structs are shrunk too, as their lengths and fields are built from separate draws.
+///
+/// `run_still_fails` executes the test replaying provided draws and returns
+/// draws recorded during that execution if it failed, or `None` otherwise.
+pub fn shrink<F>(draws: Vec<FuzzerDraw>, mut run_still_fails: F) -> Vec<FuzzerDraw>
+where
+    F: FnMut(&[FuzzerDraw]) -> Option<Vec<FuzzerDraw>>,
+{
+    let mut current = draws;
+    let mut runs = 0;
+
+    loop {
+        let mut improved = false;
+        let mut index = 0;
+
+        while index < current.len() && runs < MAX_SHRINK_RUNS {
+            let target = current[index].shrink_target();
+            if current[index].value == target {
+                index += 1;
+                continue;
+            }
+
+            runs += 1;
+            if let Some(new_draws) = run_still_fails(&with_value(&current, index, target.clone())) {
+                current = new_draws;
+                improved = true;
+                index += 1;
+                continue;
+            }
+
+            // `passing` never fails, `current[index].value` always does
+            let mut passing = target;
+            while runs < MAX_SHRINK_RUNS {
+                let Some(failing) = current.get(index).map(|draw| draw.value.clone()) else {
+                    break;
+                };
+                let distance: BigInt = &failing - &passing;
+                if distance.abs() <= BigInt::one() {
+                    break;
+                }
+
+                let middle = &passing + distance / 2;
+                runs += 1;
+                match run_still_fails(&with_value(&current, index, middle.clone())) {
+                    Some(new_draws) => {
+                        current = new_draws;
+                        improved = true;
+                    }
+                    None => passing = middle,
+                }
+            }
+
+            index += 1;
+        }
+
+        if !improved || runs >= MAX_SHRINK_RUNS {
+            return current;
+        }
+    }
+}
+
+fn with_value(draws: &[FuzzerDraw], index: usize, value: BigInt) -> Vec<FuzzerDraw> {
+    let mut draws = draws.to_vec();
+    draws[index].value = value;
+    draws
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(min: i64, max: i64, value: i64) -> FuzzerDraw {
+        FuzzerDraw {
+            min: min.into(),
+            max: max.into(),
+            value: value.into(),
+        }
+    }
+
+    fn values(draws: &[FuzzerDraw]) -> Vec<BigInt> {
+        draws.iter().map(|draw| draw.value.clone()).collect()
+    }
+
+    #[test]
+    fn shrinks_to_target_when_always_failing() {
+        let draws = vec![draw(0, 1000, 537), draw(-50, 50, -13), draw(10, 20, 17)];
+
+        let shrunk = shrink(draws, |candidate| Some(candidate.to_vec()));
+
+        assert_eq!(values(&shrunk), vec![0.into(), 0.into(), 10.into()]);
+    }
+
+    #[test]
+    fn shrinks_to_boundary_value() {
+        let draws = vec![draw(0, i64::MAX, 987_654_321)];
+
+        let shrunk = shrink(draws, |candidate| {
+            (candidate[0].value >= 1000.into()).then(|| candidate.to_vec())
+        });
+
+        assert_eq!(values(&shrunk), vec![1000.into()]);
+    }
+
+    #[test]
+    fn shrinks_negative_values_toward_zero() {
+        let draws = vec![draw(-1000, 1000, -900)];
+
+        let shrunk = shrink(draws, |candidate| {
+            (candidate[0].value <= (-7).into()).then(|| candidate.to_vec())
+        });
+
+        assert_eq!(values(&shrunk), vec![(-7).into()]);
+    }
+
+    #[test]
+    fn uses_draws_recorded_by_the_run() {
+        // Length of an array followed by its elements, a run consumes only `length` elements
+        let draws = vec![
+            draw(0, 10, 3),
+            draw(0, 100, 50),
+            draw(0, 100, 60),
+            draw(0, 100, 70),
+        ];
+
+        let shrunk = shrink(draws, |candidate| {
+            let length = usize::try_from(&candidate[0].value).unwrap();
+            let consumed = candidate[..=length].to_vec();
+            consumed[1..]
+                .iter()
+                .any(|element| element.value >= 20.into())
+                .then_some(consumed)
+        });
+
+        assert_eq!(values(&shrunk), vec![1.into(), 20.into()]);
+    }
+
+    #[test]
+    fn does_not_change_passing_values() {
+        let draws = vec![draw(0, 100, 0), draw(0, 100, 5)];
+
+        let shrunk = shrink(draws.clone(), |_| None);
+
+        assert_eq!(shrunk, draws);
+    }
+}
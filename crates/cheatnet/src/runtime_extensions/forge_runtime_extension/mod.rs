@@ -1,4 +1,5 @@
 use self::contracts_data::ContractsData;
+use self::fuzzer::FuzzerSource;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use crate::runtime_extensions::common::{get_syscalls_gas_consumed, sum_syscall_usage};
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::replace_bytecode::ReplaceBytecodeError;
@@ -14,8 +15,10 @@ use crate::runtime_extensions::{
         deploy::{deploy, deploy_at},
         generate_random_felt::generate_random_felt,
         get_class_hash::get_class_hash,
+        invariant_call::invariant_call,
+        invoke_as_account::invoke_as_account,
         l1_handler_execute::l1_handler_execute,
-        storage::{calculate_variable_address, load, store},
+        storage::{calculate_storage_path_address, calculate_variable_address, load, store},
     },
 };
 use crate::state::{CallTrace, CallTraceNode};
@@ -40,7 +43,6 @@ use conversions::felt::{ToShortString, TryInferFormat};
 use conversions::serde::deserialize::BufferReader;
 use conversions::serde::serialize::CairoSerialize;
 use data_transformer::cairo_types::CairoU256;
-use rand::prelude::StdRng;
 use runtime::starknet::constants::TEST_CONTRACT_CLASS_HASH;
 use runtime::{
     CheatcodeHandlingResult, EnhancedHintError, ExtendedRuntime, ExtensionLogic,
@@ -53,19 +55,18 @@ use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
 
 pub mod cheatcodes;
 pub mod contracts_data;
 mod file_operations;
-mod fuzzer;
+pub mod fuzzer;
 
 pub type ForgeRuntime<'a> = ExtendedRuntime<ForgeExtension<'a>>;
 
 pub struct ForgeExtension<'a> {
     pub environment_variables: &'a HashMap<String, String>,
     pub contracts_data: &'a ContractsData,
-    pub fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    pub fuzzer_source: Option<FuzzerSource>,
 }
 
 // This runtime extension provides an implementation logic for functions from snforge_std library.
@@ -95,15 +96,15 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
             "mock_call" => {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
+                let calldata = input_reader.read()?;
                 let span = input_reader.read()?;
-
-                let ret_data: Vec<_> = input_reader.read()?;
+                let result = input_reader.read()?;
 
                 extended_runtime
                     .extended_runtime
                     .extension
                     .cheatnet_state
-                    .mock_call(contract_address, function_selector, &ret_data, span);
+                    .mock_call_with(contract_address, function_selector, calldata, result, span);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
             "stop_mock_call" => {
@@ -157,6 +158,111 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(res))
             }
+            "snapshot_state" => {
+                let snapshot_id = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .snapshot_state();
+
+                Ok(CheatcodeHandlingResult::from_serializable(snapshot_id))
+            }
+            "revert_to_snapshot" => {
+                let snapshot_id = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .revert_to_snapshot(snapshot_id)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "expect_call" => {
+                let expected_call = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_call(expected_call);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "expect_revert" => {
+                let panic_data = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_revert(panic_data);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "advance_block" => {
+                let blocks = input_reader.read()?;
+                let block_time = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .advance_block(blocks, block_time)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "cheat_gas_prices" => {
+                let gas_prices = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .cheat_gas_prices(gas_prices)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "stop_cheat_gas_prices" => {
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_cheat_gas_prices();
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "start_record_storage_access" => {
+                let target = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .start_record_storage_access(target);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "stop_record_storage_access" => {
+                let target = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_record_storage_access(target);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "get_storage_accesses" => {
+                let storage_accesses = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_storage_accesses();
+
+                Ok(CheatcodeHandlingResult::from_serializable(storage_accesses))
+            }
             "declare" => {
                 let state = &mut extended_runtime
                     .extended_runtime
@@ -275,6 +381,47 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     ),
                 }
             }
+            "invoke_as_account" => {
+                let account_address = input_reader.read()?;
+                let execute_calldata: Vec<_> = input_reader.read()?;
+                let options = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                let syscall_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let result = match invoke_as_account(
+                    syscall_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    account_address,
+                    &execute_calldata,
+                    options,
+                )? {
+                    CallResult::Success { ret_data } => Ok(ret_data),
+                    CallResult::Failure(CallFailure::Panic { panic_data }) => Err(panic_data),
+                    CallResult::Failure(CallFailure::Error { msg }) => {
+                        return Err(EnhancedHintError::from(HintError::CustomHint(Box::from(
+                            msg.to_string(),
+                        ))));
+                    }
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
+            "invariant_call" => {
+                let contract_address = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                invariant_call(
+                    &mut cheatnet_runtime.extended_runtime.hint_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    self.contracts_data,
+                    self.fuzzer_source.as_mut(),
+                    contract_address,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "read_txt" => {
                 let file_path: String = input_reader.read::<ByteArray>()?.to_string();
                 let parsed_content = file_operations::read_txt(file_path)?;
@@ -308,6 +455,26 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(events))
             }
+            "spy_calls" => {
+                let calls_offset = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .count_calls();
+
+                Ok(CheatcodeHandlingResult::from_serializable(calls_offset))
+            }
+            "get_calls" => {
+                let calls_offset = input_reader.read()?;
+
+                let calls = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_calls(calls_offset);
+
+                Ok(CheatcodeHandlingResult::from_serializable(calls))
+            }
             "spy_messages_to_l1" => {
                 let messages_offset = extended_runtime
                     .extended_runtime
@@ -493,6 +660,14 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     map_entry_address,
                 ))
             }
+            "storage_path_address" => {
+                let path: String = input_reader.read::<ByteArray>()?.to_string();
+                let storage_path_address = calculate_storage_path_address(&path)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    storage_path_address,
+                ))
+            }
             "generate_random_felt" => Ok(CheatcodeHandlingResult::from_serializable(
                 generate_random_felt(),
             )),
@@ -501,7 +676,7 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                 let max_value = input_reader.read()?;
 
                 Ok(CheatcodeHandlingResult::from_serializable(
-                    fuzzer::generate_arg(self.fuzzer_rng.clone(), min_value, max_value)?,
+                    fuzzer::generate_arg(self.fuzzer_source.as_mut(), min_value, max_value)?,
                 ))
             }
             "save_fuzzer_arg" => {
@@ -8,8 +8,15 @@ use crate::runtime_extensions::common::sum_syscall_usage;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
     ExecutionInfoMock, ResourceBounds,
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect_call::RegisteredExpectedCall;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_call::CallMocks;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::snapshot_state::{
+    StateSnapshot, StateWrites,
+};
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_access::StorageAccesses;
+use crate::runtime_extensions::forge_runtime_extension::fuzzer::dictionary::MAX_STORAGE_VALUES;
 use blockifier::execution::call_info::OrderedL2ToL1Message;
 use blockifier::execution::contract_class::RunnableCompiledClass;
 use blockifier::execution::entry_point::CallEntryPoint;
@@ -23,11 +30,11 @@ use cairo_vm::vm::trace::trace_entry::RelocatedTraceEntry;
 use conversions::serde::deserialize::CairoDeserialize;
 use conversions::serde::serialize::{BufferWriter, CairoSerialize};
 use conversions::string::TryFromHexStr;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use runtime::starknet::constants::TEST_CONTRACT_CLASS_HASH;
 use runtime::starknet::context::SerializableBlockInfo;
 use runtime::starknet::state::DictStateReader;
-use starknet_api::block::BlockInfo;
+use starknet_api::block::{BlockInfo, GasPriceVector};
 use starknet_api::core::{ChainId, EntryPointSelector};
 use starknet_api::transaction::fields::ContractAddressSalt;
 use starknet_api::{
@@ -36,9 +43,11 @@ use starknet_api::{
 };
 use starknet_types_core::felt::Felt;
 use std::cell::{Ref, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::num::NonZeroUsize;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 // Specifies the duration of the cheat
 #[derive(CairoDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
@@ -259,6 +268,20 @@ impl CallTrace {
             &self.used_syscalls_sierra_gas,
         )
     }
+
+    /// Visits all calls nested in the trace in the order they were made,
+    /// every call is followed by the calls nested in it
+    pub fn visit_nested_calls(&self, visit: &mut impl FnMut(&CallTrace)) {
+        for nested_call in self
+            .nested_calls
+            .iter()
+            .filter_map(CallTraceNode::extract_entry_point_call)
+        {
+            let nested_call = nested_call.borrow();
+            visit(&nested_call);
+            nested_call.visit_nested_calls(visit);
+        }
+    }
 }
 
 /// Enum representing node of a trace of a call.
@@ -373,8 +396,7 @@ pub struct CheatnetState {
     pub cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
     pub global_cheated_execution_info: ExecutionInfoMock,
 
-    pub mocked_functions:
-        HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt>>>>,
+    pub mocked_functions: HashMap<ContractAddress, HashMap<EntryPointSelector, CallMocks>>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
     pub detected_events: Vec<Event>,
     pub detected_messages_to_l1: Vec<MessageToL1>,
@@ -383,8 +405,28 @@ pub struct CheatnetState {
     pub trace_data: TraceData,
     pub encountered_errors: EncounteredErrors,
     pub fuzzer_args: Vec<String>,
+    /// Values read from and written to the storage, used to extend the fuzzer dictionary.
+    /// `None` if the test is not fuzzed
+    pub seen_storage_values: Option<IndexSet<Felt>>,
     pub block_hash_contracts: HashMap<(ContractAddress, u64), (CheatSpan, Felt)>,
     pub global_block_hash: HashMap<u64, (Felt, Vec<ContractAddress>)>,
+    /// Number of the block reached with `roll` or `warp`, hashes of blocks before it can be read
+    pub advanced_block_number: Option<u64>,
+    /// Gas prices set with `cheat_gas_prices`, replacing the ones of the block the test runs in
+    pub cheated_gas_prices: Option<GasPriceVector>,
+    /// Set from another thread once the test exceeds its timeout,
+    /// stops execution of the test code and all calls made by it
+    pub timed_out: Arc<AtomicBool>,
+    /// Writes made to the state of the test, shared with its `SnapshotableState`
+    pub state_writes: Rc<RefCell<StateWrites>>,
+    pub state_snapshots: Vec<StateSnapshot>,
+    /// Calls which must be made by the test, verified once it finishes
+    pub expected_calls: Vec<RegisteredExpectedCall>,
+    /// Panic data the next call made by the test is expected to revert with
+    pub expected_revert: Option<Vec<Felt>>,
+    /// Contracts whose storage accesses are recorded by `start_record_storage_access`
+    pub storage_access_recording_targets: HashSet<ContractAddress>,
+    pub storage_accesses: IndexMap<ContractAddress, StorageAccesses>,
 }
 
 pub type EncounteredErrors = IndexMap<ClassHash, Vec<usize>>;
@@ -413,8 +455,18 @@ impl Default for CheatnetState {
             },
             encountered_errors: IndexMap::default(),
             fuzzer_args: Vec::default(),
+            seen_storage_values: None,
             block_hash_contracts: HashMap::default(),
             global_block_hash: HashMap::default(),
+            advanced_block_number: None,
+            cheated_gas_prices: None,
+            timed_out: Arc::default(),
+            state_writes: Rc::default(),
+            state_snapshots: vec![],
+            expected_calls: vec![],
+            expected_revert: None,
+            storage_access_recording_targets: HashSet::default(),
+            storage_accesses: IndexMap::default(),
         }
     }
 }
@@ -523,6 +575,15 @@ impl CheatnetState {
         self.fuzzer_args.push(arg);
     }
 
+    pub fn record_seen_storage_value(&mut self, value: Felt) {
+        if let Some(seen_storage_values) = &mut self.seen_storage_values {
+            // Values over the limit wouldn't fit in the fuzzer dictionary anyway
+            if seen_storage_values.len() < MAX_STORAGE_VALUES {
+                seen_storage_values.insert(value);
+            }
+        }
+    }
+
     pub fn register_error(&mut self, class_hash: ClassHash, pcs: Vec<usize>) {
         self.encountered_errors.insert(class_hash, pcs);
     }
@@ -3,9 +3,13 @@ use starknet::providers::jsonrpc::HttpTransport;
 use starknet::providers::{JsonRpcClient, Provider, ProviderError};
 use starknet_api::block::BlockNumber;
 use starknet_types_core::felt::Felt;
+use std::time::Duration;
 use tokio::runtime::Runtime;
 use url::Url;
 
+/// Time after which a request to the fork RPC node fails, so a test doesn't hang on an unresponsive node
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
 #[derive(Debug)]
 pub struct SyncClient {
     client: JsonRpcClient<HttpTransport>,
@@ -17,7 +21,13 @@ impl SyncClient {
     #[must_use]
     pub fn new(url: Url, block_number: BlockNumber) -> Self {
         Self {
-            client: JsonRpcClient::new(HttpTransport::new(url)),
+            client: JsonRpcClient::new(HttpTransport::new_with_client(
+                url,
+                reqwest::Client::builder()
+                    .timeout(REQUEST_TIMEOUT)
+                    .build()
+                    .expect("Could not instantiate HTTP client"),
+            )),
             block_id: BlockId::Number(block_number.0),
             runtime: Runtime::new().expect("Could not instantiate Runtime"),
         }
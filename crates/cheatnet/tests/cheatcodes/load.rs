@@ -1,6 +1,6 @@
 use crate::common::get_contracts;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::storage::{
-    load, map_entry_address, variable_address,
+    calculate_storage_path_address, load, map_entry_address, variable_address,
 };
 use starknet_api::core::ContractAddress;
 use starknet_types_core::felt::Felt;
@@ -56,3 +56,45 @@ fn load_state_map_simple_value() {
         "Wrong data value was returned: {map_value}"
     );
 }
+
+#[test]
+fn load_state_map_simple_value_by_path() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("MapSimpleValueSimpleKey", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash, &[]);
+
+    let map_key = Felt::from(420);
+    let inserted_value = Felt::from(69);
+    test_env.call_contract(&contract_address, "insert", &[map_key, inserted_value]);
+
+    let var_address = calculate_storage_path_address("values[0x1a4]").unwrap();
+    assert_eq!(var_address, map_entry_address("values", &[map_key]));
+
+    let map_value = test_env.load(contract_address, var_address);
+
+    assert_eq!(
+        map_value, inserted_value,
+        "Wrong data value was returned: {map_value}"
+    );
+}
+
+#[test]
+fn invalid_storage_paths() {
+    for (path, reason) in [
+        ("", "expected a storage variable name"),
+        ("values.", "expected a member name after `.`"),
+        ("values[1", "missing closing `]`"),
+        ("values[]", "invalid key part ``"),
+        ("values[wrong]", "invalid key part `wrong`"),
+        ("values(1)", "unexpected `(1)`"),
+    ] {
+        let error = calculate_storage_path_address(path).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            format!("Invalid storage path `{path}`: {reason}")
+        );
+    }
+}
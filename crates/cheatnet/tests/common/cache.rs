@@ -1,24 +1,25 @@
-use glob::glob;
+use camino::Utf8Path;
+use cheatnet::forking::cache::ForkCacheContent;
 use serde_json::{Map, Value};
+use shared::test_utils::node_url::node_rpc_url;
+use starknet_api::block::BlockNumber;
 use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-pub fn read_cache(file_pattern: &str) -> Map<String, Value> {
-    let mut cache_files = glob(file_pattern).unwrap().filter_map(Result::ok);
+pub fn read_cache(cache_dir: &str, block_number: u64) -> Map<String, Value> {
+    let cache_content = ForkCacheContent::load(
+        &node_rpc_url(),
+        BlockNumber(block_number),
+        Utf8Path::new(cache_dir),
+    )
+    .expect("Could not read cache")
+    .expect("Cache file not found");
 
-    let cache_file = match (cache_files.next(), cache_files.next()) {
-        (None, None) => panic!("Cache file not found"),
-        (Some(cache_file), None) => cache_file,
-        _ => panic!("Multiple matching cache files found"),
-    };
-
-    let cache_content = fs::read_to_string(cache_file).expect("Could not read cache");
-    let parsed_cache_content: Value =
-        serde_json::from_str(&cache_content).expect("Could not parse cache");
-    parsed_cache_content
+    serde_json::to_value(cache_content)
+        .expect("Could not serialize cache")
         .as_object()
-        .expect("Parsed cache is not an object")
+        .expect("Serialized cache is not an object")
         .clone()
 }
 
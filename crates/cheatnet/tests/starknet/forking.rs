@@ -6,7 +6,7 @@ use blockifier::state::cached_state::CachedState;
 use cairo_vm::vm::errors::hint_errors::HintError;
 use camino::Utf8Path;
 use cheatnet::constants::build_testing_state;
-use cheatnet::forking::cache::cache_version;
+use cheatnet::forking::fixture::ForkFixture;
 use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::CheatcodeError;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::storage::selector_from_name;
@@ -17,10 +17,13 @@ use conversions::string::TryFromHexStr;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use runtime::EnhancedHintError;
 use serde_json::Value;
+use shared::test_utils::node_url::node_rpc_url;
 use starknet_api::block::BlockNumber;
-use starknet_api::core::ContractAddress;
+use starknet_api::core::{ChainId, ContractAddress};
 use starknet_types_core::felt::Felt;
+use std::fs;
 use tempfile::TempDir;
+use url::Url;
 
 #[test]
 fn fork_simple() {
@@ -405,13 +408,7 @@ fn using_specified_block_nb_is_cached() {
 
     let assert_cache = || {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().to_str().unwrap(), 53_669);
         assert_eq!(
             cache["storage_at"].as_object().unwrap()
                 ["0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9"]
@@ -485,13 +482,7 @@ fn test_cache_merging() {
 
     let assert_cache = || {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().to_str().unwrap(), 53_680);
 
         let contract_1_class_hash =
             "0x6a7eb29ee38b0a0b198e39ed6ad458d2e460264b463351a0acfc05822d61550";
@@ -598,13 +589,7 @@ fn test_cached_block_info_merging() {
 
     let assert_cached_block_info = |is_block_info_cached: bool| {
         // Assertions
-        let cache = read_cache(
-            cache_dir
-                .path()
-                .join(format!("*v{}.json", cache_version()))
-                .to_str()
-                .unwrap(),
-        );
+        let cache = read_cache(cache_dir.path().to_str().unwrap(), 53_680);
         if is_block_info_cached {
             assert_eq!(
                 cache["block_info"].as_object().unwrap()["block_number"]
@@ -673,3 +658,265 @@ fn test_calling_nonexistent_url() {
         "Unable to reach the node. Check your internet connection and node url",
     );
 }
+
+#[test]
+fn offline_fork_reads_only_from_cache() {
+    let cache_dir = TempDir::new().unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+
+    // Fill the cache with values read by `get_balance`
+    {
+        let mut cached_state =
+            create_fork_cached_state_at(53_669, cache_dir.path().to_str().unwrap());
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector_from_name("get_balance"),
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(
+            ForkStateReader::new_offline(
+                node_rpc_url(),
+                BlockNumber(53_669),
+                Utf8Path::from_path(cache_dir.path()).unwrap(),
+            )
+            .unwrap(),
+        ),
+    });
+    let mut cheatnet_state = CheatnetState::default();
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut cheatnet_state,
+        &contract_address,
+        selector_from_name("get_balance"),
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut cheatnet_state,
+        &ContractAddress::try_from_hex_str("0x1234").unwrap(),
+        selector_from_name("get_balance"),
+        &[],
+    );
+    assert_error(
+        output,
+        "Class hash of contract 0x1234 is missing in the fork cache and can't be fetched in offline mode",
+    );
+}
+
+#[test]
+fn exported_fork_fixture_imported_to_another_cache() {
+    let cache_dir = TempDir::new().unwrap();
+    let imported_cache_dir = TempDir::new().unwrap();
+    let fixture_dir = TempDir::new().unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+
+    // Fill the cache with values read by `get_balance`
+    {
+        let mut cached_state =
+            create_fork_cached_state_at(53_669, cache_dir.path().to_str().unwrap());
+        let _ = cached_state.state.get_block_info().unwrap();
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector_from_name("get_balance"),
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+
+    let fixture_path = Utf8Path::from_path(fixture_dir.path())
+        .unwrap()
+        .join("fork_fixture.json");
+    ForkFixture::export(
+        node_rpc_url(),
+        BlockNumber(53_669),
+        Utf8Path::from_path(cache_dir.path()).unwrap(),
+    )
+    .unwrap()
+    .save(&fixture_path)
+    .unwrap();
+
+    // Url of the node, which may contain credentials, is not saved
+    let fixture_content = fs::read_to_string(&fixture_path).unwrap();
+    assert!(!fixture_content.contains(node_rpc_url().host_str().unwrap()));
+
+    let fixture = ForkFixture::load(&fixture_path).unwrap();
+    assert_eq!(fixture.chain_id, ChainId::Sepolia);
+    assert_eq!(fixture.block_number, BlockNumber(53_669));
+
+    // Fixture can be used with any node on its chain
+    let other_url = Url::parse("http://other.node:5050/rpc").unwrap();
+    fixture
+        .import(
+            &other_url,
+            Utf8Path::from_path(imported_cache_dir.path()).unwrap(),
+        )
+        .unwrap();
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(
+            ForkStateReader::new_offline(
+                other_url,
+                BlockNumber(53_669),
+                Utf8Path::from_path(imported_cache_dir.path()).unwrap(),
+            )
+            .unwrap(),
+        ),
+    });
+    let block_info = cached_state.state.get_block_info().unwrap();
+    assert_eq!(block_info.block_number, BlockNumber(53_669));
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut CheatnetState::default(),
+        &contract_address,
+        selector_from_name("get_balance"),
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+}
+
+#[test]
+fn legacy_json_cache_is_migrated() {
+    let cache_dir = TempDir::new().unwrap();
+    let legacy_cache_dir = TempDir::new().unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+
+    // Fill the cache with values read by `get_balance`
+    {
+        let mut cached_state =
+            create_fork_cached_state_at(53_669, cache_dir.path().to_str().unwrap());
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector_from_name("get_balance"),
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+
+    // Save the cache in the JSON format used by older versions
+    let mut legacy_cache = read_cache(cache_dir.path().to_str().unwrap(), 53_669);
+    legacy_cache.insert("cache_version".to_string(), Value::from("0_46_0"));
+    let sanitized_url = node_rpc_url()
+        .as_str()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let legacy_cache_file = legacy_cache_dir
+        .path()
+        .join(format!("{sanitized_url}_53669_v0_46_0.json"));
+    fs::write(&legacy_cache_file, Value::Object(legacy_cache).to_string()).unwrap();
+    // Caches saved by other versions are removed too, even if they can't be migrated
+    let other_legacy_cache_file = legacy_cache_dir
+        .path()
+        .join(format!("{sanitized_url}_53669_v0_45_0.json"));
+    fs::write(&other_legacy_cache_file, "{}").unwrap();
+    let stale_cache_file = legacy_cache_dir
+        .path()
+        .join(format!("{sanitized_url}_53669_v0.cache"));
+    fs::write(&stale_cache_file, "stale").unwrap();
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(
+            ForkStateReader::new_offline(
+                node_rpc_url(),
+                BlockNumber(53_669),
+                Utf8Path::from_path(legacy_cache_dir.path()).unwrap(),
+            )
+            .unwrap(),
+        ),
+    });
+    assert!(!legacy_cache_file.exists());
+    assert!(!other_legacy_cache_file.exists());
+    assert!(!stale_cache_file.exists());
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut CheatnetState::default(),
+        &contract_address,
+        selector_from_name("get_balance"),
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+}
+
+#[test]
+fn cache_with_interrupted_write_is_compacted() {
+    let cache_dir = TempDir::new().unwrap();
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+
+    // Fill the cache with values read by `get_balance`
+    {
+        let mut cached_state =
+            create_fork_cached_state_at(53_669, cache_dir.path().to_str().unwrap());
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector_from_name("get_balance"),
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+
+    // Append a record cut off in the middle of writing it
+    let cache_file = fs::read_dir(cache_dir.path())
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let cache_file_content = fs::read(&cache_file).unwrap();
+    fs::write(
+        &cache_file,
+        [cache_file_content.as_slice(), &[0xff; 16]].concat(),
+    )
+    .unwrap();
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(
+            ForkStateReader::new_offline(
+                node_rpc_url(),
+                BlockNumber(53_669),
+                Utf8Path::from_path(cache_dir.path()).unwrap(),
+            )
+            .unwrap(),
+        ),
+    });
+    assert!(!fs::read(&cache_file).unwrap().ends_with(&[0xff; 16]));
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut CheatnetState::default(),
+        &contract_address,
+        selector_from_name("get_balance"),
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+}
@@ -185,7 +185,7 @@ pub fn build_profiler_call_entry_point(
     }
 }
 
-fn get_contract_name(
+pub(crate) fn get_contract_name(
     class_hash: Option<ClassHash>,
     contracts_data: &ContractsData,
 ) -> Option<String> {
@@ -198,7 +198,7 @@ fn get_contract_name(
     }
 }
 
-fn get_function_name(
+pub(crate) fn get_function_name(
     entry_point_selector: &EntryPointSelector,
     contracts_data: &ContractsData,
     fork_data: &ForkData,
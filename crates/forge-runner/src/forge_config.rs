@@ -1,12 +1,14 @@
 use blockifier::execution::contract_class::TrackedResource;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerDraw;
 use clap::ValueEnum;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Debug, PartialEq)]
 pub struct ForgeConfig {
@@ -19,14 +21,22 @@ pub struct TestRunnerConfig {
     pub exit_first: bool,
     pub fuzzer_runs: NonZeroU32,
     pub fuzzer_seed: u64,
+    pub fuzzer_corpus: FuzzerCorpus,
+    pub fuzzer_strategy: FuzzerStrategy,
     pub max_n_steps: Option<u32>,
+    pub test_timeout: Option<Duration>,
     pub is_vm_trace_needed: bool,
+    pub gas_report: bool,
     pub cache_dir: Utf8PathBuf,
+    pub fork_mode: ForkMode,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
     pub tracked_resource: ForgeTrackedResource,
 }
 
+/// Fuzzer draws of failing runs from previous executions, keyed by test case name
+pub type FuzzerCorpus = HashMap<String, Vec<Vec<FuzzerDraw>>>;
+
 #[derive(Debug, PartialEq)]
 pub struct OutputConfig {
     pub detailed_resources: bool,
@@ -48,6 +58,25 @@ pub enum ForgeTrackedResource {
     SierraGas,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForkMode {
+    /// Read the fork state from the cache, fetching values missing in it from the node
+    #[default]
+    Online,
+    /// Read the fork state only from the cache, without connecting to the node
+    Offline,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum FuzzerStrategy {
+    /// Draw arguments of every run uniformly at random
+    #[default]
+    Random,
+    /// Mutate arguments of passed runs that reached code not covered by previous runs
+    Guided,
+}
+
 impl From<&ForgeTrackedResource> for TrackedResource {
     fn from(m: &ForgeTrackedResource) -> Self {
         match m {
@@ -82,8 +111,11 @@ impl ExecutionDataToSave {
 /// [`TestRunnerConfig`] to another function.
 pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
+    pub test_timeout: Option<Duration>,
     pub is_vm_trace_needed: bool,
+    pub gas_report: bool,
     pub cache_dir: &'a Utf8PathBuf,
+    pub fork_mode: ForkMode,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
     pub tracked_resource: &'a ForgeTrackedResource,
@@ -94,8 +126,11 @@ impl<'a> RuntimeConfig<'a> {
     pub fn from(value: &'a TestRunnerConfig) -> RuntimeConfig<'a> {
         Self {
             max_n_steps: value.max_n_steps,
+            test_timeout: value.test_timeout,
             is_vm_trace_needed: value.is_vm_trace_needed,
+            gas_report: value.gas_report,
             cache_dir: &value.cache_dir,
+            fork_mode: value.fork_mode,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
             tracked_resource: &value.tracked_resource,
@@ -0,0 +1,110 @@
+use crate::build_trace_data::{get_contract_name, get_function_name};
+use blockifier::blockifier_versioned_constants::VersionedConstants;
+use blockifier::fee::resources::ComputationResources;
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use cheatnet::forking::data::ForkData;
+use cheatnet::runtime_extensions::common::get_syscalls_gas_consumed;
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::state::{CallTrace, CallTraceNode};
+use starknet_api::execution_resources::GasAmount;
+use starknet_api::transaction::fields::GasVectorComputationMode;
+use std::collections::BTreeMap;
+
+/// Function of a deployed contract
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FunctionId {
+    pub contract_name: String,
+    pub function_name: String,
+}
+
+/// L2 gas used by every call to functions of deployed contracts made during a test
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FunctionsGasUsage(BTreeMap<FunctionId, Vec<u64>>);
+
+impl FunctionsGasUsage {
+    /// Collects gas used by all calls nested in the call of the test code.
+    /// Gas of a call includes gas of its nested calls.
+    #[must_use]
+    pub fn from_call_trace(
+        call_trace: &CallTrace,
+        contracts_data: &ContractsData,
+        fork_data: &ForkData,
+        versioned_constants: &VersionedConstants,
+    ) -> Self {
+        let mut functions_gas = Self::default();
+        functions_gas.add_nested_calls(call_trace, contracts_data, fork_data, versioned_constants);
+        functions_gas
+    }
+
+    fn add_nested_calls(
+        &mut self,
+        call_trace: &CallTrace,
+        contracts_data: &ContractsData,
+        fork_data: &ForkData,
+        versioned_constants: &VersionedConstants,
+    ) {
+        for nested_call in call_trace
+            .nested_calls
+            .iter()
+            .filter_map(CallTraceNode::extract_entry_point_call)
+        {
+            let nested_call = nested_call.borrow();
+            let entry_point = &nested_call.entry_point;
+
+            let function_id = FunctionId {
+                contract_name: get_contract_name(entry_point.class_hash, contracts_data)
+                    .or_else(|| {
+                        entry_point
+                            .class_hash
+                            .map(|class_hash| class_hash.0.to_hex_string())
+                    })
+                    .unwrap_or_default(),
+                function_name: get_function_name(
+                    &entry_point.entry_point_selector,
+                    contracts_data,
+                    fork_data,
+                )
+                .unwrap_or_else(|| entry_point.entry_point_selector.0.to_hex_string()),
+            };
+            let l2_gas = call_l2_gas(&nested_call, versioned_constants);
+            self.0.entry(function_id).or_default().push(l2_gas);
+
+            self.add_nested_calls(&nested_call, contracts_data, fork_data, versioned_constants);
+        }
+    }
+
+    pub fn extend(&mut self, other: Self) {
+        for (function_id, gas_usages) in other.0 {
+            self.0.entry(function_id).or_default().extend(gas_usages);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&FunctionId, &[u64])> {
+        self.0
+            .iter()
+            .map(|(function_id, gas_usages)| (function_id, gas_usages.as_slice()))
+    }
+}
+
+/// Trace keeps resources of syscalls separately, they are added back to get the full cost of the call
+fn call_l2_gas(call_trace: &CallTrace, versioned_constants: &VersionedConstants) -> u64 {
+    let mut vm_resources: ExecutionResources =
+        call_trace.used_execution_resources.filter_unused_builtins();
+    vm_resources += &versioned_constants
+        .get_additional_os_syscall_resources(&call_trace.used_syscalls_vm_resources);
+    let sierra_gas = call_trace.gas_consumed
+        + get_syscalls_gas_consumed(&call_trace.used_syscalls_sierra_gas, versioned_constants);
+
+    let computation_resources = ComputationResources {
+        tx_vm_resources: vm_resources,
+        os_vm_resources: ExecutionResources::default(),
+        n_reverted_steps: 0,
+        sierra_gas: GasAmount(sierra_gas),
+        reverted_sierra_gas: GasAmount::ZERO,
+    };
+
+    computation_resources
+        .to_gas_vector(versioned_constants, &GasVectorComputationMode::All)
+        .l2_gas
+        .0
+}
@@ -16,9 +16,10 @@ use cheatnet::runtime_extensions::forge_config_extension::config::RawAvailableGa
 use cheatnet::state::ExtendedStateReader;
 use foundry_ui::UI;
 use foundry_ui::components::warning::WarningMessage;
+use starknet_api::block::GasPriceVector;
 use starknet_api::execution_resources::{GasAmount, GasVector};
 use starknet_api::transaction::EventContent;
-use starknet_api::transaction::fields::GasVectorComputationMode;
+use starknet_api::transaction::fields::{Fee, GasVectorComputationMode};
 
 pub fn calculate_used_gas(
     transaction_context: &TransactionContext,
@@ -65,6 +66,35 @@ pub fn calculate_used_gas(
     ))
 }
 
+/// Calculates the fee paid for the gas used by the test, in FRI.
+/// Uses the gas prices set with `cheat_gas_prices` or the STRK gas prices of the block otherwise.
+#[must_use]
+pub fn calculate_fee(
+    transaction_context: &TransactionContext,
+    gas_used: &GasVector,
+    cheated_gas_prices: Option<&GasPriceVector>,
+) -> Fee {
+    let gas_prices = cheated_gas_prices.unwrap_or(
+        &transaction_context
+            .block_context
+            .block_info()
+            .gas_prices
+            .strk_gas_prices,
+    );
+
+    let fee = [
+        (gas_used.l1_gas, gas_prices.l1_gas_price),
+        (gas_used.l1_data_gas, gas_prices.l1_data_gas_price),
+        (gas_used.l2_gas, gas_prices.l2_gas_price),
+    ]
+    .into_iter()
+    .fold(0_u128, |fee, (gas, price)| {
+        fee.saturating_add(u128::from(gas.0).saturating_mul(price.get().0))
+    });
+
+    Fee(fee)
+}
+
 fn get_archival_data_resources(events: Vec<EventContent>) -> ArchivalDataResources {
     // Based on from https://github.com/starkware-libs/sequencer/blob/fc0f06a07f3338ae1e11612dcaed9c59373bca37/crates/blockifier/src/execution/call_info.rs#L222
     let mut event_summary = EventSummary {
@@ -182,6 +212,8 @@ pub fn check_available_gas(
                     gas_info.l1_gas, gas_info.l1_data_gas, gas_info.l2_gas
                 )),
                 fuzzer_args: Vec::default(),
+                fuzzer_draws: Vec::default(),
+                shrunk_fuzzer_args: None,
                 test_statistics: (),
                 debugging_trace,
             }
@@ -1,13 +1,22 @@
 use crate::coverage_api::run_coverage;
 use crate::debugging::TraceVerbosity;
-use crate::forge_config::{ExecutionDataToSave, ForgeConfig, TestRunnerConfig};
-use crate::running::{run_fuzz_test, run_test};
-use crate::test_case_summary::TestCaseSummary;
+use crate::forge_config::{ExecutionDataToSave, ForgeConfig, FuzzerStrategy, TestRunnerConfig};
+use crate::messages::{FuzzerProgressMessage, TestStartedMessage};
+use crate::running::{run_fuzz_test, run_shrinking, run_test};
+use crate::test_case_summary::{Single, TestCaseSummary};
 use anyhow::Result;
 use build_trace_data::save_trace_data;
 use cairo_lang_sierra::program::{ConcreteTypeLongId, Function, TypeDeclaration};
 use camino::Utf8PathBuf;
-use cheatnet::runtime_extensions::forge_config_extension::config::RawFuzzerConfig;
+use cheatnet::runtime_extensions::forge_config_extension::config::{
+    RawFuzzerConfig, RawInvariantConfig,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::invariant_call::AbiCache;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::dictionary::FuzzerDictionary;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::guided::{
+    FuzzerFeedback, GuidedFuzzer,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::{FuzzerDraw, FuzzerSource};
 use foundry_ui::UI;
 use foundry_ui::components::warning::WarningMessage;
 use futures::StreamExt;
@@ -20,6 +29,7 @@ use shared::spinner::Spinner;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use test_case_summary::{AnyTestCaseSummary, Fuzzing};
 use tokio::sync::mpsc::{Sender, channel};
 use tokio::task::JoinHandle;
@@ -29,6 +39,7 @@ pub mod build_trace_data;
 pub mod coverage_api;
 pub mod expected_result;
 pub mod forge_config;
+pub mod functions_gas;
 pub mod package_tests;
 pub mod profiler_api;
 pub mod test_case_summary;
@@ -104,6 +115,7 @@ pub fn maybe_generate_coverage(
     Ok(())
 }
 
+/// Runs the test case and returns its summary together with the time spent executing it.
 #[must_use]
 pub fn run_for_test_case(
     case: Arc<TestCaseWithResolvedConfig>,
@@ -113,11 +125,11 @@ pub fn run_for_test_case(
     send: Sender<()>,
     trace_verbosity: Option<TraceVerbosity>,
     ui: &Arc<UI>,
-) -> JoinHandle<Result<AnyTestCaseSummary>> {
-    if case.config.fuzzer_config.is_none() {
+) -> JoinHandle<Result<(AnyTestCaseSummary, Duration)>> {
+    if case.config.fuzzer_config.is_none() && case.config.invariant_config.is_none() {
         let ui = ui.clone();
         tokio::task::spawn(async move {
-            let res = run_test(
+            let (res, duration) = run_test(
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
@@ -127,12 +139,12 @@ pub fn run_for_test_case(
                 ui,
             )
             .await?;
-            Ok(AnyTestCaseSummary::Single(res))
+            Ok((AnyTestCaseSummary::Single(res), duration))
         })
     } else {
         let ui = ui.clone();
         tokio::task::spawn(async move {
-            let res = run_with_fuzzing(
+            let (res, duration) = run_with_fuzzing(
                 case,
                 casm_program,
                 forge_config.test_runner_config.clone(),
@@ -142,11 +154,12 @@ pub fn run_for_test_case(
                 ui,
             )
             .await??;
-            Ok(AnyTestCaseSummary::Fuzzing(res))
+            Ok((AnyTestCaseSummary::Fuzzing(res), duration))
         })
     }
 }
 
+#[expect(clippy::too_many_lines)]
 fn run_with_fuzzing(
     case: Arc<TestCaseWithResolvedConfig>,
     casm_program: Arc<AssembledProgramWithDebugInfo>,
@@ -155,53 +168,192 @@ fn run_with_fuzzing(
     send: Sender<()>,
     trace_verbosity: Option<TraceVerbosity>,
     ui: Arc<UI>,
-) -> JoinHandle<Result<TestCaseSummary<Fuzzing>>> {
+) -> JoinHandle<Result<(TestCaseSummary<Fuzzing>, Duration)>> {
     tokio::task::spawn(async move {
         if send.is_closed() {
-            return Ok(TestCaseSummary::Interrupted {});
+            return Ok((TestCaseSummary::Interrupted {}, Duration::ZERO));
         }
+        ui.println_json_only(&TestStartedMessage::new(&case.name));
 
         let (fuzzing_send, mut fuzzing_rec) = channel(1);
 
-        let (fuzzer_runs, fuzzer_seed) = match case.config.fuzzer_config {
-            Some(RawFuzzerConfig { runs, seed }) => (
-                runs.unwrap_or(test_runner_config.fuzzer_runs),
-                seed.unwrap_or(test_runner_config.fuzzer_seed),
-            ),
-            _ => (
-                test_runner_config.fuzzer_runs,
-                test_runner_config.fuzzer_seed,
-            ),
+        // Invariant tests are run as fuzz tests, where the fuzzer draws sequences of calls
+        let (runs, seed) = match (&case.config.fuzzer_config, &case.config.invariant_config) {
+            (Some(RawFuzzerConfig { runs, seed }), _)
+            | (None, Some(RawInvariantConfig { runs, seed })) => (*runs, *seed),
+            (None, None) => (None, None),
         };
+        let fuzzer_runs = runs.unwrap_or(test_runner_config.fuzzer_runs);
+        let fuzzer_seed = seed.unwrap_or(test_runner_config.fuzzer_seed);
 
         let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
+        let dictionary = Arc::new(Mutex::new(FuzzerDictionary::from_programs(
+            &casm_program.assembled_cairo_program.bytecode,
+            &test_runner_config.contracts_data,
+        )));
+        let abi_cache = Arc::new(Mutex::new(AbiCache::default()));
 
-        let mut tasks = FuturesUnordered::new();
+        let mut results = vec![];
+        let mut failing_fuzzer_draws = None;
+        let mut guided_fuzzer = GuidedFuzzer::default();
+        // Sum of the time spent in all runs, excluding the time runs wait for a free thread
+        let mut duration = Duration::ZERO;
 
-        for _ in 1..=fuzzer_runs.get() {
-            let ui = ui.clone();
-            tasks.push(run_fuzz_test(
+        // Inputs that failed in previous runs are replayed one by one before drawing random ones
+        let corpus_inputs = test_runner_config
+            .fuzzer_corpus
+            .get(&case.name)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let total_runs = usize::try_from(fuzzer_runs.get())? + corpus_inputs.len();
+        for fuzzer_draws in corpus_inputs {
+            let (result, fuzzer_feedback, run_duration) = run_fuzz_test(
                 case.clone(),
                 casm_program.clone(),
                 test_runner_config.clone(),
                 versioned_program_path.clone(),
                 send.clone(),
                 fuzzing_send.clone(),
-                rng.clone(),
+                FuzzerSource::replay(
+                    rng.clone(),
+                    dictionary.clone(),
+                    abi_cache.clone(),
+                    fuzzer_draws,
+                ),
                 trace_verbosity,
-                ui,
-            ));
+                ui.clone(),
+            )
+            .await?;
+            duration += run_duration;
+
+            if let Some(fuzzer_draws) = record_fuzz_run(
+                result,
+                fuzzer_feedback,
+                &mut results,
+                &mut guided_fuzzer,
+                total_runs,
+                &ui,
+            ) {
+                fuzzing_rec.close();
+                failing_fuzzer_draws = Some(fuzzer_draws);
+                break;
+            }
         }
 
-        let mut results = vec![];
-        while let Some(task) = tasks.next().await {
-            let result = task?;
+        if failing_fuzzer_draws.is_none() {
+            match test_runner_config.fuzzer_strategy {
+                FuzzerStrategy::Random => {
+                    let mut tasks = FuturesUnordered::new();
 
-            results.push(result.clone());
+                    for _ in 1..=fuzzer_runs.get() {
+                        let ui = ui.clone();
+                        tasks.push(run_fuzz_test(
+                            case.clone(),
+                            casm_program.clone(),
+                            test_runner_config.clone(),
+                            versioned_program_path.clone(),
+                            send.clone(),
+                            fuzzing_send.clone(),
+                            FuzzerSource::random(
+                                rng.clone(),
+                                dictionary.clone(),
+                                abi_cache.clone(),
+                            ),
+                            trace_verbosity,
+                            ui,
+                        ));
+                    }
 
-            if let TestCaseSummary::Failed { .. } = result {
-                fuzzing_rec.close();
-                break;
+                    while let Some(task) = tasks.next().await {
+                        let (result, fuzzer_feedback, run_duration) = task?;
+                        duration += run_duration;
+
+                        if let Some(fuzzer_draws) = record_fuzz_run(
+                            result,
+                            fuzzer_feedback,
+                            &mut results,
+                            &mut guided_fuzzer,
+                            total_runs,
+                            &ui,
+                        ) {
+                            fuzzing_rec.close();
+                            failing_fuzzer_draws = Some(fuzzer_draws);
+                            break;
+                        }
+                    }
+                }
+                FuzzerStrategy::Guided => {
+                    // Runs are executed one by one, as each of them depends on the coverage of previous ones
+                    for _ in 1..=fuzzer_runs.get() {
+                        let next_input = guided_fuzzer.next_input(
+                            &mut rng.lock().expect("Failed to acquire lock on fuzzer_rng"),
+                        );
+                        let fuzzer_source = match next_input {
+                            Some(fuzzer_draws) => FuzzerSource::replay(
+                                rng.clone(),
+                                dictionary.clone(),
+                                abi_cache.clone(),
+                                &fuzzer_draws,
+                            ),
+                            None => FuzzerSource::random(
+                                rng.clone(),
+                                dictionary.clone(),
+                                abi_cache.clone(),
+                            ),
+                        };
+
+                        let (result, fuzzer_feedback, run_duration) = run_fuzz_test(
+                            case.clone(),
+                            casm_program.clone(),
+                            test_runner_config.clone(),
+                            versioned_program_path.clone(),
+                            send.clone(),
+                            fuzzing_send.clone(),
+                            fuzzer_source,
+                            trace_verbosity,
+                            ui.clone(),
+                        )
+                        .await?;
+                        duration += run_duration;
+
+                        if let Some(fuzzer_draws) = record_fuzz_run(
+                            result,
+                            fuzzer_feedback,
+                            &mut results,
+                            &mut guided_fuzzer,
+                            total_runs,
+                            &ui,
+                        ) {
+                            fuzzing_rec.close();
+                            failing_fuzzer_draws = Some(fuzzer_draws);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Shrinking reruns the failing input many times, which is not done for runs that timed out
+        if let Some(fuzzer_draws) = failing_fuzzer_draws
+            .filter(|_| matches!(results.last(), Some(TestCaseSummary::Failed { .. })))
+        {
+            let (shrunk_result, shrinking_duration) = run_shrinking(
+                case.clone(),
+                casm_program.clone(),
+                test_runner_config.clone(),
+                versioned_program_path.clone(),
+                send.clone(),
+                fuzzer_seed,
+                fuzzer_draws,
+                trace_verbosity,
+                ui.clone(),
+            )
+            .await?;
+            duration += shrinking_duration;
+
+            if let Some(shrunk_result) = shrunk_result {
+                let failed_result = results.pop().expect("Failed result was pushed above");
+                results.push(failed_result.with_shrunk_failure(shrunk_result));
             }
         }
 
@@ -211,7 +363,9 @@ fn run_with_fuzzing(
                 .filter(|item| {
                     matches!(
                         item,
-                        TestCaseSummary::Passed { .. } | TestCaseSummary::Failed { .. }
+                        TestCaseSummary::Passed { .. }
+                            | TestCaseSummary::Failed { .. }
+                            | TestCaseSummary::TimedOut { .. }
                     )
                 })
                 .count(),
@@ -223,15 +377,43 @@ fn run_with_fuzzing(
             // Because we execute tests parallel, it's possible to
             // get Passed after Skipped. To treat fuzzing a test as Passed
             // we have to ensure that all fuzzing subtests Passed
-            if runs != fuzzer_runs.get() {
-                return Ok(TestCaseSummary::Interrupted {});
+            if runs != fuzzer_runs.get() + u32::try_from(corpus_inputs.len())? {
+                return Ok((TestCaseSummary::Interrupted {}, duration));
             }
         }
 
-        Ok(fuzzing_run_summary)
+        Ok((fuzzing_run_summary, duration))
     })
 }
 
+/// Saves the result of a single fuzz test run and passes its coverage to the guided fuzzer.
+/// Returns fuzzer draws of the run if it failed or timed out, in which case no more runs should be executed.
+fn record_fuzz_run(
+    result: TestCaseSummary<Single>,
+    fuzzer_feedback: Option<FuzzerFeedback>,
+    results: &mut Vec<TestCaseSummary<Single>>,
+    guided_fuzzer: &mut GuidedFuzzer,
+    total_runs: usize,
+    ui: &UI,
+) -> Option<Vec<FuzzerDraw>> {
+    results.push(result.clone());
+    if let Some(name) = result.name() {
+        ui.println_json_only(&FuzzerProgressMessage::new(name, results.len(), total_runs));
+    }
+
+    match result {
+        TestCaseSummary::Failed { fuzzer_draws, .. }
+        | TestCaseSummary::TimedOut { fuzzer_draws, .. } => Some(fuzzer_draws),
+        TestCaseSummary::Passed { .. } => {
+            if let Some(fuzzer_feedback) = fuzzer_feedback {
+                guided_fuzzer.add_feedback(fuzzer_feedback);
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
 #[expect(clippy::implicit_hasher)]
 #[must_use]
 pub fn function_args(
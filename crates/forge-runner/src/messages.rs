@@ -6,11 +6,14 @@ use console::style;
 use foundry_ui::Message;
 use serde::Serialize;
 use serde_json::{Value, json};
+use std::time::Duration;
 
 #[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
 enum TestResultStatus {
     Passed,
     Failed,
+    TimedOut,
     Ignored,
     Interrupted,
 }
@@ -22,6 +25,8 @@ impl From<&AnyTestCaseSummary> for TestResultStatus {
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { .. }) => Self::Passed,
             AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. })
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed { .. }) => Self::Failed,
+            AnyTestCaseSummary::Single(TestCaseSummary::TimedOut { .. })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut { .. }) => Self::TimedOut,
             AnyTestCaseSummary::Single(TestCaseSummary::Ignored { .. })
             | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored { .. }) => Self::Ignored,
             AnyTestCaseSummary::Single(TestCaseSummary::Interrupted { .. })
@@ -39,11 +44,15 @@ pub struct TestResultMessage {
     fuzzer_report: String,
     gas_usage: String,
     used_resources: String,
+    duration: Duration,
+    gas: Option<Value>,
+    fuzzer: Option<Value>,
 }
 
 impl TestResultMessage {
     pub fn new(
         test_result: &AnyTestCaseSummary,
+        duration: Duration,
         show_detailed_resources: bool,
         tracked_resource: ForgeTrackedResource,
     ) -> Self {
@@ -65,6 +74,19 @@ impl TestResultMessage {
                     ..
                 } => format!(" (runs: {runs}, {gas_info})"),
                 TestCaseSummary::Failed {
+                    fuzzer_args,
+                    shrunk_fuzzer_args: Some(shrunk_fuzzer_args),
+                    test_statistics: FuzzingStatistics { runs },
+                    ..
+                } => format!(
+                    " (runs: {runs}, arguments: {fuzzer_args:?}, shrunk arguments: {shrunk_fuzzer_args:?})"
+                ),
+                TestCaseSummary::Failed {
+                    fuzzer_args,
+                    test_statistics: FuzzingStatistics { runs },
+                    ..
+                }
+                | TestCaseSummary::TimedOut {
                     fuzzer_args,
                     test_statistics: FuzzingStatistics { runs },
                     ..
@@ -92,6 +114,47 @@ impl TestResultMessage {
             _ => String::new(),
         };
 
+        let gas = match test_result {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, fee, .. }) => {
+                Some(json!({
+                    "l1_gas": gas_info.l1_gas.0,
+                    "l1_data_gas": gas_info.l1_data_gas.0,
+                    "l2_gas": gas_info.l2_gas.0,
+                    "fee": fee.map(|fee| fee.0.to_string()),
+                }))
+            }
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(json!(gas_info))
+            }
+            _ => None,
+        };
+
+        let fuzzer = match test_result {
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+                test_statistics: FuzzingStatistics { runs },
+                ..
+            }) => Some(json!({ "runs": runs })),
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+                fuzzer_args,
+                shrunk_fuzzer_args,
+                test_statistics: FuzzingStatistics { runs },
+                ..
+            }) => Some(json!({
+                "runs": runs,
+                "arguments": fuzzer_args,
+                "shrunk_arguments": shrunk_fuzzer_args,
+            })),
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut {
+                fuzzer_args,
+                test_statistics: FuzzingStatistics { runs },
+                ..
+            }) => Some(json!({
+                "runs": runs,
+                "arguments": fuzzer_args,
+            })),
+            _ => None,
+        };
+
         let msg = test_result.msg().map(std::string::ToString::to_string);
         let status = TestResultStatus::from(test_result);
         Self {
@@ -102,13 +165,18 @@ impl TestResultMessage {
             fuzzer_report,
             gas_usage,
             used_resources,
+            duration,
+            gas,
+            fuzzer,
         }
     }
 
     fn result_message(&self) -> String {
         if let Some(msg) = &self.msg {
             match self.status {
-                TestResultStatus::Passed => return format!("\n\n{msg}"),
+                TestResultStatus::Passed | TestResultStatus::TimedOut => {
+                    return format!("\n\n{msg}");
+                }
                 TestResultStatus::Failed => return format!("\n\nFailure data:{msg}"),
                 TestResultStatus::Ignored | TestResultStatus::Interrupted => return String::new(),
             }
@@ -120,6 +188,7 @@ impl TestResultMessage {
         match self.status {
             TestResultStatus::Passed => format!("[{}]", style("PASS").green()),
             TestResultStatus::Failed => format!("[{}]", style("FAIL").red()),
+            TestResultStatus::TimedOut => format!("[{}]", style("TIMEOUT").red()),
             TestResultStatus::Ignored => format!("[{}]", style("IGNORE").yellow()),
             TestResultStatus::Interrupted => {
                 unreachable!("Interrupted tests should not have visible message representation")
@@ -146,7 +215,82 @@ impl Message for TestResultMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        json!({
+            "message_type": "test_result",
+            "status": self.status,
+            "name": self.name,
+            "msg": self.msg,
+            "duration": self.duration.as_secs_f64(),
+            "gas": self.gas,
+            "fuzzer": self.fuzzer,
+            "debugging_trace": (!self.debugging_trace.is_empty())
+                .then(|| self.debugging_trace.trim_start()),
+        })
+    }
+}
+
+/// Emitted when execution of a test case starts.
+#[derive(Serialize)]
+pub struct TestStartedMessage {
+    name: String,
+}
+
+impl TestStartedMessage {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Message for TestStartedMessage {
+    fn text(&self) -> String {
+        format!("Running {}", self.name)
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "message_type": "test_started",
+            "name": self.name,
+        })
+    }
+}
+
+/// Emitted after every finished run of a fuzz test.
+#[derive(Serialize)]
+pub struct FuzzerProgressMessage {
+    name: String,
+    runs: usize,
+    total_runs: usize,
+}
+
+impl FuzzerProgressMessage {
+    #[must_use]
+    pub fn new(name: &str, runs: usize, total_runs: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            runs,
+            total_runs,
+        }
+    }
+}
+
+impl Message for FuzzerProgressMessage {
+    fn text(&self) -> String {
+        format!(
+            "Fuzzing {} ({}/{} runs)",
+            self.name, self.runs, self.total_runs
+        )
+    }
+
+    fn json(&self) -> Value {
+        json!({
+            "message_type": "fuzzer_progress",
+            "name": self.name,
+            "runs": self.runs,
+            "total_runs": self.total_runs,
+        })
     }
 }
 
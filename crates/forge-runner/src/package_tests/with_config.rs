@@ -2,9 +2,10 @@ use super::{TestCase, TestTarget};
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
 use cheatnet::runtime_extensions::forge_config_extension::config::{
     Expected, RawAvailableGasConfig, RawForgeConfig, RawForkConfig, RawFuzzerConfig,
-    RawShouldPanicConfig,
+    RawInvariantConfig, RawShouldPanicConfig,
 };
 use conversions::serde::serialize::SerializeToFeltVec;
+use std::time::Duration;
 
 pub type TestTargetWithConfig = TestTarget<TestCaseConfig>;
 
@@ -19,7 +20,9 @@ pub struct TestCaseConfig {
     pub expected_result: ExpectedTestResult,
     pub fork_config: Option<RawForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
+    pub invariant_config: Option<RawInvariantConfig>,
     pub disable_predeployed_contracts: bool,
+    pub timeout: Option<Duration>,
 }
 
 impl From<RawForgeConfig> for TestCaseConfig {
@@ -30,9 +33,11 @@ impl From<RawForgeConfig> for TestCaseConfig {
             expected_result: value.should_panic.into(),
             fork_config: value.fork,
             fuzzer_config: value.fuzzer,
+            invariant_config: value.invariant,
             disable_predeployed_contracts: value
                 .disable_predeployed_contracts
                 .is_some_and(|v| v.is_disabled),
+            timeout: value.timeout.map(|v| Duration::from_secs(v.seconds)),
         }
     }
 }
@@ -3,9 +3,10 @@ use crate::expected_result::ExpectedTestResult;
 use anyhow::Result;
 use cairo_vm::types::program::Program;
 use cheatnet::runtime_extensions::forge_config_extension::config::{
-    RawAvailableGasConfig, RawFuzzerConfig,
+    RawAvailableGasConfig, RawFuzzerConfig, RawInvariantConfig,
 };
 use starknet_api::block::BlockNumber;
+use std::time::Duration;
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 use url::Url;
 
@@ -38,5 +39,7 @@ pub struct TestCaseResolvedConfig {
     pub expected_result: ExpectedTestResult,
     pub fork_config: Option<ResolvedForkConfig>,
     pub fuzzer_config: Option<RawFuzzerConfig>,
+    pub invariant_config: Option<RawInvariantConfig>,
     pub disable_predeployed_contracts: bool,
+    pub timeout: Option<Duration>,
 }
@@ -1,6 +1,8 @@
 use crate::backtrace::add_backtrace_footer;
-use crate::forge_config::{RuntimeConfig, TestRunnerConfig};
-use crate::gas::calculate_used_gas;
+use crate::forge_config::{ForkMode, FuzzerStrategy, RuntimeConfig, TestRunnerConfig};
+use crate::functions_gas::FunctionsGasUsage;
+use crate::gas::{calculate_fee, calculate_used_gas};
+use crate::messages::TestStartedMessage;
 use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWithResolvedConfig};
 use crate::test_case_summary::{Single, TestCaseSummary};
 use anyhow::{Result, bail};
@@ -20,7 +22,14 @@ use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::CallToBlockifierExtension;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use cheatnet::runtime_extensions::cheatable_starknet_runtime_extension::CheatableStarknetRuntimeExtension;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::expect_call::RegisteredExpectedCall;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::snapshot_state::SnapshotableState;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::guided::{
+    FuzzerFeedback, collect_coverage,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::shrinking::shrink;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::{FuzzerDraw, FuzzerSource};
 use cheatnet::runtime_extensions::forge_runtime_extension::{
     ForgeExtension, ForgeRuntime, add_resources_to_top_call, get_all_used_resources,
     update_top_call_l1_resources, update_top_call_resources, update_top_call_vm_trace,
@@ -31,17 +40,22 @@ use cheatnet::state::{
 use execution::finalize_execution;
 use foundry_ui::UI;
 use hints::hints_by_representation;
+use rand::SeedableRng;
 use rand::prelude::StdRng;
 use runtime::starknet::context::{build_context, set_max_steps};
 use runtime::{ExtendedRuntime, StarknetRuntime};
 use starknet_api::execution_resources::GasVector;
+use starknet_api::transaction::fields::Fee;
 use std::cell::RefCell;
 use std::default::Default;
-use std::marker::PhantomData;
+use std::mem;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::Sender;
-use tokio::task::JoinHandle;
+use tokio::sync::oneshot;
+use tokio::task::{AbortHandle, JoinError, JoinHandle};
 use universal_sierra_compiler_api::AssembledProgramWithDebugInfo;
 
 pub mod config_run;
@@ -68,14 +82,22 @@ pub fn run_test(
     send: Sender<()>,
     trace_verbosity: Option<TraceVerbosity>,
     ui: Arc<UI>,
-) -> JoinHandle<TestCaseSummary<Single>> {
-    tokio::task::spawn_blocking(move || {
+) -> impl Future<Output = Result<(TestCaseSummary<Single>, Duration), JoinError>> {
+    let name = case.name.clone();
+    let timeout = case.config.timeout.or(test_runner_config.test_timeout);
+    let (started_send, started_recv) = oneshot::channel();
+
+    let task = tokio::task::spawn_blocking(move || {
+        let start = Instant::now();
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, start.elapsed());
         }
+        let _ = started_send.send(());
+        ui.println_json_only(&TestStartedMessage::new(&case.name));
+
         let run_result = run_test_case(
             &case,
             &casm_program,
@@ -84,16 +106,25 @@ pub fn run_test(
         );
 
         if send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, start.elapsed());
         }
 
-        extract_test_case_summary(
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             &test_runner_config.contracts_data,
             &versioned_program_path,
             trace_verbosity,
             &ui,
+        );
+
+        (summary, start.elapsed())
+    });
+
+    join_with_timeout(task, started_recv, timeout, move |timeout| {
+        (
+            TestCaseSummary::timed_out(name, timeout, Vec::new(), Vec::new()),
+            timeout,
         )
     })
 }
@@ -106,43 +137,154 @@ pub(crate) fn run_fuzz_test(
     versioned_program_path: Arc<Utf8PathBuf>,
     send: Sender<()>,
     fuzzing_send: Sender<()>,
-    rng: Arc<Mutex<StdRng>>,
+    fuzzer_source: FuzzerSource,
     trace_verbosity: Option<TraceVerbosity>,
     ui: Arc<UI>,
-) -> JoinHandle<TestCaseSummary<Single>> {
-    tokio::task::spawn_blocking(move || {
+) -> impl Future<Output = Result<(TestCaseSummary<Single>, Option<FuzzerFeedback>, Duration), JoinError>>
+{
+    let name = case.name.clone();
+    let timeout = case.config.timeout.or(test_runner_config.test_timeout);
+    let (started_send, started_recv) = oneshot::channel();
+
+    let task = tokio::task::spawn_blocking(move || {
+        let start = Instant::now();
         // Due to the inability of spawn_blocking to be abruptly cancelled,
         // a channel is used to receive information indicating
         // that the execution of the task is no longer necessary.
         if send.is_closed() | fuzzing_send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, None, start.elapsed());
         }
+        let _ = started_send.send(());
 
-        let run_result = run_test_case(
-            &case,
-            &casm_program,
-            &Arc::new(RuntimeConfig::from(&test_runner_config)),
-            Some(rng),
-        );
+        let is_guided = test_runner_config.fuzzer_strategy == FuzzerStrategy::Guided;
+        let mut runtime_config = RuntimeConfig::from(&test_runner_config);
+        // Guided fuzzer needs the VM trace of the test code to measure coverage
+        runtime_config.is_vm_trace_needed |= is_guided;
+
+        let run_result = run_test_case(&case, &casm_program, &runtime_config, Some(fuzzer_source));
 
         // TODO: code below is added to fix snforge tests
         // remove it after improve exit-first tests
         // issue #1043
         if send.is_closed() {
-            return TestCaseSummary::Interrupted {};
+            return (TestCaseSummary::Interrupted {}, None, start.elapsed());
         }
 
-        extract_test_case_summary(
+        let fuzzer_feedback = if is_guided {
+            get_fuzzer_feedback(&run_result)
+        } else {
+            None
+        };
+        let summary = extract_test_case_summary(
             run_result,
             &case,
             &test_runner_config.contracts_data,
             &versioned_program_path,
             trace_verbosity,
             &ui,
+        );
+
+        (summary, fuzzer_feedback, start.elapsed())
+    });
+
+    join_with_timeout(task, started_recv, timeout, move |timeout| {
+        (
+            TestCaseSummary::timed_out(name, timeout, Vec::new(), Vec::new()),
+            None,
+            timeout,
         )
     })
 }
 
+/// Extra time given to the test after its timeout elapses, so that [`TimeoutTimer`] can stop the VM
+/// and report the fuzzer arguments of the run before the test is abandoned.
+const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Awaits the test run in `task`, returning the result of `on_timeout` once the test doesn't finish
+/// within `timeout` from the moment it started running.
+/// Unlike [`TimeoutTimer`], this also stops waiting for tests blocked outside the VM,
+/// e.g. in a fork RPC request. Such a task can't be cancelled and is left running in the background.
+async fn join_with_timeout<T>(
+    task: JoinHandle<T>,
+    started: oneshot::Receiver<()>,
+    timeout: Option<Duration>,
+    on_timeout: impl FnOnce(Duration) -> T,
+) -> Result<T, JoinError> {
+    let Some(timeout) = timeout else {
+        return task.await;
+    };
+    // Sender is dropped without sending when the test was interrupted before it started
+    if started.await.is_err() {
+        return task.await;
+    }
+
+    match tokio::time::timeout(timeout + TIMEOUT_GRACE_PERIOD, task).await {
+        Ok(result) => result,
+        Err(_) => Ok(on_timeout(timeout)),
+    }
+}
+
+/// Reruns a failed fuzz test case with `fuzzer_draws` shrunk toward a minimal counterexample.
+/// Returns the summary of the last failing run, or `None` if no smaller failing input was found,
+/// together with the time spent on shrinking.
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn run_shrinking(
+    case: Arc<TestCaseWithResolvedConfig>,
+    casm_program: Arc<AssembledProgramWithDebugInfo>,
+    test_runner_config: Arc<TestRunnerConfig>,
+    versioned_program_path: Arc<Utf8PathBuf>,
+    send: Sender<()>,
+    fuzzer_seed: u64,
+    fuzzer_draws: Vec<FuzzerDraw>,
+    trace_verbosity: Option<TraceVerbosity>,
+    ui: Arc<UI>,
+) -> JoinHandle<(Option<TestCaseSummary<Single>>, Duration)> {
+    tokio::task::spawn_blocking(move || {
+        let start = Instant::now();
+        let runtime_config = RuntimeConfig::from(&test_runner_config);
+        let mut shrunk_summary = None;
+        let abi_cache = Arc::default();
+
+        shrink(fuzzer_draws, |candidate| {
+            if send.is_closed() {
+                return None;
+            }
+
+            // Used only when the test draws more values than recorded in `candidate`
+            let rng = Arc::new(Mutex::new(StdRng::seed_from_u64(fuzzer_seed)));
+            let run_result = run_test_case(
+                &case,
+                &casm_program,
+                &runtime_config,
+                Some(FuzzerSource::replay(
+                    rng,
+                    Arc::default(),
+                    Arc::clone(&abi_cache),
+                    candidate,
+                )),
+            );
+            let summary = extract_test_case_summary(
+                run_result,
+                &case,
+                &test_runner_config.contracts_data,
+                &versioned_program_path,
+                trace_verbosity,
+                &ui,
+            );
+
+            if let TestCaseSummary::Failed { fuzzer_draws, .. } = &summary {
+                let fuzzer_draws = fuzzer_draws.clone();
+                shrunk_summary = Some(summary);
+                Some(fuzzer_draws)
+            } else {
+                None
+            }
+        });
+
+        (shrunk_summary, start.elapsed())
+    })
+}
+
 pub enum RunStatus {
     Success(Vec<Felt252>),
     Panic(Vec<Felt252>),
@@ -152,9 +294,14 @@ pub struct RunCompleted {
     pub(crate) status: RunStatus,
     pub(crate) call_trace: Rc<RefCell<CallTrace>>,
     pub(crate) gas_used: GasVector,
+    pub(crate) fee: Fee,
     pub(crate) used_resources: UsedResources,
+    pub(crate) functions_gas: FunctionsGasUsage,
     pub(crate) encountered_errors: EncounteredErrors,
+    pub(crate) expected_calls: Vec<RegisteredExpectedCall>,
+    pub(crate) expected_revert: Option<Vec<Felt252>>,
     pub(crate) fuzzer_args: Vec<String>,
+    pub(crate) fuzzer_draws: Vec<FuzzerDraw>,
     pub(crate) fork_data: ForkData,
 }
 
@@ -163,12 +310,56 @@ pub struct RunError {
     pub(crate) call_trace: Rc<RefCell<CallTrace>>,
     pub(crate) encountered_errors: EncounteredErrors,
     pub(crate) fuzzer_args: Vec<String>,
+    pub(crate) fuzzer_draws: Vec<FuzzerDraw>,
     pub(crate) fork_data: ForkData,
 }
 
+pub struct RunTimedOut {
+    pub(crate) timeout: Duration,
+    pub(crate) fuzzer_args: Vec<String>,
+    pub(crate) fuzzer_draws: Vec<FuzzerDraw>,
+}
+
 pub enum RunResult {
     Completed(Box<RunCompleted>),
     Error(RunError),
+    TimedOut(RunTimedOut),
+}
+
+/// Interrupts execution of the test once its timeout elapses, stopped when dropped
+struct TimeoutTimer {
+    timeout: Duration,
+    timed_out: Arc<AtomicBool>,
+    abort_handle: AbortHandle,
+}
+
+impl TimeoutTimer {
+    fn start(timeout: Duration, timed_out: Arc<AtomicBool>) -> Self {
+        let abort_handle = tokio::spawn({
+            let timed_out = timed_out.clone();
+            async move {
+                tokio::time::sleep(timeout).await;
+                timed_out.store(true, Ordering::Relaxed);
+            }
+        })
+        .abort_handle();
+
+        Self {
+            timeout,
+            timed_out,
+            abort_handle,
+        }
+    }
+
+    fn has_elapsed(&self) -> bool {
+        self.timed_out.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for TimeoutTimer {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
 }
 
 #[expect(clippy::too_many_lines)]
@@ -176,7 +367,7 @@ pub fn run_test_case(
     case: &TestCaseWithResolvedConfig,
     casm_program: &AssembledProgramWithDebugInfo,
     runtime_config: &RuntimeConfig,
-    fuzzer_rng: Option<Arc<Mutex<StdRng>>>,
+    fuzzer_source: Option<FuzzerSource>,
 ) -> Result<RunResult> {
     let program = case.try_into_program(casm_program)?;
     let (call, entry_point) =
@@ -186,6 +377,7 @@ pub fn run_test_case(
         dict_state_reader: cheatnet_constants::build_testing_state(),
         fork_state_reader: get_fork_state_reader(
             runtime_config.cache_dir,
+            runtime_config.fork_mode,
             case.config.fork_config.as_ref(),
         )?,
     };
@@ -204,6 +396,13 @@ pub fn run_test_case(
     }
     let mut cached_state = CachedState::new(state_reader);
 
+    let mut cheatnet_state = CheatnetState {
+        block_info,
+        seen_storage_values: fuzzer_source.is_some().then(Default::default),
+        ..Default::default()
+    };
+    let mut state = SnapshotableState::new(&mut cached_state, cheatnet_state.state_writes.clone());
+
     let hints = hints_by_representation(&casm_program.assembled_cairo_program);
     let VmExecutionContext {
         mut runner,
@@ -214,15 +413,16 @@ pub fn run_test_case(
         call.clone(),
         &hints,
         &program,
-        &mut cached_state,
+        &mut state,
         &mut context,
     )?;
 
-    let mut cheatnet_state = CheatnetState {
-        block_info,
-        ..Default::default()
-    };
     cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
+    let timeout_timer = case
+        .config
+        .timeout
+        .or(runtime_config.test_timeout)
+        .map(|timeout| TimeoutTimer::start(timeout, cheatnet_state.timed_out.clone()));
 
     let cheatable_runtime = ExtendedRuntime {
         extension: CheatableStarknetRuntimeExtension {
@@ -236,14 +436,14 @@ pub fn run_test_case(
 
     let call_to_blockifier_runtime = ExtendedRuntime {
         extension: CallToBlockifierExtension {
-            lifetime: &PhantomData,
+            contracts_data: runtime_config.contracts_data,
         },
         extended_runtime: cheatable_runtime,
     };
     let forge_extension = ForgeExtension {
         environment_variables: runtime_config.environment_variables,
         contracts_data: runtime_config.contracts_data,
-        fuzzer_rng,
+        fuzzer_source,
     };
 
     let mut forge_runtime = ExtendedRuntime {
@@ -349,21 +549,81 @@ pub fn run_test_case(
         .cheatnet_state
         .fuzzer_args
         .clone();
+    let seen_storage_values = mem::take(
+        &mut forge_runtime
+            .extended_runtime
+            .extended_runtime
+            .extension
+            .cheatnet_state
+            .seen_storage_values,
+    );
+    let expected_calls = mem::take(
+        &mut forge_runtime
+            .extended_runtime
+            .extended_runtime
+            .extension
+            .cheatnet_state
+            .expected_calls,
+    );
+    let expected_revert = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .expected_revert
+        .take();
+    let cheated_gas_prices = forge_runtime
+        .extended_runtime
+        .extended_runtime
+        .extension
+        .cheatnet_state
+        .cheated_gas_prices
+        .take();
+    let fuzzer_draws = forge_runtime
+        .extension
+        .fuzzer_source
+        .take()
+        .map(|fuzzer_source| {
+            fuzzer_source.extend_dictionary(seen_storage_values.unwrap_or_default());
+            fuzzer_source.into_draws()
+        })
+        .unwrap_or_default();
+
+    // Execution interrupted by the timeout ends with an error which is not reported
+    if let Some(timeout_timer) = timeout_timer.filter(TimeoutTimer::has_elapsed) {
+        return Ok(RunResult::TimedOut(RunTimedOut {
+            timeout: timeout_timer.timeout,
+            fuzzer_args,
+            fuzzer_draws,
+        }));
+    }
 
     let transaction_context = get_context(&forge_runtime).tx_context.clone();
     let used_resources =
         get_all_used_resources(forge_runtime, &transaction_context, tracked_resource);
+    state.commit()?;
     let gas_used = calculate_used_gas(
         &transaction_context,
         &mut cached_state,
         used_resources.clone(),
     )?;
+    let fee = calculate_fee(&transaction_context, &gas_used, cheated_gas_prices.as_ref());
 
     let fork_data = cached_state
         .state
         .fork_state_reader
         .map(|fork_state_reader| ForkData::new(&fork_state_reader.compiled_contract_class_map()))
         .unwrap_or_default();
+    let functions_gas = if runtime_config.gas_report {
+        FunctionsGasUsage::from_call_trace(
+            &call_trace_ref.borrow(),
+            runtime_config.contracts_data,
+            &fork_data,
+            transaction_context.block_context.versioned_constants(),
+        )
+    } else {
+        FunctionsGasUsage::default()
+    };
 
     Ok(match result {
         Ok(result) => RunResult::Completed(Box::new(RunCompleted {
@@ -374,9 +634,14 @@ pub fn run_test_case(
             },
             call_trace: call_trace_ref,
             gas_used,
+            fee,
             used_resources,
+            functions_gas,
             encountered_errors,
+            expected_calls,
+            expected_revert,
             fuzzer_args,
+            fuzzer_draws,
             fork_data,
         })),
         Err(error) => RunResult::Error(RunError {
@@ -384,6 +649,7 @@ pub fn run_test_case(
             call_trace: call_trace_ref,
             encountered_errors,
             fuzzer_args,
+            fuzzer_draws,
             fork_data,
         }),
     })
@@ -428,6 +694,8 @@ fn extract_test_case_summary(
                         add_backtrace_footer(msg, contracts_data, &run_error.encountered_errors)
                     }),
                     fuzzer_args: run_error.fuzzer_args,
+                    fuzzer_draws: run_error.fuzzer_draws,
+                    shrunk_fuzzer_args: None,
                     test_statistics: (),
                     debugging_trace: build_debugging_trace(
                         &run_error.call_trace.borrow(),
@@ -438,6 +706,12 @@ fn extract_test_case_summary(
                     ),
                 }
             }
+            RunResult::TimedOut(run_timed_out) => TestCaseSummary::timed_out(
+                case.name.clone(),
+                run_timed_out.timeout,
+                run_timed_out.fuzzer_args,
+                run_timed_out.fuzzer_draws,
+            ),
         },
         // `ForkStateReader.get_block_info`, `get_fork_state_reader, `calculate_used_gas` may return an error
         // `available_gas` may be specified with Scarb ~2.4
@@ -445,19 +719,35 @@ fn extract_test_case_summary(
             name: case.name.clone(),
             msg: Some(error.to_string()),
             fuzzer_args: Vec::default(),
+            fuzzer_draws: Vec::default(),
+            shrunk_fuzzer_args: None,
             test_statistics: (),
             debugging_trace: None,
         },
     }
 }
 
+fn get_fuzzer_feedback(run_result: &Result<RunResult>) -> Option<FuzzerFeedback> {
+    match run_result {
+        Ok(RunResult::Completed(run_completed)) => Some(FuzzerFeedback {
+            draws: run_completed.fuzzer_draws.clone(),
+            coverage: collect_coverage(&run_completed.call_trace.borrow()),
+        }),
+        _ => None,
+    }
+}
+
 fn get_fork_state_reader(
     cache_dir: &Utf8Path,
+    fork_mode: ForkMode,
     fork_config: Option<&ResolvedForkConfig>,
 ) -> Result<Option<ForkStateReader>> {
     fork_config
-        .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
+        .map(|ResolvedForkConfig { url, block_number }| match fork_mode {
+            ForkMode::Online => ForkStateReader::new(url.clone(), *block_number, cache_dir),
+            ForkMode::Offline => {
+                ForkStateReader::new_offline(url.clone(), *block_number, cache_dir)
+            }
         })
         .transpose()
 }
@@ -2,24 +2,31 @@ use crate::backtrace::{add_backtrace_footer, get_backtrace, is_backtrace_enabled
 use crate::build_trace_data::build_profiler_call_trace;
 use crate::debugging::{TraceVerbosity, build_debugging_trace};
 use crate::expected_result::{ExpectedPanicValue, ExpectedTestResult};
+use crate::functions_gas::FunctionsGasUsage;
 use crate::gas::check_available_gas;
 use crate::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
 use crate::running::{RunCompleted, RunStatus};
 use cairo_annotations::trace_data::VersionedCallTrace as VersionedProfilerCallTrace;
 use camino::Utf8Path;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::expect_call::verify_expected_calls;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::expect_revert::verify_expected_revert;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::runtime_extensions::forge_runtime_extension::fuzzer::FuzzerDraw;
 use conversions::byte_array::ByteArray;
 use conversions::felt::ToShortString;
 use foundry_ui::UI;
 use num_traits::Pow;
+use serde::{Deserialize, Serialize};
 use shared::utils::build_readable_text;
 use starknet_api::execution_resources::GasVector;
+use starknet_api::transaction::fields::Fee;
 use starknet_types_core::felt::Felt;
 use std::fmt;
 use std::option::Option;
+use std::time::Duration;
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct GasStatistics {
     pub l1_gas: GasStatisticsComponent,
     pub l1_data_gas: GasStatisticsComponent,
@@ -36,7 +43,7 @@ impl fmt::Display for GasStatistics {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct GasStatisticsComponent {
     pub min: u64,
     pub max: u64,
@@ -138,8 +145,12 @@ pub enum TestCaseSummary<T: TestType> {
         debugging_trace: Option<debugging::Trace>,
         /// Information on used gas
         gas_info: <T as TestType>::GasInfo,
+        /// Fee paid for the used gas, in FRI, not calculated for fuzz tests
+        fee: Option<Fee>,
         /// Resources used during test
         used_resources: UsedResources,
+        /// Gas used by calls to functions of deployed contracts
+        functions_gas: FunctionsGasUsage,
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
         /// Test trace data
@@ -155,6 +166,23 @@ pub enum TestCaseSummary<T: TestType> {
         debugging_trace: Option<debugging::Trace>,
         /// Random arguments used in the fuzz test case run
         fuzzer_args: Vec<String>,
+        /// Values drawn by the fuzzer in the run reported by this summary, allowing to replay it
+        fuzzer_draws: Vec<FuzzerDraw>,
+        /// Arguments of the smallest failing fuzz test case run found by shrinking `fuzzer_args`
+        shrunk_fuzzer_args: Option<Vec<String>>,
+        /// Statistics of the test run
+        test_statistics: <T as TestType>::TestStatistics,
+    },
+    /// Test case stopped after exceeding its timeout
+    TimedOut {
+        /// Name of the test case
+        name: String,
+        /// Message with the exceeded timeout
+        msg: Option<String>,
+        /// Random arguments used in the fuzz test case run
+        fuzzer_args: Vec<String>,
+        /// Values drawn by the fuzzer in the timed out run
+        fuzzer_draws: Vec<FuzzerDraw>,
         /// Statistics of the test run
         test_statistics: <T as TestType>::TestStatistics,
     },
@@ -181,6 +209,7 @@ impl<T: TestType> TestCaseSummary<T> {
         match self {
             TestCaseSummary::Failed { name, .. }
             | TestCaseSummary::Passed { name, .. }
+            | TestCaseSummary::TimedOut { name, .. }
             | TestCaseSummary::Ignored { name, .. } => Some(name),
             TestCaseSummary::Interrupted { .. } => None,
         }
@@ -190,7 +219,8 @@ impl<T: TestType> TestCaseSummary<T> {
     pub fn msg(&self) -> Option<&str> {
         match self {
             TestCaseSummary::Failed { msg: Some(msg), .. }
-            | TestCaseSummary::Passed { msg: Some(msg), .. } => Some(msg),
+            | TestCaseSummary::Passed { msg: Some(msg), .. }
+            | TestCaseSummary::TimedOut { msg: Some(msg), .. } => Some(msg),
             _ => None,
         }
     }
@@ -223,16 +253,26 @@ impl TestCaseSummary<Fuzzing> {
                 name,
                 msg,
                 gas_info: _,
+                fee: _,
                 used_resources: _,
+                functions_gas: _,
                 test_statistics: (),
                 trace_data: _,
                 debugging_trace,
             } => {
                 let runs = results.len();
+                let mut functions_gas = FunctionsGasUsage::default();
                 let gas_usages: Vec<GasVector> = results
                     .into_iter()
                     .map(|a| match a {
-                        TestCaseSummary::Passed { gas_info, .. } => gas_info,
+                        TestCaseSummary::Passed {
+                            gas_info,
+                            functions_gas: run_functions_gas,
+                            ..
+                        } => {
+                            functions_gas.extend(run_functions_gas);
+                            gas_info
+                        }
                         _ => unreachable!(),
                     })
                     .collect();
@@ -241,7 +281,9 @@ impl TestCaseSummary<Fuzzing> {
                     name,
                     msg,
                     gas_info: GasStatistics::new(gas_usages.as_ref()),
+                    fee: None,
                     used_resources: UsedResources::default(),
+                    functions_gas,
                     test_statistics: FuzzingStatistics { runs },
                     trace_data: (),
                     debugging_trace,
@@ -251,17 +293,36 @@ impl TestCaseSummary<Fuzzing> {
                 name,
                 msg,
                 fuzzer_args,
+                fuzzer_draws,
+                shrunk_fuzzer_args,
                 debugging_trace,
                 test_statistics: (),
             } => TestCaseSummary::Failed {
                 name,
                 msg,
                 fuzzer_args,
+                fuzzer_draws,
+                shrunk_fuzzer_args,
                 test_statistics: FuzzingStatistics {
                     runs: results.len(),
                 },
                 debugging_trace,
             },
+            TestCaseSummary::TimedOut {
+                name,
+                msg,
+                fuzzer_args,
+                fuzzer_draws,
+                test_statistics: (),
+            } => TestCaseSummary::TimedOut {
+                name,
+                msg,
+                fuzzer_args,
+                fuzzer_draws,
+                test_statistics: FuzzingStatistics {
+                    runs: results.len(),
+                },
+            },
             TestCaseSummary::Ignored { name } => TestCaseSummary::Ignored { name: name.clone() },
             TestCaseSummary::Interrupted {} => TestCaseSummary::Interrupted {},
         }
@@ -310,15 +371,39 @@ fn check_if_matching_and_get_message(
 }
 
 impl TestCaseSummary<Single> {
+    #[must_use]
+    pub(crate) fn timed_out(
+        name: String,
+        timeout: Duration,
+        fuzzer_args: Vec<String>,
+        fuzzer_draws: Vec<FuzzerDraw>,
+    ) -> Self {
+        TestCaseSummary::TimedOut {
+            name,
+            msg: Some(format!(
+                "Test did not finish within the timeout of {}s",
+                timeout.as_secs()
+            )),
+            fuzzer_args,
+            fuzzer_draws,
+            test_statistics: (),
+        }
+    }
+
     #[must_use]
     pub(crate) fn from_run_completed(
         RunCompleted {
             status,
             call_trace,
             gas_used: gas_info,
+            fee,
             used_resources,
+            functions_gas,
             encountered_errors,
+            expected_calls,
+            expected_revert,
             fuzzer_args,
+            fuzzer_draws,
             fork_data,
         }: RunCompleted,
         test_case: &TestCaseWithResolvedConfig,
@@ -340,12 +425,28 @@ impl TestCaseSummary<Single> {
         match status {
             RunStatus::Success(data) => match &test_case.config.expected_result {
                 ExpectedTestResult::Success => {
+                    if let Some(msg) = verify_expected_calls(&expected_calls, &call_trace.borrow())
+                        .or_else(|| verify_expected_revert(expected_revert.as_deref()))
+                    {
+                        return TestCaseSummary::Failed {
+                            name,
+                            msg: Some(msg),
+                            fuzzer_args,
+                            fuzzer_draws,
+                            shrunk_fuzzer_args: None,
+                            test_statistics: (),
+                            debugging_trace,
+                        };
+                    }
+
                     let summary = TestCaseSummary::Passed {
                         name,
                         msg: build_readable_text(&data),
                         test_statistics: (),
                         gas_info,
+                        fee: Some(fee),
                         used_resources,
+                        functions_gas,
                         trace_data: VersionedProfilerCallTrace::V1(build_profiler_call_trace(
                             &call_trace,
                             contracts_data,
@@ -360,6 +461,8 @@ impl TestCaseSummary<Single> {
                     name,
                     msg: Some(build_expected_panic_message(expected_panic_value)),
                     fuzzer_args,
+                    fuzzer_draws,
+                    shrunk_fuzzer_args: None,
                     test_statistics: (),
                     debugging_trace,
                 },
@@ -370,20 +473,31 @@ impl TestCaseSummary<Single> {
                     msg: build_readable_text(&value)
                         .map(|msg| add_backtrace_footer(msg, contracts_data, &encountered_errors)),
                     fuzzer_args,
+                    fuzzer_draws,
+                    shrunk_fuzzer_args: None,
                     test_statistics: (),
                     debugging_trace,
                 },
                 ExpectedTestResult::Panics(expected_panic_value) => {
                     let (matching, msg) =
                         check_if_matching_and_get_message(&value, expected_panic_value);
-                    if matching {
+                    let failure_msg = if matching {
+                        verify_expected_calls(&expected_calls, &call_trace.borrow())
+                    } else {
+                        msg.map(|msg| {
+                            add_backtrace_footer(msg, contracts_data, &encountered_errors)
+                        })
+                    };
+                    if failure_msg.is_none() {
                         TestCaseSummary::Passed {
                             name,
                             msg: is_backtrace_enabled()
                                 .then(|| get_backtrace(contracts_data, &encountered_errors)),
                             test_statistics: (),
                             gas_info,
+                            fee: Some(fee),
                             used_resources,
+                            functions_gas,
                             trace_data: VersionedProfilerCallTrace::V1(build_profiler_call_trace(
                                 &call_trace,
                                 contracts_data,
@@ -395,10 +509,10 @@ impl TestCaseSummary<Single> {
                     } else {
                         TestCaseSummary::Failed {
                             name,
-                            msg: msg.map(|msg| {
-                                add_backtrace_footer(msg, contracts_data, &encountered_errors)
-                            }),
+                            msg: failure_msg,
                             fuzzer_args,
+                            fuzzer_draws,
+                            shrunk_fuzzer_args: None,
                             test_statistics: (),
                             debugging_trace,
                         }
@@ -407,6 +521,35 @@ impl TestCaseSummary<Single> {
             },
         }
     }
+
+    /// Takes the failure details and fuzzer draws from the run with shrunk arguments,
+    /// while keeping the originally generated arguments.
+    #[must_use]
+    pub(crate) fn with_shrunk_failure(self, shrunk: TestCaseSummary<Single>) -> Self {
+        match (self, shrunk) {
+            (
+                TestCaseSummary::Failed {
+                    name, fuzzer_args, ..
+                },
+                TestCaseSummary::Failed {
+                    msg,
+                    debugging_trace,
+                    fuzzer_args: shrunk_fuzzer_args,
+                    fuzzer_draws,
+                    ..
+                },
+            ) => TestCaseSummary::Failed {
+                name,
+                msg,
+                debugging_trace,
+                fuzzer_args,
+                fuzzer_draws,
+                shrunk_fuzzer_args: Some(shrunk_fuzzer_args),
+                test_statistics: (),
+            },
+            (summary, _) => summary,
+        }
+    }
 }
 
 fn join_short_strings(data: &[Felt]) -> String {
@@ -475,6 +618,15 @@ impl AnyTestCaseSummary {
         )
     }
 
+    #[must_use]
+    pub fn is_timed_out(&self) -> bool {
+        matches!(
+            self,
+            AnyTestCaseSummary::Single(TestCaseSummary::TimedOut { .. })
+                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut { .. })
+        )
+    }
+
     #[must_use]
     pub fn is_interrupted(&self) -> bool {
         matches!(
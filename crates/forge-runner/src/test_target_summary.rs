@@ -1,13 +1,22 @@
 use crate::test_case_summary::AnyTestCaseSummary;
+use std::collections::HashMap;
+use std::time::Duration;
 
 /// Summary of the test run in the file
 #[derive(Debug)]
 pub struct TestTargetSummary {
     /// Summaries of each test case in the file
     pub test_case_summaries: Vec<AnyTestCaseSummary>,
+    /// Time spent executing each test case, keyed by the test case name
+    pub test_case_durations: HashMap<String, Duration>,
 }
 
 impl TestTargetSummary {
+    #[must_use]
+    pub fn duration(&self, test_case: &AnyTestCaseSummary) -> Option<Duration> {
+        self.test_case_durations.get(test_case.name()?).copied()
+    }
+
     #[must_use]
     pub fn count_passed(&self) -> usize {
         self.test_case_summaries
@@ -24,6 +33,14 @@ impl TestTargetSummary {
             .count()
     }
 
+    #[must_use]
+    pub fn count_timed_out(&self) -> usize {
+        self.test_case_summaries
+            .iter()
+            .filter(|tu| tu.is_timed_out())
+            .count()
+    }
+
     #[must_use]
     pub fn count_interrupted(&self) -> usize {
         self.test_case_summaries
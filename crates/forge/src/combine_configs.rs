@@ -2,13 +2,15 @@ use crate::scarb::config::ForgeConfigFromScarb;
 use camino::Utf8PathBuf;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, TestRunnerConfig,
+    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, ForkMode, FuzzerCorpus, FuzzerStrategy,
+    OutputConfig, TestRunnerConfig,
 };
 use rand::{RngCore, thread_rng};
 use std::env;
 use std::ffi::OsString;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[expect(clippy::too_many_arguments)]
 #[expect(clippy::fn_params_excessive_bools)]
@@ -16,14 +18,19 @@ pub fn combine_configs(
     exit_first: bool,
     fuzzer_runs: Option<NonZeroU32>,
     fuzzer_seed: Option<u64>,
+    fuzzer_strategy: FuzzerStrategy,
     detailed_resources: bool,
     save_trace_data: bool,
     build_profile: bool,
     coverage: bool,
+    gas_report: bool,
+    offline: bool,
     max_n_steps: Option<u32>,
+    test_timeout: Option<u64>,
     tracked_resource: ForgeTrackedResource,
     contracts_data: ContractsData,
     cache_dir: Utf8PathBuf,
+    fuzzer_corpus: FuzzerCorpus,
     forge_config_from_scarb: &ForgeConfigFromScarb,
     additional_args: &[OsString],
 ) -> ForgeConfig {
@@ -43,9 +50,20 @@ pub fn combine_configs(
             fuzzer_seed: fuzzer_seed
                 .or(forge_config_from_scarb.fuzzer_seed)
                 .unwrap_or_else(|| thread_rng().next_u64()),
+            fuzzer_corpus,
+            fuzzer_strategy,
             max_n_steps: max_n_steps.or(forge_config_from_scarb.max_n_steps),
+            test_timeout: test_timeout
+                .or(forge_config_from_scarb.test_timeout)
+                .map(Duration::from_secs),
             is_vm_trace_needed: execution_data_to_save.is_vm_trace_needed(),
+            gas_report,
             cache_dir,
+            fork_mode: if offline {
+                ForkMode::Offline
+            } else {
+                forge_config_from_scarb.fork_mode
+            },
             contracts_data,
             tracked_resource,
             environment_variables: env::vars().collect(),
@@ -67,14 +85,19 @@ mod tests {
             false,
             None,
             None,
+            FuzzerStrategy::Random,
+            false,
+            false,
             false,
             false,
             false,
             false,
             None,
+            None,
             ForgeTrackedResource::CairoSteps,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            FuzzerCorpus::default(),
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -82,14 +105,19 @@ mod tests {
             false,
             None,
             None,
+            FuzzerStrategy::Random,
+            false,
             false,
             false,
             false,
             false,
+            false,
+            None,
             None,
             ForgeTrackedResource::CairoSteps,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            FuzzerCorpus::default(),
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -108,14 +136,19 @@ mod tests {
             false,
             None,
             None,
+            FuzzerStrategy::Random,
+            false,
             false,
             false,
             false,
             false,
+            false,
+            None,
             None,
             ForgeTrackedResource::CairoSteps,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            FuzzerCorpus::default(),
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -126,10 +159,15 @@ mod tests {
                     exit_first: false,
                     fuzzer_runs: NonZeroU32::new(256).unwrap(),
                     fuzzer_seed: config.test_runner_config.fuzzer_seed,
+                    fuzzer_corpus: FuzzerCorpus::default(),
+                    fuzzer_strategy: FuzzerStrategy::Random,
                     max_n_steps: None,
+                    test_timeout: None,
                     tracked_resource: ForgeTrackedResource::CairoSteps,
                     is_vm_trace_needed: false,
+                    gas_report: false,
                     cache_dir: Utf8PathBuf::default(),
+                    fork_mode: ForkMode::Online,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -153,21 +191,28 @@ mod tests {
             build_profile: true,
             coverage: true,
             max_n_steps: Some(1_000_000),
+            test_timeout: Some(60),
             tracked_resource: ForgeTrackedResource::CairoSteps,
+            fork_mode: ForkMode::Offline,
         };
 
         let config = combine_configs(
             false,
             None,
             None,
+            FuzzerStrategy::Random,
+            false,
+            false,
             false,
             false,
             false,
             false,
             None,
+            None,
             ForgeTrackedResource::CairoSteps,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            FuzzerCorpus::default(),
             &config_from_scarb,
             &[],
         );
@@ -178,10 +223,15 @@ mod tests {
                     exit_first: true,
                     fuzzer_runs: NonZeroU32::new(1234).unwrap(),
                     fuzzer_seed: 500,
+                    fuzzer_corpus: FuzzerCorpus::default(),
+                    fuzzer_strategy: FuzzerStrategy::Random,
                     max_n_steps: Some(1_000_000),
+                    test_timeout: Some(Duration::from_secs(60)),
                     tracked_resource: ForgeTrackedResource::CairoSteps,
                     is_vm_trace_needed: true,
+                    gas_report: false,
                     cache_dir: Utf8PathBuf::default(),
+                    fork_mode: ForkMode::Offline,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -210,20 +260,27 @@ mod tests {
             build_profile: false,
             coverage: false,
             max_n_steps: Some(1234),
+            test_timeout: Some(60),
             tracked_resource: ForgeTrackedResource::CairoSteps,
+            fork_mode: ForkMode::Online,
         };
         let config = combine_configs(
             true,
             Some(NonZeroU32::new(100).unwrap()),
             Some(32),
+            FuzzerStrategy::Guided,
+            true,
+            true,
             true,
             true,
             true,
             true,
             Some(1_000_000),
+            Some(30),
             ForgeTrackedResource::CairoSteps,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            FuzzerCorpus::default(),
             &config_from_scarb,
             &[],
         );
@@ -235,10 +292,15 @@ mod tests {
                     exit_first: true,
                     fuzzer_runs: NonZeroU32::new(100).unwrap(),
                     fuzzer_seed: 32,
+                    fuzzer_corpus: FuzzerCorpus::default(),
+                    fuzzer_strategy: FuzzerStrategy::Guided,
                     max_n_steps: Some(1_000_000),
+                    test_timeout: Some(Duration::from_secs(30)),
                     tracked_resource: ForgeTrackedResource::CairoSteps,
                     is_vm_trace_needed: true,
+                    gas_report: true,
                     cache_dir: Utf8PathBuf::default(),
+                    fork_mode: ForkMode::Offline,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -0,0 +1,36 @@
+use crate::{ForkArgs, ForkCommand, ForkExportArgs, ForkImportArgs};
+use anyhow::Result;
+use cheatnet::forking::fixture::ForkFixture;
+use forge_runner::CACHE_DIR;
+use foundry_ui::UI;
+use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
+use starknet_api::block::BlockNumber;
+
+pub fn fork(args: ForkArgs, ui: &UI) -> Result<()> {
+    let scarb_metadata = ScarbCommand::metadata().inherit_stderr().no_deps().run()?;
+    let cache_dir = scarb_metadata.workspace.root.join(CACHE_DIR);
+
+    match args.command {
+        ForkCommand::Export(ForkExportArgs {
+            url,
+            block_number,
+            output,
+        }) => {
+            let fixture = ForkFixture::export(url, BlockNumber(block_number), &cache_dir)?;
+            fixture.save(&output)?;
+            ui.println(&format!("Exported fork fixture to {output}"));
+        }
+        ForkCommand::Import(ForkImportArgs { path, url }) => {
+            let fixture = ForkFixture::load(&path)?;
+            let chain_id = fixture.chain_id.clone();
+            let block_number = fixture.block_number;
+            fixture.import(&url, &cache_dir)?;
+            ui.println(&format!(
+                "Imported fork fixture of chain {chain_id} and block number = {} to the fork cache",
+                block_number.0
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::table::format_table;
+use forge_runner::functions_gas::FunctionsGasUsage;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use forge_runner::test_target_summary::TestTargetSummary;
+use foundry_ui::Message;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+/// L2 gas used by functions of deployed contracts over all passed tests, printed with `--gas-report`
+#[derive(Debug, Serialize)]
+pub struct GasReportMessage {
+    functions: Vec<FunctionGasReport>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct FunctionGasReport {
+    contract_name: String,
+    function_name: String,
+    calls: usize,
+    min: u64,
+    mean: u64,
+    median: u64,
+    max: u64,
+}
+
+impl GasReportMessage {
+    #[must_use]
+    pub fn new(summaries: &[TestTargetSummary]) -> Self {
+        let mut functions_gas = FunctionsGasUsage::default();
+        for test_case in summaries
+            .iter()
+            .flat_map(|summary| &summary.test_case_summaries)
+        {
+            match test_case {
+                AnyTestCaseSummary::Single(TestCaseSummary::Passed {
+                    functions_gas: test_functions_gas,
+                    ..
+                })
+                | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+                    functions_gas: test_functions_gas,
+                    ..
+                }) => functions_gas.extend(test_functions_gas.clone()),
+                _ => {}
+            }
+        }
+
+        Self::from_functions_gas(&functions_gas)
+    }
+
+    fn from_functions_gas(functions_gas: &FunctionsGasUsage) -> Self {
+        let functions = functions_gas
+            .iter()
+            .map(|(function_id, gas_usages)| {
+                let mut gas_usages = gas_usages.to_vec();
+                gas_usages.sort_unstable();
+                let calls = gas_usages.len();
+
+                FunctionGasReport {
+                    contract_name: function_id.contract_name.clone(),
+                    function_name: function_id.function_name.clone(),
+                    calls,
+                    min: gas_usages[0],
+                    mean: gas_usages.iter().sum::<u64>() / calls as u64,
+                    median: if calls % 2 == 0 {
+                        u64::midpoint(gas_usages[calls / 2 - 1], gas_usages[calls / 2])
+                    } else {
+                        gas_usages[calls / 2]
+                    },
+                    max: gas_usages[calls - 1],
+                }
+            })
+            .collect();
+
+        Self { functions }
+    }
+}
+
+impl Message for GasReportMessage {
+    fn text(&self) -> String {
+        if self.functions.is_empty() {
+            return "Gas report: no calls to deployed contracts were made".to_string();
+        }
+
+        let rows = self
+            .functions
+            .iter()
+            .map(|function| {
+                [
+                    function.contract_name.clone(),
+                    function.function_name.clone(),
+                    function.calls.to_string(),
+                    function.min.to_string(),
+                    function.mean.to_string(),
+                    function.median.to_string(),
+                    function.max.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let table = format_table(
+            [
+                "Contract", "Function", "Calls", "Min", "Mean", "Median", "Max",
+            ],
+            &rows,
+        );
+        format!("Gas report (l2_gas):\n{}", table.trim_end())
+    }
+
+    fn json(&self) -> Value {
+        let mut json = json!(self);
+        json["message_type"] = json!("gas_report");
+        json
+    }
+}
@@ -0,0 +1,332 @@
+use crate::report::GasReport;
+use crate::table::format_table;
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8Path;
+use forge_runner::test_target_summary::TestTargetSummary;
+use foundry_ui::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::io::ErrorKind;
+
+pub const GAS_SNAPSHOT_FILE: &str = ".gas-snapshot";
+
+/// Gas used by passed tests, saved with `--gas-snapshot` and checked with `--gas-snapshot-check`.
+/// Fuzz tests are not included, as their gas usage depends on the random arguments.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GasSnapshot {
+    tests: BTreeMap<String, GasReport>,
+}
+
+impl GasSnapshot {
+    /// Loads the snapshot, returning `None` if it does not exist.
+    pub fn load(path: &Utf8Path) -> Result<Option<Self>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => Err(err)?,
+        };
+
+        serde_json::from_str(&content)
+            .map(Some)
+            .with_context(|| format!("Failed to read gas snapshot from {path}"))
+    }
+
+    pub fn save(&self, path: &Utf8Path) -> Result<()> {
+        let mut content = serde_json::to_string_pretty(self)?;
+        content.push('\n');
+        fs::write(path, content).with_context(|| format!("Failed to save gas snapshot to {path}"))
+    }
+
+    /// Sets gas usage of all passed tests.
+    /// Entries of tests which were filtered out or have failed are kept, while entries of tests which no longer exist
+    /// in the crates of `all_test_names`, e.g. were removed or renamed, are removed.
+    pub fn update(&mut self, summaries: &[TestTargetSummary], all_test_names: &HashSet<String>) {
+        let crate_names: HashSet<&str> =
+            all_test_names.iter().map(|name| crate_name(name)).collect();
+        self.tests.retain(|name, _| {
+            all_test_names.contains(name) || !crate_names.contains(crate_name(name))
+        });
+
+        self.tests.extend(gas_usages(summaries));
+    }
+
+    /// Compares gas usage of passed tests with the snapshot.
+    /// Gas usage higher than in the snapshot by more than `tolerance` percent is reported as regression.
+    #[must_use]
+    #[expect(clippy::float_cmp)]
+    pub fn check(
+        &self,
+        summaries: &[TestTargetSummary],
+        tolerance: f64,
+    ) -> GasSnapshotCheckMessage {
+        let mut differences = vec![];
+        let mut missing = vec![];
+
+        for (name, gas) in gas_usages(summaries) {
+            let Some(snapshot_gas) = self.tests.get(&name) else {
+                missing.push(name);
+                continue;
+            };
+
+            for ((resource, snapshot), (_, current)) in
+                gas_values(snapshot_gas).into_iter().zip(gas_values(&gas))
+            {
+                if snapshot == current {
+                    continue;
+                }
+                differences.push(GasDifference {
+                    test_name: name.clone(),
+                    resource,
+                    snapshot,
+                    current,
+                    exceeds_tolerance: current > snapshot * (1.0 + tolerance / 100.0),
+                });
+            }
+        }
+
+        GasSnapshotCheckMessage {
+            tolerance,
+            differences,
+            missing,
+        }
+    }
+}
+
+pub fn load_gas_snapshot(path: &Utf8Path) -> Result<GasSnapshot> {
+    GasSnapshot::load(path)?.ok_or_else(|| {
+        anyhow!(
+            "Gas snapshot not found at {path}, create it by running `snforge test --gas-snapshot`"
+        )
+    })
+}
+
+fn gas_usages(summaries: &[TestTargetSummary]) -> impl Iterator<Item = (String, GasReport)> {
+    summaries
+        .iter()
+        .flat_map(|summary| &summary.test_case_summaries)
+        .filter_map(|test_case| Some((test_case.name()?.to_string(), GasReport::new(test_case)?)))
+        .filter(|(_, gas)| matches!(gas, GasReport::Single { .. }))
+}
+
+/// Name of the crate the test belongs to, i.e. the first segment of its path
+fn crate_name(test_name: &str) -> &str {
+    test_name.split("::").next().unwrap_or(test_name)
+}
+
+/// Compared values of every gas resource, mean values are used for fuzz tests
+#[expect(clippy::cast_precision_loss)]
+fn gas_values(gas: &GasReport) -> [(&'static str, f64); 3] {
+    match gas {
+        GasReport::Single {
+            l1_gas,
+            l1_data_gas,
+            l2_gas,
+        } => [
+            ("l1_gas", *l1_gas as f64),
+            ("l1_data_gas", *l1_data_gas as f64),
+            ("l2_gas", *l2_gas as f64),
+        ],
+        GasReport::Fuzzing(gas_statistics) => [
+            ("l1_gas", gas_statistics.l1_gas.mean),
+            ("l1_data_gas", gas_statistics.l1_data_gas.mean),
+            ("l2_gas", gas_statistics.l2_gas.mean),
+        ],
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct GasDifference {
+    test_name: String,
+    resource: &'static str,
+    snapshot: f64,
+    current: f64,
+    exceeds_tolerance: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GasSnapshotCheckMessage {
+    tolerance: f64,
+    differences: Vec<GasDifference>,
+    missing: Vec<String>,
+}
+
+impl GasSnapshotCheckMessage {
+    #[must_use]
+    pub fn has_regressions(&self) -> bool {
+        self.differences
+            .iter()
+            .any(|difference| difference.exceeds_tolerance)
+    }
+}
+
+impl Message for GasSnapshotCheckMessage {
+    fn text(&self) -> String {
+        if self.differences.is_empty() && self.missing.is_empty() {
+            return "Gas usage matches the gas snapshot".to_string();
+        }
+
+        let mut text = String::new();
+        if !self.differences.is_empty() {
+            let rows = self
+                .differences
+                .iter()
+                .map(|difference| {
+                    [
+                        difference.test_name.clone(),
+                        difference.resource.to_string(),
+                        format_gas(difference.snapshot),
+                        format_gas(difference.current),
+                        format_change(difference),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            writeln!(
+                text,
+                "Gas usage differs from the gas snapshot (tolerance: {}%):",
+                self.tolerance
+            )
+            .unwrap();
+            text.push_str(&format_table(
+                ["Test", "Resource", "Snapshot", "Current", "Change"],
+                &rows,
+            ));
+        }
+        if !self.missing.is_empty() {
+            writeln!(
+                text,
+                "Tests missing from the gas snapshot: {}",
+                self.missing.join(", ")
+            )
+            .unwrap();
+        }
+
+        text.trim_end().to_string()
+    }
+
+    fn json(&self) -> Value {
+        let mut json = json!(self);
+        json["message_type"] = json!("gas_snapshot_check");
+        json
+    }
+}
+
+fn format_gas(gas: f64) -> String {
+    if gas.fract() == 0.0 {
+        format!("{gas}")
+    } else {
+        format!("{gas:.2}")
+    }
+}
+
+fn format_change(difference: &GasDifference) -> String {
+    let change = if difference.snapshot == 0.0 {
+        format!("{:+}", difference.current)
+    } else {
+        format!(
+            "{:+.2}%",
+            (difference.current - difference.snapshot) / difference.snapshot * 100.0
+        )
+    };
+
+    if difference.exceeds_tolerance {
+        format!("{change} (regression)")
+    } else {
+        change
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(tests: &[(&str, u64)]) -> GasSnapshot {
+        GasSnapshot {
+            tests: tests
+                .iter()
+                .map(|(name, l2_gas)| {
+                    (
+                        (*name).to_string(),
+                        GasReport::Single {
+                            l1_gas: 0,
+                            l1_data_gas: 96,
+                            l2_gas: *l2_gas,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot = snapshot(&[("tests::a", 100), ("tests::b", 200)]);
+        let content = serde_json::to_string_pretty(&snapshot).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<GasSnapshot>(&content).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn update_removes_tests_which_no_longer_exist() {
+        let mut gas_snapshot = snapshot(&[
+            ("package::tests::removed", 100),
+            ("package::tests::filtered_out", 200),
+            ("other_package::tests::a", 300),
+        ]);
+        let all_test_names = HashSet::from([
+            "package::tests::filtered_out".to_string(),
+            "package::tests::new".to_string(),
+        ]);
+
+        gas_snapshot.update(&[], &all_test_names);
+
+        assert_eq!(
+            gas_snapshot,
+            snapshot(&[
+                ("package::tests::filtered_out", 200),
+                ("other_package::tests::a", 300),
+            ])
+        );
+    }
+
+    #[test]
+    fn formats_differences() {
+        let message = GasSnapshotCheckMessage {
+            tolerance: 5.0,
+            differences: vec![
+                GasDifference {
+                    test_name: "tests::a".to_string(),
+                    resource: "l2_gas",
+                    snapshot: 100.0,
+                    current: 110.0,
+                    exceeds_tolerance: true,
+                },
+                GasDifference {
+                    test_name: "tests::long_name".to_string(),
+                    resource: "l1_data_gas",
+                    snapshot: 200.0,
+                    current: 190.5,
+                    exceeds_tolerance: false,
+                },
+            ],
+            missing: vec!["tests::new".to_string()],
+        };
+
+        assert!(message.has_regressions());
+        assert_eq!(
+            message.text(),
+            "Gas usage differs from the gas snapshot (tolerance: 5%):
+Test             | Resource    | Snapshot | Current | Change
+tests::a         | l2_gas      | 100      | 110     | +10.00% (regression)
+tests::long_name | l1_data_gas | 200      | 190.50  | -4.75%
+Tests missing from the gas snapshot: tests::new"
+        );
+    }
+}
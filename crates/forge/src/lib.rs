@@ -5,9 +5,11 @@ use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use derive_more::Display;
 use forge_runner::CACHE_DIR;
 use forge_runner::debugging::TraceVerbosity;
-use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::forge_config::{ForgeTrackedResource, FuzzerStrategy};
 use foundry_ui::components::warning::WarningMessage;
-use foundry_ui::{Message, UI};
+use foundry_ui::{Message, OutputFormat, UI};
+use report::ReportArg;
+use run_tests::watch::watch_workspace;
 use run_tests::workspace::run_for_workspace;
 use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
 use scarb_ui::args::{FeaturesSpec, PackagesFilter, ProfileSpec};
@@ -18,18 +20,25 @@ use std::ffi::OsString;
 use std::process::Command;
 use std::sync::Arc;
 use std::{fs, num::NonZeroU32, thread::available_parallelism};
+use test_filter::Partition;
 use tokio::runtime::Builder;
 use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
+use url::Url;
 
 pub mod block_number_map;
 mod clean;
 mod combine_configs;
 mod compatibility_check;
+mod fork;
+pub mod gas_report;
+pub mod gas_snapshot;
 mod init;
 mod new;
+pub mod report;
 pub mod run_tests;
 pub mod scarb;
 pub mod shared_cache;
+mod table;
 pub mod test_filter;
 mod warn;
 
@@ -105,6 +114,11 @@ enum ForgeSubcommand {
     },
     /// Clean Forge cache directory
     CleanCache {},
+    /// Export and import fork fixtures, which let fork tests run without connecting to the node
+    Fork {
+        #[command(flatten)]
+        args: ForkArgs,
+    },
     /// Check if all `snforge` requirements are installed
     CheckRequirements,
     /// Generate completions script
@@ -119,6 +133,42 @@ pub struct CleanArgs {
     pub clean_components: Vec<CleanComponent>,
 }
 
+#[derive(Parser, Debug)]
+pub struct ForkArgs {
+    #[command(subcommand)]
+    pub command: ForkCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ForkCommand {
+    /// Export the fork state saved in the fork cache to a fixture file
+    Export(ForkExportArgs),
+    /// Import the fork state from a fixture file to the fork cache
+    Import(ForkImportArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct ForkExportArgs {
+    /// Url of the RPC provider the fork was created from
+    #[arg(long)]
+    pub url: Url,
+    /// Number of the block the fork was created from
+    #[arg(long)]
+    pub block_number: u64,
+    /// Path to the fixture file to create
+    #[arg(short, long)]
+    pub output: Utf8PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub struct ForkImportArgs {
+    /// Path to the fixture file
+    pub path: Utf8PathBuf,
+    /// Url of the RPC provider used by the fork tests, on the chain of the fixture
+    #[arg(long)]
+    pub url: Url,
+}
+
 #[derive(ValueEnum, Debug, Clone, PartialEq, Eq)]
 pub enum CleanComponent {
     /// Clean the `coverage` directory
@@ -140,6 +190,23 @@ enum ColorOption {
     Never,
 }
 
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum FormatOption {
+    /// Human-readable output
+    Human,
+    /// Stream of JSON events, one per line
+    Json,
+}
+
+impl From<FormatOption> for OutputFormat {
+    fn from(format: FormatOption) -> Self {
+        match format {
+            FormatOption::Human => OutputFormat::Human,
+            FormatOption::Json => OutputFormat::Json,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[expect(clippy::struct_excessive_bools)]
 pub struct TestArgs {
@@ -168,6 +235,9 @@ pub struct TestArgs {
     /// Seed for the fuzzer
     #[arg(short = 's', long, env = "SNFORGE_FUZZER_SEED")]
     fuzzer_seed: Option<u64>,
+    /// Strategy used by the fuzzer to generate arguments
+    #[arg(long, value_enum, default_value_t)]
+    fuzzer_strategy: FuzzerStrategy,
 
     /// Run only tests marked with `#[ignore]` attribute
     #[arg(long = "ignored")]
@@ -184,10 +254,23 @@ pub struct TestArgs {
     #[arg(value_enum, long, default_value_t = ColorOption::Auto, value_name="WHEN")]
     color: ColorOption,
 
+    /// Format of the output
+    #[arg(value_enum, long, default_value_t = FormatOption::Human)]
+    format: FormatOption,
+
     /// Run tests that failed during the last run
     #[arg(long)]
     rerun_failed: bool,
 
+    /// Rerun tests every time a source file of the package changes, running tests that failed during the last run first
+    #[arg(long)]
+    watch: bool,
+
+    /// Run only the given partition of tests, e.g. `1/3` or `hash:1/3`.
+    /// Tests are split between partitions by count (default) or by hash of their name
+    #[arg(long, value_name = "[count:|hash:]INDEX/TOTAL")]
+    partition: Option<Partition>,
+
     /// Save execution traces of all test which have passed and are not fuzz tests
     #[arg(long)]
     save_trace_data: bool,
@@ -204,6 +287,14 @@ pub struct TestArgs {
     #[arg(long)]
     max_n_steps: Option<u32>,
 
+    /// Time in seconds after which a test is stopped and reported as timed out. For fuzz tests this value is applied to each subtest separately.
+    #[arg(long, value_name = "SECONDS")]
+    test_timeout: Option<u64>,
+
+    /// Run fork tests using only the fork cache, failing on values missing in it instead of fetching them from the node
+    #[arg(long)]
+    offline: bool,
+
     /// Build contracts separately in the scarb starknet contract target
     #[arg(long)]
     no_optimization: bool,
@@ -212,6 +303,26 @@ pub struct TestArgs {
     #[arg(long, value_enum, default_value_t)]
     tracked_resource: ForgeTrackedResource,
 
+    /// Save a report of the test results, can be specified multiple times. Supported formats: junit, json
+    #[arg(long, value_name = "FORMAT=PATH")]
+    report: Vec<ReportArg>,
+
+    /// Print a table with gas used by functions of deployed contracts over all passed tests
+    #[arg(long)]
+    gas_report: bool,
+
+    /// Save gas usage of passed tests to the `.gas-snapshot` file in the workspace root
+    #[arg(long, conflicts_with = "gas_snapshot_check")]
+    gas_snapshot: bool,
+
+    /// Fail if gas usage of any test exceeds the one saved in the `.gas-snapshot` file
+    #[arg(long)]
+    gas_snapshot_check: bool,
+
+    /// Increase of gas usage in percent allowed by `--gas-snapshot-check`
+    #[arg(long, default_value_t = 0.0, requires = "gas_snapshot_check")]
+    gas_snapshot_tolerance: f64,
+
     /// Additional arguments for cairo-coverage or cairo-profiler
     #[arg(last = true)]
     additional_args: Vec<OsString>,
@@ -295,7 +406,12 @@ pub fn main_execution(ui: Arc<UI>) -> Result<ExitStatus> {
 
             Ok(ExitStatus::Success)
         }
+        ForgeSubcommand::Fork { args } => {
+            fork::fork(args, &ui)?;
+            Ok(ExitStatus::Success)
+        }
         ForgeSubcommand::Test { args } => {
+            let ui = Arc::new(UI::new(args.format.into()));
             check_requirements(false, args.tracked_resource, &ui)?;
             let cores = if let Ok(available_cores) = available_parallelism() {
                 available_cores.get()
@@ -309,7 +425,15 @@ pub fn main_execution(ui: Arc<UI>) -> Result<ExitStatus> {
                 .enable_all()
                 .build()?;
 
-            rt.block_on(run_for_workspace(args, ui))
+            let result = if args.watch {
+                rt.block_on(watch_workspace(args, ui))
+            } else {
+                rt.block_on(run_for_workspace(&args, ui))
+            };
+            // Tests abandoned after exceeding their timeout may still be blocked, e.g. on a fork RPC request
+            rt.shutdown_background();
+
+            result
         }
         ForgeSubcommand::CheckRequirements => {
             check_requirements(true, ForgeTrackedResource::default(), &ui)?;
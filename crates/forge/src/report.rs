@@ -0,0 +1,305 @@
+use anyhow::{Context, Result, anyhow};
+use camino::{Utf8Path, Utf8PathBuf};
+use forge_runner::test_case_summary::{AnyTestCaseSummary, GasStatistics, TestCaseSummary};
+use forge_runner::test_target_summary::TestTargetSummary;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::str::FromStr;
+use std::time::Duration;
+
+mod json;
+mod junit;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Junit,
+    Json,
+}
+
+/// Report requested with `--report <FORMAT>=<PATH>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportArg {
+    pub format: ReportFormat,
+    pub path: Utf8PathBuf,
+}
+
+impl FromStr for ReportArg {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (format, path) = value
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Report must be specified as <FORMAT>=<PATH>, got: {value}"))?;
+
+        let format = match format {
+            "junit" => ReportFormat::Junit,
+            "json" => ReportFormat::Json,
+            _ => {
+                return Err(anyhow!(
+                    "Unsupported report format: {format}, supported formats are: junit, json"
+                ));
+            }
+        };
+        if path.is_empty() {
+            return Err(anyhow!("Path of the {value} report must not be empty"));
+        }
+
+        Ok(Self {
+            format,
+            path: Utf8PathBuf::from(path),
+        })
+    }
+}
+
+/// Results of tests from a single package, in the form saved to reports.
+#[derive(Debug, Serialize)]
+pub struct PackageReport {
+    pub name: String,
+    pub test_cases: Vec<TestCaseReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TestCaseReport {
+    pub name: String,
+    pub status: TestCaseStatus,
+    /// Time spent executing the test case in seconds
+    pub duration: f64,
+    pub gas: Option<GasReport>,
+    pub fuzzer: Option<FuzzerReport>,
+    /// Panic data of a failed test together with the backtrace, if it was decoded,
+    /// or the exceeded timeout of a timed out test
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TestCaseStatus {
+    Passed,
+    Failed,
+    TimedOut,
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GasReport {
+    Single {
+        l1_gas: u64,
+        l1_data_gas: u64,
+        l2_gas: u64,
+    },
+    Fuzzing(GasStatistics),
+}
+
+#[derive(Debug, Serialize)]
+pub struct FuzzerReport {
+    pub runs: usize,
+    /// Arguments of the failing run
+    pub arguments: Option<Vec<String>>,
+    /// Arguments of the smallest failing run found by shrinking
+    pub shrunk_arguments: Option<Vec<String>>,
+}
+
+impl PackageReport {
+    /// Creates a report from the summaries of all test targets of the package.
+    /// Interrupted test cases are not included.
+    #[must_use]
+    pub fn new(name: &str, summaries: &[TestTargetSummary]) -> Self {
+        let test_cases = summaries
+            .iter()
+            .flat_map(|summary| {
+                summary.test_case_summaries.iter().filter_map(|test_case| {
+                    TestCaseReport::new(test_case, summary.duration(test_case).unwrap_or_default())
+                })
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            test_cases,
+        }
+    }
+
+    fn count(&self, status: TestCaseStatus) -> usize {
+        self.test_cases
+            .iter()
+            .filter(|test_case| test_case.status == status)
+            .count()
+    }
+
+    fn duration(&self) -> f64 {
+        self.test_cases
+            .iter()
+            .map(|test_case| test_case.duration)
+            .sum()
+    }
+}
+
+impl GasReport {
+    /// Gas used by a passed test case, or gas statistics of all runs of a passed fuzz test
+    #[must_use]
+    pub fn new(test_case: &AnyTestCaseSummary) -> Option<Self> {
+        match test_case {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(Self::Single {
+                    l1_gas: gas_info.l1_gas.0,
+                    l1_data_gas: gas_info.l1_data_gas.0,
+                    l2_gas: gas_info.l2_gas.0,
+                })
+            }
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed { gas_info, .. }) => {
+                Some(Self::Fuzzing(gas_info.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TestCaseReport {
+    fn new(test_case: &AnyTestCaseSummary, duration: Duration) -> Option<Self> {
+        let name = test_case.name()?.to_string();
+        let message = test_case.msg().map(str::to_string);
+        let duration = duration.as_secs_f64();
+        let gas = GasReport::new(test_case);
+
+        let report = match test_case {
+            AnyTestCaseSummary::Single(TestCaseSummary::Passed { .. }) => Self {
+                name,
+                status: TestCaseStatus::Passed,
+                duration,
+                gas,
+                fuzzer: None,
+                message,
+            },
+            AnyTestCaseSummary::Single(TestCaseSummary::Failed { .. }) => Self {
+                name,
+                status: TestCaseStatus::Failed,
+                duration,
+                gas: None,
+                fuzzer: None,
+                message,
+            },
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Passed {
+                test_statistics, ..
+            }) => Self {
+                name,
+                status: TestCaseStatus::Passed,
+                duration,
+                gas,
+                fuzzer: Some(FuzzerReport {
+                    runs: test_statistics.runs,
+                    arguments: None,
+                    shrunk_arguments: None,
+                }),
+                message,
+            },
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+                fuzzer_args,
+                shrunk_fuzzer_args,
+                test_statistics,
+                ..
+            }) => Self {
+                name,
+                status: TestCaseStatus::Failed,
+                duration,
+                gas: None,
+                fuzzer: Some(FuzzerReport {
+                    runs: test_statistics.runs,
+                    arguments: Some(fuzzer_args.clone()),
+                    shrunk_arguments: shrunk_fuzzer_args.clone(),
+                }),
+                message,
+            },
+            AnyTestCaseSummary::Single(TestCaseSummary::TimedOut { .. }) => Self {
+                name,
+                status: TestCaseStatus::TimedOut,
+                duration,
+                gas: None,
+                fuzzer: None,
+                message,
+            },
+            AnyTestCaseSummary::Fuzzing(TestCaseSummary::TimedOut {
+                fuzzer_args,
+                test_statistics,
+                ..
+            }) => Self {
+                name,
+                status: TestCaseStatus::TimedOut,
+                duration,
+                gas: None,
+                fuzzer: Some(FuzzerReport {
+                    runs: test_statistics.runs,
+                    arguments: Some(fuzzer_args.clone()),
+                    shrunk_arguments: None,
+                }),
+                message,
+            },
+            AnyTestCaseSummary::Single(TestCaseSummary::Ignored { .. })
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Ignored { .. }) => Self {
+                name,
+                status: TestCaseStatus::Ignored,
+                duration,
+                gas: None,
+                fuzzer: None,
+                message: None,
+            },
+            AnyTestCaseSummary::Single(TestCaseSummary::Interrupted {})
+            | AnyTestCaseSummary::Fuzzing(TestCaseSummary::Interrupted {}) => return None,
+        };
+
+        Some(report)
+    }
+}
+
+/// Saves the reports, resolving their relative paths against `base_dir`
+pub fn save_reports(
+    reports: &[ReportArg],
+    packages: &[PackageReport],
+    base_dir: &Utf8Path,
+) -> Result<()> {
+    for ReportArg { format, path } in reports {
+        let path = base_dir.join(path);
+        let content = match format {
+            ReportFormat::Junit => junit::render(packages),
+            ReportFormat::Json => json::render(packages)?,
+        };
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory for report {path}"))?;
+        }
+        fs::write(&path, content).with_context(|| format!("Failed to save report to {path}"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_report_arg() {
+        assert_eq!(
+            ReportArg::from_str("junit=target/report.xml").unwrap(),
+            ReportArg {
+                format: ReportFormat::Junit,
+                path: Utf8PathBuf::from("target/report.xml"),
+            }
+        );
+        assert_eq!(
+            ReportArg::from_str("json=report.json").unwrap(),
+            ReportArg {
+                format: ReportFormat::Json,
+                path: Utf8PathBuf::from("report.json"),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_report_arg() {
+        assert!(ReportArg::from_str("junit").is_err());
+        assert!(ReportArg::from_str("xml=report.xml").is_err());
+        assert!(ReportArg::from_str("json=").is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use super::PackageReport;
+use anyhow::Result;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    packages: &'a [PackageReport],
+}
+
+pub(super) fn render(packages: &[PackageReport]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&JsonReport { packages })?)
+}
@@ -0,0 +1,274 @@
+use super::{FuzzerReport, GasReport, PackageReport, TestCaseReport, TestCaseStatus};
+use std::fmt::Write;
+
+/// Renders the report in the JUnit XML format, with a test suite for every package.
+pub(super) fn render(packages: &[PackageReport]) -> String {
+    let tests = packages
+        .iter()
+        .map(|package| package.test_cases.len())
+        .sum::<usize>();
+    let failures = packages.iter().map(failures).sum::<usize>();
+    let skipped = packages
+        .iter()
+        .map(|package| package.count(TestCaseStatus::Ignored))
+        .sum::<usize>();
+    let time = packages.iter().map(PackageReport::duration).sum::<f64>();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        xml,
+        r#"<testsuites name="snforge" tests="{tests}" failures="{failures}" skipped="{skipped}" time="{time:.3}">"#
+    )
+    .unwrap();
+    for package in packages {
+        render_test_suite(&mut xml, package);
+    }
+    xml.push_str("</testsuites>\n");
+
+    xml
+}
+
+fn render_test_suite(xml: &mut String, package: &PackageReport) {
+    writeln!(
+        xml,
+        r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{:.3}">"#,
+        escape(&package.name),
+        package.test_cases.len(),
+        failures(package),
+        package.count(TestCaseStatus::Ignored),
+        package.duration(),
+    )
+    .unwrap();
+    for test_case in &package.test_cases {
+        render_test_case(xml, test_case);
+    }
+    xml.push_str("  </testsuite>\n");
+}
+
+fn render_test_case(xml: &mut String, test_case: &TestCaseReport) {
+    let (class_name, name) = test_case
+        .name
+        .rsplit_once("::")
+        .unwrap_or(("", test_case.name.as_str()));
+
+    writeln!(
+        xml,
+        r#"    <testcase name="{}" classname="{}" time="{:.3}">"#,
+        escape(name),
+        escape(class_name),
+        test_case.duration,
+    )
+    .unwrap();
+
+    let properties = properties(test_case);
+    if !properties.is_empty() {
+        xml.push_str("      <properties>\n");
+        for (name, value) in properties {
+            writeln!(
+                xml,
+                r#"        <property name="{name}" value="{}"/>"#,
+                escape(&value)
+            )
+            .unwrap();
+        }
+        xml.push_str("      </properties>\n");
+    }
+
+    match test_case.status {
+        TestCaseStatus::Passed => {}
+        TestCaseStatus::Failed => {
+            let message = test_case.message.as_deref().unwrap_or_default();
+            let summary = message
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or("Test failed");
+            writeln!(
+                xml,
+                r#"      <failure message="{}">{}</failure>"#,
+                escape(summary),
+                escape(message),
+            )
+            .unwrap();
+        }
+        TestCaseStatus::TimedOut => {
+            let message = test_case.message.as_deref().unwrap_or("Test timed out");
+            writeln!(
+                xml,
+                r#"      <failure message="{}" type="timeout"/>"#,
+                escape(message),
+            )
+            .unwrap();
+        }
+        TestCaseStatus::Ignored => xml.push_str("      <skipped/>\n"),
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+/// Timed out test cases are reported as failures, as JUnit has no separate status for them
+fn failures(package: &PackageReport) -> usize {
+    package.count(TestCaseStatus::Failed) + package.count(TestCaseStatus::TimedOut)
+}
+
+/// Gas usage and fuzzer statistics of the test case as name-value pairs.
+fn properties(test_case: &TestCaseReport) -> Vec<(&'static str, String)> {
+    let mut properties = vec![];
+
+    match &test_case.gas {
+        Some(GasReport::Single {
+            l1_gas,
+            l1_data_gas,
+            l2_gas,
+        }) => {
+            properties.push(("l1_gas", l1_gas.to_string()));
+            properties.push(("l1_data_gas", l1_data_gas.to_string()));
+            properties.push(("l2_gas", l2_gas.to_string()));
+        }
+        Some(GasReport::Fuzzing(gas_statistics)) => {
+            properties.push(("l1_gas", gas_statistics.l1_gas.to_string()));
+            properties.push(("l1_data_gas", gas_statistics.l1_data_gas.to_string()));
+            properties.push(("l2_gas", gas_statistics.l2_gas.to_string()));
+        }
+        None => {}
+    }
+
+    if let Some(FuzzerReport {
+        runs,
+        arguments,
+        shrunk_arguments,
+    }) = &test_case.fuzzer
+    {
+        properties.push(("fuzzer_runs", runs.to_string()));
+        if let Some(arguments) = arguments {
+            properties.push(("fuzzer_arguments", format!("{arguments:?}")));
+        }
+        if let Some(shrunk_arguments) = shrunk_arguments {
+            properties.push(("fuzzer_shrunk_arguments", format!("{shrunk_arguments:?}")));
+        }
+    }
+
+    properties
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Other control characters are not allowed in XML 1.0
+            '\n' | '\r' | '\t' => escaped.push(character),
+            character if character.is_control() => {}
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_test_cases() {
+        let packages = [PackageReport {
+            name: "my_package".to_string(),
+            test_cases: vec![
+                TestCaseReport {
+                    name: "my_package::tests::test_passing".to_string(),
+                    status: TestCaseStatus::Passed,
+                    duration: 0.5,
+                    gas: Some(GasReport::Single {
+                        l1_gas: 0,
+                        l1_data_gas: 96,
+                        l2_gas: 40000,
+                    }),
+                    fuzzer: None,
+                    message: None,
+                },
+                TestCaseReport {
+                    name: "my_package::tests::test_failing".to_string(),
+                    status: TestCaseStatus::Failed,
+                    duration: 0.25,
+                    gas: None,
+                    fuzzer: Some(FuzzerReport {
+                        runs: 3,
+                        arguments: Some(vec!["5".to_string()]),
+                        shrunk_arguments: Some(vec!["1".to_string()]),
+                    }),
+                    message: Some("\n    0x0 ('a < b')\n".to_string()),
+                },
+                TestCaseReport {
+                    name: "my_package::tests::test_ignored".to_string(),
+                    status: TestCaseStatus::Ignored,
+                    duration: 0.0,
+                    gas: None,
+                    fuzzer: None,
+                    message: None,
+                },
+            ],
+        }];
+
+        assert_eq!(
+            render(&packages),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="snforge" tests="3" failures="1" skipped="1" time="0.750">
+  <testsuite name="my_package" tests="3" failures="1" skipped="1" time="0.750">
+    <testcase name="test_passing" classname="my_package::tests" time="0.500">
+      <properties>
+        <property name="l1_gas" value="0"/>
+        <property name="l1_data_gas" value="96"/>
+        <property name="l2_gas" value="40000"/>
+      </properties>
+    </testcase>
+    <testcase name="test_failing" classname="my_package::tests" time="0.250">
+      <properties>
+        <property name="fuzzer_runs" value="3"/>
+        <property name="fuzzer_arguments" value="[&quot;5&quot;]"/>
+        <property name="fuzzer_shrunk_arguments" value="[&quot;1&quot;]"/>
+      </properties>
+      <failure message="0x0 (&apos;a &lt; b&apos;)">
+    0x0 (&apos;a &lt; b&apos;)
+</failure>
+    </testcase>
+    <testcase name="test_ignored" classname="my_package::tests" time="0.000">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn renders_timed_out_test_case_as_failure() {
+        let packages = [PackageReport {
+            name: "my_package".to_string(),
+            test_cases: vec![TestCaseReport {
+                name: "my_package::tests::test_slow".to_string(),
+                status: TestCaseStatus::TimedOut,
+                duration: 30.0,
+                gas: None,
+                fuzzer: None,
+                message: Some("Test did not finish within the timeout of 30s".to_string()),
+            }],
+        }];
+
+        assert_eq!(
+            render(&packages),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="snforge" tests="1" failures="1" skipped="0" time="30.000">
+  <testsuite name="my_package" tests="1" failures="1" skipped="0" time="30.000">
+    <testcase name="test_slow" classname="my_package::tests" time="30.000">
+      <failure message="Test did not finish within the timeout of 30s" type="timeout"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+}
@@ -3,4 +3,5 @@ pub mod package;
 pub mod resolve_config;
 pub mod structs;
 pub mod test_target;
+pub mod watch;
 pub mod workspace;
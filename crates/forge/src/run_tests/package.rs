@@ -11,7 +11,7 @@ use crate::{
         config::{ForgeConfigFromScarb, ForkTarget},
         load_test_artifacts, should_compile_starknet_contract_target,
     },
-    shared_cache::FailedTestsCache,
+    shared_cache::{FailedTestsCache, FuzzerCorpusCache},
     test_filter::{NameFilter, TestsFilter},
     warn::{
         warn_if_available_gas_used_with_incompatible_scarb_version,
@@ -25,7 +25,7 @@ use configuration::load_package_config;
 use console::Style;
 use forge_runner::debugging::TraceVerbosity;
 use forge_runner::{
-    forge_config::ForgeConfig,
+    forge_config::{ForgeConfig, ForkMode},
     package_tests::{raw::TestTargetRaw, with_config_resolved::TestTargetWithResolvedConfig},
     running::with_config::test_target_with_config,
     test_case_summary::AnyTestCaseSummary,
@@ -97,14 +97,19 @@ impl RunForPackageArgs {
             args.exit_first,
             args.fuzzer_runs,
             args.fuzzer_seed,
+            args.fuzzer_strategy,
             args.detailed_resources,
             args.save_trace_data,
             args.build_profile,
             args.coverage,
+            args.gas_report,
+            args.offline,
             args.max_n_steps,
+            args.test_timeout,
             args.tracked_resource,
             contracts_data,
             cache_dir.clone(),
+            FuzzerCorpusCache::new(cache_dir).load()?,
             &forge_config_from_scarb,
             &args.additional_args,
         ));
@@ -116,6 +121,7 @@ impl RunForPackageArgs {
             args.only_ignored,
             args.include_ignored,
             args.rerun_failed,
+            args.partition,
             FailedTestsCache::new(cache_dir),
         );
 
@@ -143,7 +149,13 @@ async fn test_package_with_config_resolved(
             &forge_config.test_runner_config.tracked_resource,
         )?;
 
-        let test_target = resolve_config(test_target, fork_targets, block_number_map).await?;
+        let test_target = resolve_config(
+            test_target,
+            fork_targets,
+            block_number_map,
+            forge_config.test_runner_config.fork_mode,
+        )
+        .await?;
 
         test_targets_with_resolved_config.push(test_target);
     }
@@ -155,7 +167,82 @@ fn sum_test_cases(test_targets: &[TestTargetWithResolvedConfig]) -> usize {
     test_targets.iter().map(|tc| tc.test_cases.len()).sum()
 }
 
-pub async fn run_for_package(
+/// Package with test targets resolved and filtered, ready to be run
+pub struct PreparedPackage {
+    test_targets: Vec<TestTargetWithResolvedConfig>,
+    forge_config: Arc<ForgeConfig>,
+    tests_filter: Arc<TestsFilter>,
+    package_name: String,
+    /// Names of all tests of the package, including the filtered out ones
+    all_test_names: Vec<String>,
+}
+
+impl PreparedPackage {
+    /// Moves the tests for which the predicate holds to a new package, keeping the others
+    #[must_use]
+    pub fn split_off(&mut self, predicate: impl Fn(&str) -> bool) -> Self {
+        let test_targets = self
+            .test_targets
+            .iter_mut()
+            .map(|test_target| {
+                let (split_off, kept) = test_target
+                    .test_cases
+                    .drain(..)
+                    .partition(|test_case| predicate(&test_case.name));
+                test_target.test_cases = kept;
+
+                TestTargetWithResolvedConfig {
+                    tests_location: test_target.tests_location.clone(),
+                    sierra_program: test_target.sierra_program.clone(),
+                    sierra_program_path: test_target.sierra_program_path.clone(),
+                    casm_program: test_target.casm_program.clone(),
+                    test_cases: split_off,
+                }
+            })
+            .collect();
+
+        Self {
+            test_targets,
+            forge_config: self.forge_config.clone(),
+            tests_filter: self.tests_filter.clone(),
+            package_name: self.package_name.clone(),
+            all_test_names: self.all_test_names.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn all_test_names(&self) -> &[String] {
+        &self.all_test_names
+    }
+}
+
+/// Keeps only the tests of the partition selected with `--partition`.
+/// Tests of all packages are partitioned together, so it must be called after all of them are prepared.
+pub fn partition_packages(prepared_packages: &mut [PreparedPackage]) {
+    let Some(tests_filter) = prepared_packages
+        .first()
+        .map(|package| package.tests_filter.clone())
+    else {
+        return;
+    };
+
+    tests_filter.partition_tests(prepared_packages.iter_mut().flat_map(
+        |PreparedPackage {
+             test_targets,
+             package_name,
+             ..
+         }| {
+            let package_name = package_name.as_str();
+            test_targets
+                .iter_mut()
+                .map(move |test_target| (package_name, &mut test_target.test_cases))
+        },
+    ));
+}
+
+/// Resolves the config of the tests of the package and applies the filters to them,
+/// except for the partition, which is applied by [`partition_packages`].
+pub async fn prepare_package(
     RunForPackageArgs {
         test_targets,
         forge_config,
@@ -164,9 +251,8 @@ pub async fn run_for_package(
         package_name,
     }: RunForPackageArgs,
     block_number_map: &mut BlockNumberMap,
-    trace_verbosity: Option<TraceVerbosity>,
     ui: Arc<UI>,
-) -> Result<PackageTestResult> {
+) -> Result<PreparedPackage> {
     let mut test_targets = test_package_with_config_resolved(
         test_targets,
         &fork_targets,
@@ -174,15 +260,42 @@ pub async fn run_for_package(
         &forge_config,
     )
     .await?;
-    let all_tests = sum_test_cases(&test_targets);
+    let all_test_names = test_targets
+        .iter()
+        .flat_map(|test_target| &test_target.test_cases)
+        .map(|test_case| test_case.name.clone())
+        .collect();
 
     for test_target in &mut test_targets {
         tests_filter.filter_tests(&mut test_target.test_cases)?;
     }
 
     warn_if_available_gas_used_with_incompatible_scarb_version(&test_targets, &ui)?;
-    warn_if_incompatible_rpc_version(&test_targets, ui.clone()).await?;
+    // Nodes can't be reached in offline mode, so their versions aren't checked
+    if forge_config.test_runner_config.fork_mode == ForkMode::Online {
+        warn_if_incompatible_rpc_version(&test_targets, ui).await?;
+    }
+
+    Ok(PreparedPackage {
+        test_targets,
+        forge_config,
+        tests_filter: Arc::new(tests_filter),
+        package_name,
+        all_test_names,
+    })
+}
 
+pub async fn run_for_package(
+    PreparedPackage {
+        test_targets,
+        forge_config,
+        tests_filter,
+        package_name,
+        all_test_names,
+    }: PreparedPackage,
+    trace_verbosity: Option<TraceVerbosity>,
+    ui: Arc<UI>,
+) -> Result<PackageTestResult> {
     let not_filtered = sum_test_cases(&test_targets);
     ui.println(&CollectedTestsCountMessage {
         tests_num: not_filtered,
@@ -201,7 +314,7 @@ pub async fn run_for_package(
         let summary = run_for_test_target(
             test_target,
             forge_config.clone(),
-            &tests_filter,
+            tests_filter.as_ref(),
             trace_verbosity,
             ui,
         )
@@ -225,17 +338,24 @@ pub async fn run_for_package(
     let filtered_count = if let NameFilter::ExactMatch(_) = tests_filter.name_filter {
         None
     } else {
-        Some(all_tests - not_filtered)
+        Some(all_test_names.len() - not_filtered)
     };
 
     ui.println(&TestsSummaryMessage::new(&summaries, filtered_count));
 
+    if let Some(partition) = tests_filter.partition() {
+        ui.println(&LabeledMessage::new(
+            &Style::new().bold().apply_to("Partition").to_string(),
+            &partition.to_string(),
+        ));
+    }
+
     let any_fuzz_test_was_run = summaries.iter().any(|test_target_summary| {
         test_target_summary
             .test_case_summaries
             .iter()
             .filter(|summary| matches!(summary, AnyTestCaseSummary::Fuzzing(_)))
-            .any(|summary| summary.is_passed() || summary.is_failed())
+            .any(|summary| summary.is_passed() || summary.is_failed() || summary.is_timed_out())
     });
 
     if any_fuzz_test_was_run {
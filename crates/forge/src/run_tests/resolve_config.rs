@@ -5,6 +5,7 @@ use cheatnet::runtime_extensions::forge_config_extension::config::{
     BlockId, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
 };
 use conversions::byte_array::ByteArray;
+use forge_runner::forge_config::ForkMode;
 use forge_runner::package_tests::{
     with_config::TestTargetWithConfig,
     with_config_resolved::{
@@ -18,6 +19,7 @@ pub async fn resolve_config(
     test_target: TestTargetWithConfig,
     fork_targets: &[ForkTarget],
     block_number_map: &mut BlockNumberMap,
+    fork_mode: ForkMode,
 ) -> Result<TestTargetWithResolvedConfig> {
     let mut test_cases = Vec::with_capacity(test_target.test_cases.len());
     let env_ignore_fork_tests = env_ignore_fork_tests();
@@ -35,10 +37,13 @@ pub async fn resolve_config(
                     case.config.fork_config,
                     block_number_map,
                     fork_targets,
+                    fork_mode,
                 )
                 .await?,
                 fuzzer_config: case.config.fuzzer_config,
+                invariant_config: case.config.invariant_config,
                 disable_predeployed_contracts: case.config.disable_predeployed_contracts,
+                timeout: case.config.timeout,
             },
         });
     }
@@ -56,6 +61,7 @@ async fn resolve_fork_config(
     fork_config: Option<RawForkConfig>,
     block_number_map: &mut BlockNumberMap,
     fork_targets: &[ForkTarget],
+    fork_mode: ForkMode,
 ) -> Result<Option<ResolvedForkConfig>> {
     let Some(fc) = fork_config else {
         return Ok(None);
@@ -67,6 +73,12 @@ async fn resolve_fork_config(
 
     let block_number = match raw_fork_params.block {
         BlockId::BlockNumber(block_number) => BlockNumber(block_number),
+        // Resolving block hashes and tags requires fetching blocks from the node
+        BlockId::BlockHash(_) | BlockId::BlockTag if fork_mode == ForkMode::Offline => {
+            return Err(anyhow!(
+                "Fork with url = {url} must specify the block by its number in offline mode"
+            ));
+        }
         BlockId::BlockHash(hash) => {
             block_number_map
                 .get_block_number_for_hash(url.clone(), hash)
@@ -167,7 +179,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: Some(RawForkConfig::Named("non_existent".into())),
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
                 test_details: TestDetails {
                     sierra_entry_point_statement_idx: 100,
@@ -193,10 +207,33 @@ mod tests {
                     url: Url::parse("https://not_taken.com").expect("Should be valid url"),
                     block_id: BlockId::BlockNumber(120),
                 }],
-                &mut BlockNumberMap::default()
+                &mut BlockNumberMap::default(),
+                ForkMode::Online,
             )
             .await
             .is_err()
         );
     }
+
+    #[tokio::test]
+    async fn block_tag_in_offline_mode() {
+        let fork_config = RawForkConfig::Inline(InlineForkConfig {
+            url: Url::parse("https://not_taken.com").expect("Should be valid url"),
+            block: BlockId::BlockTag,
+        });
+
+        let error = resolve_fork_config(
+            Some(fork_config),
+            &mut BlockNumberMap::default(),
+            &[],
+            ForkMode::Offline,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Fork with url = https://not_taken.com/ must specify the block by its number in offline mode"
+        );
+    }
 }
@@ -37,7 +37,7 @@ impl Message for TestsRunMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("tests_run", json!(self))
     }
 }
 
@@ -57,7 +57,7 @@ impl Message for CollectedTestsCountMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("tests_collected", json!(self))
     }
 }
 
@@ -66,6 +66,7 @@ impl Message for CollectedTestsCountMessage {
 struct TestsSummary {
     passed: usize,
     failed: usize,
+    timed_out: usize,
     interrupted: usize,
     ignored: usize,
     filtered: Option<usize>,
@@ -76,6 +77,10 @@ impl TestsSummary {
     fn new(summaries: &[TestTargetSummary], filtered: Option<usize>) -> Self {
         let passed = summaries.iter().map(TestTargetSummary::count_passed).sum();
         let failed = summaries.iter().map(TestTargetSummary::count_failed).sum();
+        let timed_out = summaries
+            .iter()
+            .map(TestTargetSummary::count_timed_out)
+            .sum();
         let interrupted = summaries
             .iter()
             .map(TestTargetSummary::count_interrupted)
@@ -85,6 +90,7 @@ impl TestsSummary {
         Self {
             passed,
             failed,
+            timed_out,
             interrupted,
             ignored,
             filtered,
@@ -102,8 +108,14 @@ impl TestsSummary {
             String::new()
         };
 
+        let timed_out = if self.timed_out > 0 {
+            format!(" {} timed out,", self.timed_out)
+        } else {
+            String::new()
+        };
+
         format!(
-            "{} passed, {} failed, {} ignored, {filtered} filtered out{interrupted}",
+            "{} passed, {} failed,{timed_out} {} ignored, {filtered} filtered out{interrupted}",
             self.passed, self.failed, self.ignored,
         )
     }
@@ -132,7 +144,7 @@ impl Message for TestsSummaryMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("tests_summary", json!(self))
     }
 }
 
@@ -167,7 +179,7 @@ impl Message for TestsFailureSummaryMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("tests_failure_summary", json!(self))
     }
 }
 
@@ -203,7 +215,7 @@ impl Message for LatestBlocksNumbersMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("latest_blocks_numbers", json!(self))
     }
 }
 
@@ -230,6 +242,14 @@ impl Message for OverallSummaryMessage {
     }
 
     fn json(&self) -> Value {
-        json!(self)
+        with_message_type("overall_summary", json!(self))
+    }
+}
+
+/// Adds `message_type` to the JSON representation of a message, so events in the JSON output can be told apart.
+fn with_message_type(message_type: &str, mut json: Value) -> Value {
+    if let Value::Object(fields) = &mut json {
+        fields.insert("message_type".to_string(), json!(message_type));
     }
+    json
 }
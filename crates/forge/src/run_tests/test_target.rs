@@ -12,7 +12,9 @@ use forge_runner::{
 };
 use foundry_ui::UI;
 use futures::{StreamExt, stream::FuturesUnordered};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc::channel;
 
 #[non_exhaustive]
@@ -44,9 +46,10 @@ pub async fn run_for_test_target(
         if !tests_filter.should_be_run(&case) {
             tasks.push(tokio::task::spawn(async {
                 // TODO TestCaseType should also be encoded in the test case definition
-                Ok(AnyTestCaseSummary::Single(TestCaseSummary::Ignored {
-                    name: case_name,
-                }))
+                Ok((
+                    AnyTestCaseSummary::Single(TestCaseSummary::Ignored { name: case_name }),
+                    Duration::ZERO,
+                ))
             }));
             continue;
         }
@@ -65,15 +68,17 @@ pub async fn run_for_test_target(
     }
 
     let mut results = vec![];
+    let mut durations = HashMap::new();
     let mut saved_trace_data_paths = vec![];
     let mut interrupted = false;
 
     while let Some(task) = tasks.next().await {
-        let result = task??;
+        let (result, duration) = task??;
 
         if !result.is_interrupted() {
             let test_result_message = TestResultMessage::new(
                 &result,
+                duration,
                 forge_config.output_config.detailed_resources,
                 forge_config.test_runner_config.tracked_resource,
             );
@@ -88,11 +93,16 @@ pub async fn run_for_test_target(
             saved_trace_data_paths.push(path);
         }
 
-        if result.is_failed() && forge_config.test_runner_config.exit_first {
+        if (result.is_failed() || result.is_timed_out())
+            && forge_config.test_runner_config.exit_first
+        {
             interrupted = true;
             rec.close();
         }
 
+        if let Some(name) = result.name() {
+            durations.insert(name.to_string(), duration);
+        }
         results.push(result);
     }
 
@@ -104,6 +114,7 @@ pub async fn run_for_test_target(
 
     let summary = TestTargetSummary {
         test_case_summaries: results,
+        test_case_durations: durations,
     };
 
     if interrupted {
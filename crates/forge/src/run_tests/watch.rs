@@ -0,0 +1,104 @@
+use super::workspace::run_for_workspace;
+use crate::{ExitStatus, TestArgs};
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use foundry_ui::{UI, components::error::ErrorMessage};
+use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use walkdir::{DirEntry, WalkDir};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Runs tests of the workspace every time a source file of one of its packages changes.
+/// Never returns unless an error prevents watching the files.
+pub async fn watch_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus> {
+    // Running tests changes the current directory to the root of the tested package
+    let initial_dir = env::current_dir()?;
+    let mut watched_dirs = vec![];
+
+    loop {
+        env::set_current_dir(&initial_dir)?;
+        match package_roots(&args) {
+            Ok(package_roots) => watched_dirs = package_roots,
+            // A broken manifest is reported by the test run, keep watching the previous directories
+            Err(_) if !watched_dirs.is_empty() => {}
+            Err(error) => return Err(error),
+        }
+
+        // Errors, e.g. compilation failures, are expected to be fixed by the next change
+        if let Err(error) = run_for_workspace(&args, ui.clone()).await {
+            ui.println(&ErrorMessage::from(error));
+        }
+
+        ui.print_blank_line();
+        ui.println(&"Watching for changes...");
+        wait_for_changes(&watched_dirs).await;
+        ui.print_blank_line();
+    }
+}
+
+/// Root directories of all packages of the workspace.
+fn package_roots(args: &TestArgs) -> Result<Vec<Utf8PathBuf>> {
+    let mut metadata_command = ScarbCommand::metadata();
+    if let Some(profile) = &args.scarb_args.profile.specified() {
+        metadata_command.profile(profile.clone());
+    }
+    let scarb_metadata = metadata_command
+        .inherit_stderr()
+        .run()
+        .context("Failed to read metadata of the watched packages")?;
+
+    Ok(scarb_metadata
+        .packages
+        .into_iter()
+        .filter(|package| scarb_metadata.workspace.members.contains(&package.id))
+        .map(|package| package.root)
+        .collect())
+}
+
+async fn wait_for_changes(dirs: &[Utf8PathBuf]) {
+    let initial = sources_snapshot(dirs);
+
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        if sources_snapshot(dirs) != initial {
+            return;
+        }
+    }
+}
+
+/// Modification times of Cairo sources and manifests in the given directories.
+fn sources_snapshot(dirs: &[Utf8PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    dirs.iter()
+        .flat_map(|dir| {
+            WalkDir::new(dir)
+                .into_iter()
+                .filter_entry(|entry| entry.depth() == 0 || !is_ignored(entry))
+                .filter_map(Result::ok)
+        })
+        .filter(is_source)
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((entry.into_path(), modified))
+        })
+        .collect()
+}
+
+/// Build outputs, caches and VCS directories
+fn is_ignored(entry: &DirEntry) -> bool {
+    entry.file_type().is_dir()
+        && entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name == "target" || name.starts_with('.'))
+}
+
+fn is_source(entry: &DirEntry) -> bool {
+    entry.file_type().is_file()
+        && (entry.file_name() == "Scarb.toml"
+            || entry.path().extension().is_some_and(|ext| ext == "cairo"))
+}
@@ -1,13 +1,22 @@
-use super::package::RunForPackageArgs;
+use super::package::{PreparedPackage, RunForPackageArgs, partition_packages};
 use super::structs::{LatestBlocksNumbersMessage, TestsFailureSummaryMessage};
+use crate::gas_report::GasReportMessage;
+use crate::gas_snapshot::{
+    GAS_SNAPSHOT_FILE, GasSnapshot, GasSnapshotCheckMessage, load_gas_snapshot,
+};
+use crate::report::{PackageReport, save_reports};
 use crate::run_tests::structs::OverallSummaryMessage;
 use crate::warn::{error_if_snforge_std_not_compatible, warn_if_backtrace_without_panic_hint};
 use crate::{
-    ColorOption, ExitStatus, TestArgs, block_number_map::BlockNumberMap,
-    run_tests::package::run_for_package, scarb::build_artifacts_with_scarb,
-    shared_cache::FailedTestsCache, warn::warn_if_snforge_std_not_compatible,
+    ColorOption, ExitStatus, TestArgs,
+    block_number_map::BlockNumberMap,
+    run_tests::package::{prepare_package, run_for_package},
+    scarb::build_artifacts_with_scarb,
+    shared_cache::{FailedTestsCache, FuzzerCorpusCache},
+    warn::warn_if_snforge_std_not_compatible,
 };
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use forge_runner::{CACHE_DIR, test_target_summary::TestTargetSummary};
 use forge_runner::{
     coverage_api::can_coverage_be_generated, test_case_summary::AnyTestCaseSummary,
@@ -20,10 +29,11 @@ use scarb_api::{
 };
 use scarb_ui::args::PackagesFilter;
 use shared::consts::SNFORGE_TEST_FILTER;
+use std::collections::HashSet;
 use std::env;
 use std::sync::Arc;
 
-pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus> {
+pub async fn run_for_workspace(args: &TestArgs, ui: Arc<UI>) -> Result<ExitStatus> {
     match args.color {
         // SAFETY: This runs in a single-threaded environment.
         ColorOption::Always => unsafe { env::set_var("CLICOLOR_FORCE", "1") },
@@ -37,6 +47,10 @@ pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus
         metadata_command.profile(profile.clone());
     }
     let scarb_metadata = metadata_command.inherit_stderr().run()?;
+    // Working directory is changed to the root of every tested package,
+    // so relative paths passed by the user are resolved against the original one
+    let current_dir = Utf8PathBuf::try_from(env::current_dir()?)
+        .context("Current directory path is not valid UTF-8")?;
 
     if args.coverage {
         can_coverage_be_generated(&scarb_metadata)?;
@@ -72,46 +86,125 @@ pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus
         args.scarb_args.profile.clone(),
         &scarb_metadata.app_version_info.version,
         args.no_optimization,
+        ui.output_format(),
     )?;
 
     let mut block_number_map = BlockNumberMap::default();
-    let mut all_tests = vec![];
+    let mut package_summaries: Vec<Vec<TestTargetSummary>> =
+        packages.iter().map(|_| vec![]).collect();
     let mut total_filtered_count = Some(0);
 
     let workspace_root = &scarb_metadata.workspace.root;
     let cache_dir = workspace_root.join(CACHE_DIR);
+    let gas_snapshot_path = workspace_root.join(GAS_SNAPSHOT_FILE);
+    // Fail before running tests if there is nothing to compare with
+    let gas_snapshot = if args.gas_snapshot_check {
+        Some(load_gas_snapshot(&gas_snapshot_path)?)
+    } else {
+        None
+    };
     let trace_verbosity = args.trace_verbosity;
     let packages_len = packages.len();
 
-    for package in packages {
+    let mut prepared_packages = vec![];
+    for package in &packages {
         env::set_current_dir(&package.root)?;
 
         let args = RunForPackageArgs::build(
-            package,
+            package.clone(),
             &scarb_metadata,
-            &args,
+            args,
             &cache_dir,
             &artifacts_dir_path,
             &ui,
         )?;
+        prepared_packages.push(prepare_package(args, &mut block_number_map, ui.clone()).await?);
+    }
+
+    let all_test_names: HashSet<String> = prepared_packages
+        .iter()
+        .flat_map(PreparedPackage::all_test_names)
+        .cloned()
+        .collect();
+    partition_packages(&mut prepared_packages);
+
+    // In watch mode tests that failed during the last run are run before all the other tests.
+    // Packages are prepared only once and split between the stages.
+    let failed_tests = if args.watch && !args.rerun_failed {
+        FailedTestsCache::new(&cache_dir).load()?
+    } else {
+        vec![]
+    };
+    let stages = if failed_tests.is_empty() {
+        vec![prepared_packages]
+    } else {
+        let failed_packages = prepared_packages
+            .iter_mut()
+            .map(|package| package.split_off(|name| failed_tests.iter().any(|t| t == name)))
+            .collect();
+        vec![failed_packages, prepared_packages]
+    };
+    let is_staged = stages.len() > 1;
+
+    for (stage, prepared_packages) in stages.into_iter().enumerate() {
+        if is_staged {
+            if stage == 0 {
+                ui.println(&"Running tests that failed during the last run");
+            } else {
+                ui.print_blank_line();
+                ui.println(&"Running remaining tests");
+            }
+        }
+
+        for ((package, summaries), prepared_package) in packages
+            .iter()
+            .zip(&mut package_summaries)
+            .zip(prepared_packages)
+        {
+            env::set_current_dir(&package.root)?;
 
-        let result =
-            run_for_package(args, &mut block_number_map, trace_verbosity, ui.clone()).await?;
+            let result = run_for_package(prepared_package, trace_verbosity, ui.clone()).await?;
 
-        let filtered = result.filtered();
-        all_tests.extend(result.summaries());
+            // Tests run in one stage of a watch run are filtered out in the other one,
+            // so the total filtered count would be misleading
+            let filtered = result.filtered().filter(|_| !is_staged);
+            summaries.extend(result.summaries());
 
-        // Accumulate filtered test counts across packages. When using --exact flag,
-        // result.filtered_count is None, so total_filtered_count becomes None too.
-        total_filtered_count = total_filtered_count
-            .zip(filtered)
-            .map(|(total, filtered)| total + filtered);
+            // Accumulate filtered test counts across packages. When using --exact flag,
+            // result.filtered_count is None, so total_filtered_count becomes None too.
+            total_filtered_count = total_filtered_count
+                .zip(filtered)
+                .map(|(total, filtered)| total + filtered);
+        }
     }
 
+    let package_reports = if args.report.is_empty() {
+        vec![]
+    } else {
+        packages
+            .iter()
+            .zip(&package_summaries)
+            .map(|(package, summaries)| PackageReport::new(&package.name, summaries))
+            .collect()
+    };
+    let all_tests = package_summaries.into_iter().flatten().collect::<Vec<_>>();
+
+    if args.gas_snapshot {
+        let mut gas_snapshot = GasSnapshot::load(&gas_snapshot_path)?.unwrap_or_default();
+        gas_snapshot.update(&all_tests, &all_test_names);
+        gas_snapshot.save(&gas_snapshot_path)?;
+    }
+    let gas_snapshot_check = gas_snapshot
+        .map(|gas_snapshot| gas_snapshot.check(&all_tests, args.gas_snapshot_tolerance));
+
+    let gas_report = args.gas_report.then(|| GasReportMessage::new(&all_tests));
+
     let overall_summary = OverallSummaryMessage::new(&all_tests, total_filtered_count);
     let all_failed_tests: Vec<AnyTestCaseSummary> = extract_failed_tests(all_tests).collect();
 
     FailedTestsCache::new(&cache_dir).save_failed_tests(&all_failed_tests)?;
+    FuzzerCorpusCache::new(&cache_dir).save_failing_inputs(&all_failed_tests)?;
+    save_reports(&args.report, &package_reports, &current_dir)?;
 
     if !block_number_map.get_url_to_latest_block_number().is_empty() {
         ui.println(&LatestBlocksNumbersMessage::new(
@@ -121,6 +214,16 @@ pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus
 
     ui.println(&TestsFailureSummaryMessage::new(&all_failed_tests));
 
+    if let Some(gas_report) = &gas_report {
+        ui.print_blank_line();
+        ui.println(gas_report);
+    }
+
+    if let Some(gas_snapshot_check) = &gas_snapshot_check {
+        ui.print_blank_line();
+        ui.println(gas_snapshot_check);
+    }
+
     // Print the overall summary only when testing multiple packages
     if packages_len > 1 {
         // Add newline to separate summary from previous output
@@ -132,7 +235,11 @@ pub async fn run_for_workspace(args: TestArgs, ui: Arc<UI>) -> Result<ExitStatus
         unset_forge_test_filter();
     }
 
-    Ok(if all_failed_tests.is_empty() {
+    let gas_regressed = gas_snapshot_check
+        .as_ref()
+        .is_some_and(GasSnapshotCheckMessage::has_regressions);
+
+    Ok(if all_failed_tests.is_empty() && !gas_regressed {
         ExitStatus::Success
     } else {
         ExitStatus::Failure
@@ -145,7 +252,7 @@ fn extract_failed_tests(
     tests_summaries
         .into_iter()
         .flat_map(|summary| summary.test_case_summaries)
-        .filter(AnyTestCaseSummary::is_failed)
+        .filter(|summary| summary.is_failed() || summary.is_timed_out())
 }
 
 fn set_forge_test_filter(test_filter: String) {
@@ -5,6 +5,7 @@ use camino::Utf8Path;
 use configuration::PackageConfig;
 use forge_runner::package_tests::TestTargetLocation;
 use forge_runner::package_tests::raw::TestTargetRaw;
+use foundry_ui::OutputFormat;
 use scarb_api::{ScarbCommand, test_targets_by_name};
 use scarb_metadata::PackageMetadata;
 use scarb_ui::args::{FeaturesSpec, PackagesFilter, ProfileSpec};
@@ -43,20 +44,33 @@ pub fn build_artifacts_with_scarb(
     profile: ProfileSpec,
     scarb_version: &Version,
     no_optimization: bool,
+    output_format: OutputFormat,
 ) -> Result<()> {
+    let mut scarb_command = ScarbCommand::new_with_stdio();
+    // Scarb output is printed together with the `snforge` output, so it has to be in the same format
+    if output_format == OutputFormat::Json {
+        scarb_command.json();
+    }
+
     if should_compile_starknet_contract_target(scarb_version, no_optimization) {
-        build_contracts_with_scarb(filter.clone(), features.clone(), profile.clone())?;
+        build_contracts_with_scarb(
+            scarb_command.clone(),
+            filter.clone(),
+            features.clone(),
+            profile.clone(),
+        )?;
     }
-    build_test_artifacts_with_scarb(filter, features, profile)?;
+    build_test_artifacts_with_scarb(scarb_command, filter, features, profile)?;
     Ok(())
 }
 
 fn build_contracts_with_scarb(
+    mut scarb_command: ScarbCommand,
     filter: PackagesFilter,
     features: FeaturesSpec,
     profile: ProfileSpec,
 ) -> Result<()> {
-    ScarbCommand::new_with_stdio()
+    scarb_command
         .arg("build")
         .packages_filter(filter)
         .features(features)
@@ -67,11 +81,12 @@ fn build_contracts_with_scarb(
 }
 
 fn build_test_artifacts_with_scarb(
+    mut scarb_command: ScarbCommand,
     filter: PackagesFilter,
     features: FeaturesSpec,
     profile: ProfileSpec,
 ) -> Result<()> {
-    ScarbCommand::new_with_stdio()
+    scarb_command
         .arg("build")
         .arg("--test")
         .packages_filter(filter)
@@ -134,7 +149,7 @@ mod tests {
     use camino::Utf8PathBuf;
     use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
     use configuration::load_package_config;
-    use forge_runner::forge_config::ForgeTrackedResource;
+    use forge_runner::forge_config::{ForgeTrackedResource, ForkMode};
     use indoc::{formatdoc, indoc};
     use scarb_api::metadata::MetadataCommandExt;
     use scarb_metadata::PackageId;
@@ -241,7 +256,9 @@ mod tests {
                 fuzzer_runs: None,
                 fuzzer_seed: None,
                 max_n_steps: None,
+                test_timeout: None,
                 tracked_resource: ForgeTrackedResource::CairoSteps,
+                fork_mode: ForkMode::Online,
                 detailed_resources: false,
                 save_trace_data: false,
                 build_profile: false,
@@ -510,7 +527,9 @@ mod tests {
                 fuzzer_runs: None,
                 fuzzer_seed: None,
                 max_n_steps: None,
+                test_timeout: None,
                 tracked_resource: ForgeTrackedResource::CairoSteps,
+                fork_mode: ForkMode::Online,
                 detailed_resources: false,
                 save_trace_data: false,
                 build_profile: false,
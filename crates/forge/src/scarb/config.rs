@@ -1,6 +1,6 @@
 use anyhow::Result;
 use cheatnet::runtime_extensions::forge_config_extension::config::BlockId;
-use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::forge_config::{ForgeTrackedResource, ForkMode};
 use serde::{Deserialize, Deserializer};
 use std::{collections::HashSet, num::NonZeroU32};
 use url::Url;
@@ -12,6 +12,7 @@ pub const SCARB_MANIFEST_TEMPLATE_CONTENT: &str = r#"
 # exit_first = true                                          # Stop tests execution immediately upon the first failure
 # fuzzer_runs = 1234                                         # Number of runs of the random fuzzer
 # fuzzer_seed = 1111                                         # Seed for the random fuzzer
+# fork_mode = "offline"                                      # Run fork tests using only the fork cache
 
 # [[tool.snforge.fork]]                                      # Used for fork testing
 # name = "SOME_NAME"                                         # Fork name
@@ -64,9 +65,14 @@ pub struct ForgeConfigFromScarb {
     pub fork: Vec<ForkTarget>,
     /// Limit of steps
     pub max_n_steps: Option<u32>,
+    /// Time in seconds after which a test is stopped and reported as timed out
+    pub test_timeout: Option<u64>,
     /// Set tracked resource
     #[serde(default)]
     pub tracked_resource: ForgeTrackedResource,
+    /// Whether fork state can be fetched from the node, or is read only from the cache
+    #[serde(default)]
+    pub fork_mode: ForkMode,
 }
 
 #[derive(Debug, PartialEq, Clone, Deserialize)]
@@ -1,6 +1,8 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
-use forge_runner::test_case_summary::AnyTestCaseSummary;
+use forge_runner::forge_config::FuzzerCorpus;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use fs2::FileExt;
 use std::fs::File;
 use std::io::{BufRead, BufReader, BufWriter, ErrorKind, Write};
 
@@ -47,3 +49,83 @@ impl FailedTestsCache {
         Ok(())
     }
 }
+
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct FuzzerCorpusCache {
+    cache_file: Utf8PathBuf,
+}
+
+const FILE_WITH_FUZZER_CORPUS: &str = ".fuzzer_corpus.json";
+
+/// Maximal number of failing inputs saved in the corpus for a single test
+pub const MAX_INPUTS_PER_TEST: usize = 16;
+
+impl FuzzerCorpusCache {
+    #[must_use]
+    pub fn new(cache_dir: &Utf8PathBuf) -> Self {
+        Self {
+            cache_file: cache_dir.join(FILE_WITH_FUZZER_CORPUS),
+        }
+    }
+
+    pub fn load(&self) -> Result<FuzzerCorpus> {
+        let file = match File::open(&self.cache_file) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(FuzzerCorpus::default()),
+            Err(err) => Err(err)?,
+        };
+
+        serde_json::from_reader(BufReader::new(file)).with_context(|| {
+            format!(
+                "Failed to read fuzzer corpus from {}, consider removing this file",
+                self.cache_file
+            )
+        })
+    }
+
+    /// Adds fuzzer draws of failed fuzz tests to the corpus, keeping inputs saved previously.
+    /// Only the [`MAX_INPUTS_PER_TEST`] most recent distinct inputs of every test are kept.
+    ///
+    /// The corpus is updated under an exclusive lock and replaced atomically,
+    /// so concurrent runs don't lose each other's inputs or leave a partially written file.
+    pub fn save_failing_inputs(&self, all_failed_tests: &[AnyTestCaseSummary]) -> Result<()> {
+        let failing_inputs: Vec<_> = all_failed_tests
+            .iter()
+            .filter_map(|test| match test {
+                AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+                    name, fuzzer_draws, ..
+                }) if !fuzzer_draws.is_empty() => Some((name, fuzzer_draws)),
+                _ => None,
+            })
+            .collect();
+        if failing_inputs.is_empty() {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(self.cache_file.parent().unwrap())?;
+        let lock_file = File::create(self.cache_file.with_extension("json.lock"))?;
+        lock_file
+            .lock_exclusive()
+            .context("Could not lock on fuzzer corpus")?;
+
+        let mut corpus = self.load()?;
+        for (name, fuzzer_draws) in failing_inputs {
+            let inputs = corpus.entry(name.clone()).or_default();
+            inputs.retain(|input| input != fuzzer_draws);
+            inputs.push(fuzzer_draws.clone());
+            if inputs.len() > MAX_INPUTS_PER_TEST {
+                inputs.drain(..inputs.len() - MAX_INPUTS_PER_TEST);
+            }
+        }
+
+        let temp_file = self.cache_file.with_extension("json.tmp");
+        let mut writer = BufWriter::new(File::create(&temp_file)?);
+        serde_json::to_writer(&mut writer, &corpus)?;
+        writer.flush()?;
+        std::fs::rename(&temp_file, &self.cache_file)?;
+
+        fs2::FileExt::unlock(&lock_file).context("Could not unlock fuzzer corpus")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Write;
+
+/// Formats rows as a table with columns separated by `|` and aligned to the widest cell.
+pub(crate) fn format_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let header = header.map(String::from);
+    let widths: Vec<usize> = (0..N)
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].len())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut table = String::new();
+    for row in [&header].into_iter().chain(rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
@@ -1,7 +1,10 @@
 use crate::shared_cache::FailedTestsCache;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use forge_runner::TestCaseFilter;
 use forge_runner::package_tests::with_config_resolved::TestCaseWithResolvedConfig;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Debug, PartialEq)]
 // Specifies what tests should be included
@@ -14,6 +17,8 @@ pub struct TestsFilter {
     last_failed_filter: bool,
     // based on `--skip` flag
     skip_filter: Vec<String>,
+    // based on `--partition` flag
+    partition: Option<Partition>,
 
     failed_tests_cache: FailedTestsCache,
 }
@@ -42,6 +47,7 @@ impl TestsFilter {
         only_ignored: bool,
         include_ignored: bool,
         rerun_failed: bool,
+        partition: Option<Partition>,
         failed_tests_cache: FailedTestsCache,
     ) -> Self {
         assert!(
@@ -73,10 +79,16 @@ impl TestsFilter {
             ignored_filter,
             last_failed_filter: rerun_failed,
             skip_filter: skip,
+            partition,
             failed_tests_cache,
         }
     }
 
+    #[must_use]
+    pub fn partition(&self) -> Option<Partition> {
+        self.partition
+    }
+
     pub(crate) fn filter_tests(
         &self,
         test_cases: &mut Vec<TestCaseWithResolvedConfig>,
@@ -115,6 +127,128 @@ impl TestsFilter {
 
         Ok(())
     }
+
+    /// Keeps only the tests of the partition selected with `--partition`.
+    /// Must be applied after [`TestsFilter::filter_tests`], so that every partition gets a similar share
+    /// of the selected tests. Tests of all packages are numbered together, sorted by package and test name,
+    /// so the partitions don't depend on the order in which packages and targets were collected.
+    pub(crate) fn partition_tests<'a>(
+        &self,
+        test_targets: impl IntoIterator<Item = (&'a str, &'a mut Vec<TestCaseWithResolvedConfig>)>,
+    ) {
+        let Some(partition) = self.partition else {
+            return;
+        };
+
+        let mut test_targets: Vec<_> = test_targets.into_iter().collect();
+        let mut tests: Vec<(&str, String)> = test_targets
+            .iter()
+            .flat_map(|(package_name, test_cases)| {
+                test_cases
+                    .iter()
+                    .map(|test_case| (*package_name, test_case.name.clone()))
+            })
+            .collect();
+        tests.sort();
+
+        let included: HashSet<(&str, String)> = tests
+            .into_iter()
+            .enumerate()
+            .filter(|(position, (_, name))| partition.includes(name, *position))
+            .map(|(_, test)| test)
+            .collect();
+
+        for (package_name, test_cases) in &mut test_targets {
+            test_cases
+                .retain(|test_case| included.contains(&(*package_name, test_case.name.clone())));
+        }
+    }
+}
+
+/// Deterministic subset of tests selected with `--partition`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Partition {
+    strategy: PartitionStrategy,
+    /// 1-based index of the partition
+    index: usize,
+    total: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionStrategy {
+    /// Tests are assigned to partitions in a round-robin fashion, in order of package and test name
+    Count,
+    /// Tests are assigned to partitions based on the hash of their name,
+    /// so the assignment does not change when other tests are added or removed
+    Hash,
+}
+
+impl Partition {
+    fn includes(&self, test_name: &str, position: usize) -> bool {
+        match self.strategy {
+            PartitionStrategy::Count => position % self.total == self.index - 1,
+            PartitionStrategy::Hash => {
+                fnv1a_hash(test_name) % self.total as u64 == (self.index - 1) as u64
+            }
+        }
+    }
+}
+
+/// Stable across platforms and compiler versions, unlike the `std` hashers
+fn fnv1a_hash(value: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    value.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+impl FromStr for Partition {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (strategy, shard) = match value.split_once(':') {
+            Some(("count", shard)) => (PartitionStrategy::Count, shard),
+            Some(("hash", shard)) => (PartitionStrategy::Hash, shard),
+            Some((strategy, _)) => {
+                return Err(anyhow!(
+                    "Unsupported partition strategy: {strategy}, supported strategies are: count, hash"
+                ));
+            }
+            None => (PartitionStrategy::Count, value),
+        };
+
+        let (index, total) = shard
+            .split_once('/')
+            .and_then(|(index, total)| Some((index.parse().ok()?, total.parse().ok()?)))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Partition must be specified as [count:|hash:]<INDEX>/<TOTAL>, got: {value}"
+                )
+            })?;
+        if total == 0 || index == 0 || index > total {
+            return Err(anyhow!(
+                "Partition index must be between 1 and the total number of partitions, got: {value}"
+            ));
+        }
+
+        Ok(Self {
+            strategy,
+            index,
+            total,
+        })
+    }
+}
+
+impl Display for Partition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let strategy = match self.strategy {
+            PartitionStrategy::Count => "count",
+            PartitionStrategy::Hash => "hash",
+        };
+        write!(f, "{}/{} ({strategy})", self.index, self.total)
+    }
 }
 
 impl TestCaseFilter for TestsFilter {
@@ -132,7 +266,7 @@ impl TestCaseFilter for TestsFilter {
 #[cfg(test)]
 mod tests {
     use crate::shared_cache::FailedTestsCache;
-    use crate::test_filter::TestsFilter;
+    use crate::test_filter::{Partition, TestsFilter};
     use cairo_lang_sierra::program::Program;
     use cairo_lang_sierra::program::ProgramArtifact;
     use forge_runner::expected_result::ExpectedTestResult;
@@ -140,6 +274,7 @@ mod tests {
         TestCaseResolvedConfig, TestCaseWithResolvedConfig, TestTargetWithResolvedConfig,
     };
     use forge_runner::package_tests::{TestDetails, TestTargetLocation};
+    use std::str::FromStr;
     use std::sync::Arc;
     use universal_sierra_compiler_api::{SierraType, compile_sierra};
 
@@ -165,6 +300,7 @@ mod tests {
             true,
             true,
             false,
+            None,
             FailedTestsCache::default(),
         );
     }
@@ -179,6 +315,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
     }
@@ -207,7 +344,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -220,7 +359,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -233,7 +374,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -246,7 +389,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ],
@@ -260,6 +405,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -279,7 +425,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
             },]
         );
@@ -291,6 +439,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -309,7 +458,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
             },]
         );
@@ -321,6 +472,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -340,7 +492,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -353,7 +507,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -366,7 +522,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -379,7 +537,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -392,6 +552,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -407,6 +568,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -426,7 +588,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -439,7 +603,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -452,7 +618,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -465,7 +633,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -495,6 +665,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -510,6 +681,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -543,7 +715,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -556,7 +730,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -569,7 +745,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -582,7 +760,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ],
@@ -596,6 +776,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -611,6 +792,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -626,6 +808,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -644,7 +827,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
             },]
         );
@@ -656,6 +841,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -674,7 +860,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
             },]
         );
@@ -686,6 +874,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -701,6 +890,7 @@ mod tests {
             false,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
 
@@ -719,7 +909,9 @@ mod tests {
                     expected_result: ExpectedTestResult::Success,
                     fork_config: None,
                     fuzzer_config: None,
+                    invariant_config: None,
                     disable_predeployed_contracts: false,
+                    timeout: None,
                 },
             },]
         );
@@ -749,7 +941,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -762,7 +956,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -775,7 +971,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -788,7 +986,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ],
@@ -802,6 +1002,7 @@ mod tests {
             true,
             false,
             false,
+            None,
             FailedTestsCache::default(),
         );
         let mut filtered = mocked_tests;
@@ -820,7 +1021,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -833,7 +1036,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ]
@@ -864,7 +1069,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -877,7 +1084,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -890,7 +1099,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -903,7 +1114,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ],
@@ -917,6 +1130,7 @@ mod tests {
             false,
             true,
             false,
+            None,
             FailedTestsCache::default(),
         );
         let mut filtered = mocked_tests;
@@ -935,7 +1149,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -948,7 +1164,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -961,7 +1179,9 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
                 TestCaseWithResolvedConfig {
@@ -974,10 +1194,138 @@ mod tests {
                         expected_result: ExpectedTestResult::Success,
                         fork_config: None,
                         fuzzer_config: None,
+                        invariant_config: None,
                         disable_predeployed_contracts: false,
+                        timeout: None,
                     },
                 },
             ]
         );
     }
+
+    fn test_case(name: &str) -> TestCaseWithResolvedConfig {
+        TestCaseWithResolvedConfig {
+            name: name.to_string(),
+            test_details: TestDetails::default(),
+
+            config: TestCaseResolvedConfig {
+                available_gas: None,
+                ignored: false,
+                expected_result: ExpectedTestResult::Success,
+                fork_config: None,
+                fuzzer_config: None,
+                invariant_config: None,
+                disable_predeployed_contracts: false,
+                timeout: None,
+            },
+        }
+    }
+
+    #[test]
+    fn parsing_partition() {
+        assert_eq!(
+            Partition::from_str("2/3").unwrap(),
+            Partition::from_str("count:2/3").unwrap()
+        );
+        assert_eq!(
+            Partition::from_str("hash:1/2").unwrap().to_string(),
+            "1/2 (hash)"
+        );
+
+        assert!(Partition::from_str("0/3").is_err());
+        assert!(Partition::from_str("4/3").is_err());
+        assert!(Partition::from_str("1/0").is_err());
+        assert!(Partition::from_str("1").is_err());
+        assert!(Partition::from_str("size:1/3").is_err());
+    }
+
+    #[test]
+    fn filtering_with_partition() {
+        let test_cases = (0..10)
+            .map(|i| test_case(&format!("crate::tests::test_{i}")))
+            .collect::<Vec<_>>();
+
+        for strategy in ["count", "hash"] {
+            let mut partitioned = vec![];
+            for index in 1..=3 {
+                let tests_filter = TestsFilter::from_flags(
+                    None,
+                    false,
+                    Vec::new(),
+                    false,
+                    false,
+                    false,
+                    Some(Partition::from_str(&format!("{strategy}:{index}/3")).unwrap()),
+                    FailedTestsCache::default(),
+                );
+
+                let mut filtered = test_cases.clone();
+                tests_filter.filter_tests(&mut filtered).unwrap();
+                tests_filter.partition_tests([("crate", &mut filtered)]);
+                partitioned.extend(filtered);
+            }
+
+            // Every test is run by exactly one partition
+            partitioned.sort_by(|a, b| a.name.cmp(&b.name));
+            assert_eq!(partitioned, test_cases);
+        }
+
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            false,
+            false,
+            false,
+            Some(Partition::from_str("2/3").unwrap()),
+            FailedTestsCache::default(),
+        );
+        let mut filtered = test_cases.clone();
+        tests_filter.filter_tests(&mut filtered).unwrap();
+        tests_filter.partition_tests([("crate", &mut filtered)]);
+
+        assert_eq!(
+            filtered,
+            vec![
+                test_case("crate::tests::test_1"),
+                test_case("crate::tests::test_4"),
+                test_case("crate::tests::test_7"),
+            ]
+        );
+    }
+
+    #[test]
+    fn partition_spans_packages() {
+        let tests_filter = TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            false,
+            false,
+            false,
+            Some(Partition::from_str("2/2").unwrap()),
+            FailedTestsCache::default(),
+        );
+
+        let mut second_package_tests = vec![
+            test_case("second::tests::test_0"),
+            test_case("second::tests::test_1"),
+        ];
+        let mut integration_tests = vec![test_case("tests::test_1"), test_case("tests::test_2")];
+        let mut unit_tests = vec![test_case("first::tests::test_0")];
+        // Tests are numbered after sorting by package and test name,
+        // regardless of the order of packages and targets
+        tests_filter.partition_tests([
+            ("second", &mut second_package_tests),
+            ("first", &mut integration_tests),
+            ("first", &mut unit_tests),
+        ]);
+
+        assert!(unit_tests.is_empty());
+        assert_eq!(integration_tests, vec![test_case("tests::test_1")]);
+        assert_eq!(
+            second_package_tests,
+            vec![test_case("second::tests::test_0")]
+        );
+    }
 }
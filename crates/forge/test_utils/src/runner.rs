@@ -259,6 +259,16 @@ pub fn assert_failed(result: &[TestTargetSummary]) {
     );
 }
 
+pub fn assert_timed_out(result: &[TestTargetSummary]) {
+    let result = &TestCase::find_test_result(result).test_case_summaries;
+
+    assert!(!result.is_empty(), "No test results found");
+    assert!(
+        result.iter().all(AnyTestCaseSummary::is_timed_out),
+        "Some tests didn't time out"
+    );
+}
+
 pub fn assert_case_output_contains(
     result: &[TestTargetSummary],
     test_case_name: &str,
@@ -269,7 +279,7 @@ pub fn assert_case_output_contains(
     let result = TestCase::find_test_result(result);
 
     assert!(result.test_case_summaries.iter().any(|any_case| {
-        if any_case.is_passed() || any_case.is_failed() {
+        if any_case.is_passed() || any_case.is_failed() || any_case.is_timed_out() {
             return any_case.msg().unwrap().contains(asserted_msg)
                 && any_case
                     .name()
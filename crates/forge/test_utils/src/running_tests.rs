@@ -4,13 +4,14 @@ use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::Contr
 use forge::shared_cache::FailedTestsCache;
 use forge::{
     block_number_map::BlockNumberMap,
-    run_tests::package::{RunForPackageArgs, run_for_package},
+    run_tests::package::{RunForPackageArgs, prepare_package, run_for_package},
     scarb::load_test_artifacts,
     test_filter::TestsFilter,
 };
 use forge_runner::CACHE_DIR;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, OutputConfig, TestRunnerConfig,
+    ExecutionDataToSave, ForgeConfig, ForgeTrackedResource, ForkMode, FuzzerCorpus, FuzzerStrategy,
+    OutputConfig, TestRunnerConfig,
 };
 use forge_runner::test_target_summary::TestTargetSummary;
 use foundry_ui::UI;
@@ -48,44 +49,52 @@ pub fn run_test_case(
         load_test_artifacts(&test.path().unwrap().join("target/dev"), package).unwrap();
 
     let ui = Arc::new(UI::default());
-    rt.block_on(run_for_package(
-        RunForPackageArgs {
-            test_targets: raw_test_targets,
-            package_name: "test_package".to_string(),
-            tests_filter: TestsFilter::from_flags(
-                None,
-                false,
-                Vec::new(),
-                false,
-                false,
-                false,
-                FailedTestsCache::default(),
-            ),
-            forge_config: Arc::new(ForgeConfig {
-                test_runner_config: Arc::new(TestRunnerConfig {
-                    exit_first: false,
-                    fuzzer_runs: NonZeroU32::new(256).unwrap(),
-                    fuzzer_seed: 12345,
-                    max_n_steps: None,
-                    is_vm_trace_needed: false,
-                    cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
-                        .unwrap()
-                        .join(CACHE_DIR),
-                    contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap()).unwrap(),
-                    tracked_resource,
-                    environment_variables: test.env().clone(),
-                }),
-                output_config: Arc::new(OutputConfig {
-                    detailed_resources: false,
-                    execution_data_to_save: ExecutionDataToSave::default(),
-                }),
+    let args = RunForPackageArgs {
+        test_targets: raw_test_targets,
+        package_name: "test_package".to_string(),
+        tests_filter: TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            false,
+            false,
+            false,
+            None,
+            FailedTestsCache::default(),
+        ),
+        forge_config: Arc::new(ForgeConfig {
+            test_runner_config: Arc::new(TestRunnerConfig {
+                exit_first: false,
+                fuzzer_runs: NonZeroU32::new(256).unwrap(),
+                fuzzer_seed: 12345,
+                fuzzer_corpus: FuzzerCorpus::default(),
+                fuzzer_strategy: FuzzerStrategy::Random,
+                max_n_steps: None,
+                test_timeout: None,
+                is_vm_trace_needed: false,
+                gas_report: false,
+                cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
+                    .unwrap()
+                    .join(CACHE_DIR),
+                fork_mode: ForkMode::Online,
+                contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap()).unwrap(),
+                tracked_resource,
+                environment_variables: test.env().clone(),
             }),
-            fork_targets: vec![],
-        },
-        &mut BlockNumberMap::default(),
-        Option::default(),
-        ui,
-    ))
-    .expect("Runner fail")
-    .summaries()
+            output_config: Arc::new(OutputConfig {
+                detailed_resources: false,
+                execution_data_to_save: ExecutionDataToSave::default(),
+            }),
+        }),
+        fork_targets: vec![],
+    };
+    let result = rt.block_on(async {
+        let prepared_package =
+            prepare_package(args, &mut BlockNumberMap::default(), ui.clone()).await?;
+        run_for_package(prepared_package, Option::default(), ui).await
+    });
+    // Tests which timed out may still be blocked, e.g. on a fork RPC request
+    rt.shutdown_background();
+
+    result.expect("Runner fail").summaries()
 }
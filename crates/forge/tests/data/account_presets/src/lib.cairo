@@ -0,0 +1,77 @@
+#[starknet::interface]
+pub trait IHelloStarknet<TContractState> {
+    fn increase_balance(ref self: TContractState, amount: felt252);
+    fn get_balance(self: @TContractState) -> felt252;
+}
+
+#[starknet::contract]
+pub mod HelloStarknet {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        balance: felt252,
+    }
+
+    #[abi(embed_v0)]
+    impl IHelloStarknetImpl of super::IHelloStarknet<ContractState> {
+        fn increase_balance(ref self: ContractState, amount: felt252) {
+            self.balance.write(self.balance.read() + amount);
+        }
+
+        fn get_balance(self: @ContractState) -> felt252 {
+            self.balance.read()
+        }
+    }
+}
+
+/// Account accepting only transactions with a single call, signed with the stark curve key.
+#[starknet::contract(account)]
+pub mod SingleCallAccount {
+    use core::ecdsa::check_ecdsa_signature;
+    use starknet::account::Call;
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+    use starknet::syscalls::call_contract_syscall;
+    use starknet::{SyscallResultTrait, VALIDATED, get_tx_info};
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[abi(per_item)]
+    #[generate_trait]
+    impl AccountImpl of AccountTrait {
+        #[external(v0)]
+        fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+            assert(calls.len() == 1, 'Account: single call only');
+
+            let tx_info = get_tx_info().unbox();
+            let signature = tx_info.signature;
+            assert(signature.len() == 2, 'Account: invalid signature');
+            assert(
+                check_ecdsa_signature(
+                    tx_info.transaction_hash, self.public_key.read(), *signature[0], *signature[1],
+                ),
+                'Account: invalid signature',
+            );
+
+            VALIDATED
+        }
+
+        #[external(v0)]
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let mut results = array![];
+            for call in calls {
+                let Call { to, selector, calldata } = call;
+                results.append(call_contract_syscall(to, selector, calldata).unwrap_syscall());
+            };
+            results
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use account_presets::{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait};
+use snforge_std::{ContractClassTrait, DeclareResultTrait, InvokeOptions, declare, invoke_as_account};
+use starknet::ContractAddress;
+use starknet::account::Call;
+
+fn deploy_account() -> ContractAddress {
+    let account_class = declare("SingleCallAccount").unwrap().contract_class();
+    let (account, _) = account_class.deploy(@array!['public key']).unwrap();
+    account
+}
+
+fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+    let contract = declare("HelloStarknet").unwrap().contract_class();
+    let (contract_address, _) = contract.deploy(@array![]).unwrap();
+    IHelloStarknetDispatcher { contract_address }
+}
+
+fn increase_balance_call(dispatcher: IHelloStarknetDispatcher, amount: felt252) -> Call {
+    Call {
+        to: dispatcher.contract_address,
+        selector: selector!("increase_balance"),
+        calldata: array![amount].span(),
+    }
+}
+
+#[test]
+fn skipped_signature_verification() {
+    let account = deploy_account();
+    let dispatcher = deploy_hello_starknet();
+
+    let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+    invoke_as_account(account, array![increase_balance_call(dispatcher, 100)], options).unwrap();
+
+    assert(dispatcher.get_balance() == 100, 'call not executed');
+}
+
+#[test]
+fn validation_failure_with_skipped_signature_verification() {
+    let account = deploy_account();
+    let dispatcher = deploy_hello_starknet();
+
+    let calls = array![
+        increase_balance_call(dispatcher, 100), increase_balance_call(dispatcher, 1),
+    ];
+    let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+    let panic_data = invoke_as_account(account, calls, options).unwrap_err();
+
+    assert(*panic_data.at(0) == 'Account: single call only', *panic_data.at(0));
+    assert(dispatcher.get_balance() == 0, 'call executed');
+}
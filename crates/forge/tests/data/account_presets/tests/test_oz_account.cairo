@@ -0,0 +1,75 @@
+use account_presets::{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait};
+use snforge_std::signature::KeyPairTrait;
+use snforge_std::signature::stark_curve::{StarkCurveKeyPairImpl, StarkCurveSignerImpl};
+use snforge_std::{ContractClassTrait, DeclareResultTrait, InvokeOptions, declare, invoke_as_account};
+use starknet::ContractAddress;
+use starknet::account::Call;
+
+fn deploy_account(public_key: felt252) -> ContractAddress {
+    let account_class = declare("AccountUpgradeable").unwrap().contract_class();
+    let (account, _) = account_class.deploy(@array![public_key]).unwrap();
+    account
+}
+
+fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+    let contract = declare("HelloStarknet").unwrap().contract_class();
+    let (contract_address, _) = contract.deploy(@array![]).unwrap();
+    IHelloStarknetDispatcher { contract_address }
+}
+
+fn increase_balance_calls(dispatcher: IHelloStarknetDispatcher, amount: felt252) -> Array<Call> {
+    array![
+        Call {
+            to: dispatcher.contract_address,
+            selector: selector!("increase_balance"),
+            calldata: array![amount].span(),
+        },
+    ]
+}
+
+#[test]
+fn signed_transaction() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let transaction_hash = 'transaction hash';
+    let (r, s) = key_pair.sign(transaction_hash).unwrap();
+    let options = InvokeOptions {
+        transaction_hash, signature: array![r, s], skip_signature_verification: false,
+    };
+    invoke_as_account(account, increase_balance_calls(dispatcher, 100), options).unwrap();
+
+    assert(dispatcher.get_balance() == 100, 'call not executed');
+}
+
+#[test]
+fn invalid_signature() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let (r, s) = key_pair.sign('other transaction hash').unwrap();
+    let options = InvokeOptions {
+        transaction_hash: 'transaction hash',
+        signature: array![r, s],
+        skip_signature_verification: false,
+    };
+    let panic_data = invoke_as_account(account, increase_balance_calls(dispatcher, 100), options)
+        .unwrap_err();
+
+    assert(*panic_data.at(0) == 'Account: invalid signature', *panic_data.at(0));
+    assert(dispatcher.get_balance() == 0, 'call executed');
+}
+
+#[test]
+fn skipped_signature_verification() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+    invoke_as_account(account, increase_balance_calls(dispatcher, 100), options).unwrap();
+
+    assert(dispatcher.get_balance() == 100, 'call not executed');
+}
@@ -0,0 +1,88 @@
+use account_presets::{IHelloStarknetDispatcher, IHelloStarknetDispatcherTrait};
+use snforge_std::signature::KeyPairTrait;
+use snforge_std::signature::stark_curve::{StarkCurveKeyPairImpl, StarkCurveSignerImpl};
+use snforge_std::{
+    ContractClass, ContractClassTrait, DeclareResultTrait, InvokeOptions, declare,
+    invoke_as_account,
+};
+use starknet::ContractAddress;
+use starknet::account::Call;
+
+/// Class hash of the Ready (formerly Argent) account v0.4.0, declared on Sepolia
+const READY_CLASS_HASH: felt252 =
+    0x036078334509b514626504edc9fb252328d1a240e4e948bef8d0c08dff45927f;
+
+fn deploy_account(public_key: felt252) -> ContractAddress {
+    let account_class = ContractClass { class_hash: READY_CLASS_HASH.try_into().unwrap() };
+    // Owner is `Signer::Starknet(StarknetSigner { pubkey })`, guardian is `Option::None`
+    let (account, _) = account_class.deploy(@array![0, public_key, 1]).unwrap();
+    account
+}
+
+fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+    let contract = declare("HelloStarknet").unwrap().contract_class();
+    let (contract_address, _) = contract.deploy(@array![]).unwrap();
+    IHelloStarknetDispatcher { contract_address }
+}
+
+fn increase_balance_calls(dispatcher: IHelloStarknetDispatcher, amount: felt252) -> Array<Call> {
+    array![
+        Call {
+            to: dispatcher.contract_address,
+            selector: selector!("increase_balance"),
+            calldata: array![amount].span(),
+        },
+    ]
+}
+
+#[test]
+#[fork(url: "{{ NODE_RPC_URL }}", block_number: 828912)]
+fn signed_transaction() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let transaction_hash = 'transaction hash';
+    let (r, s) = key_pair.sign(transaction_hash).unwrap();
+    let options = InvokeOptions {
+        transaction_hash, signature: array![r, s], skip_signature_verification: false,
+    };
+    invoke_as_account(account, increase_balance_calls(dispatcher, 100), options).unwrap();
+
+    assert(dispatcher.get_balance() == 100, 'call not executed');
+}
+
+#[test]
+#[fork(url: "{{ NODE_RPC_URL }}", block_number: 828912)]
+fn invalid_signature() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let (r, s) = key_pair.sign('other transaction hash').unwrap();
+    let options = InvokeOptions {
+        transaction_hash: 'transaction hash',
+        signature: array![r, s],
+        skip_signature_verification: false,
+    };
+    invoke_as_account(account, increase_balance_calls(dispatcher, 100), options).unwrap_err();
+
+    assert(dispatcher.get_balance() == 0, 'call executed');
+}
+
+#[test]
+#[fork(url: "{{ NODE_RPC_URL }}", block_number: 828912)]
+fn skipped_signature_verification() {
+    let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+    let account = deploy_account(key_pair.public_key);
+    let dispatcher = deploy_hello_starknet();
+
+    let options = InvokeOptions {
+        transaction_hash: 'transaction hash',
+        signature: array!['not', 'signed'],
+        skip_signature_verification: true,
+    };
+    invoke_as_account(account, increase_balance_calls(dispatcher, 100), options).unwrap();
+
+    assert(dispatcher.get_balance() == 100, 'call not executed');
+}
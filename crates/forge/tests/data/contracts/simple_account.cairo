@@ -0,0 +1,88 @@
+use starknet::account::Call;
+
+#[starknet::interface]
+trait ISimpleAccount<TContractState> {
+    fn __validate__(self: @TContractState, calls: Array<Call>) -> felt252;
+    fn __execute__(ref self: TContractState, calls: Array<Call>) -> Array<Span<felt252>>;
+    fn is_valid_signature(self: @TContractState, hash: felt252, signature: Array<felt252>) -> felt252;
+    fn get_last_nonce(self: @TContractState) -> felt252;
+}
+
+#[starknet::contract(account)]
+mod SimpleAccount {
+    use array::ArrayTrait;
+    use core::ecdsa::check_ecdsa_signature;
+    use starknet::account::Call;
+    use starknet::{SyscallResultTrait, get_caller_address, get_tx_info};
+    use starknet::syscalls::call_contract_syscall;
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+        last_nonce: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[abi(embed_v0)]
+    impl ISimpleAccountImpl of super::ISimpleAccount<ContractState> {
+        fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+            assert(calls.len() > 0, 'no calls');
+            let tx_info = get_tx_info().unbox();
+            let signature = tx_info.signature;
+            assert(signature.len() == 2, 'invalid signature length');
+            assert(
+                check_ecdsa_signature(
+                    tx_info.transaction_hash,
+                    self.public_key.read(),
+                    *signature.at(0),
+                    *signature.at(1),
+                ),
+                'invalid signature',
+            );
+            starknet::VALIDATED
+        }
+
+        fn __execute__(ref self: ContractState, calls: Array<Call>) -> Array<Span<felt252>> {
+            let caller_address: felt252 = get_caller_address().into();
+            assert(caller_address == 0, 'invalid caller');
+            self.last_nonce.write(get_tx_info().unbox().nonce);
+
+            let mut results = array![];
+            let mut calls = calls.span();
+            loop {
+                match calls.pop_front() {
+                    Option::Some(call) => {
+                        results
+                            .append(
+                                call_contract_syscall(*call.to, *call.selector, *call.calldata)
+                                    .unwrap_syscall(),
+                            );
+                    },
+                    Option::None => { break; },
+                };
+            };
+            results
+        }
+
+        fn is_valid_signature(
+            self: @ContractState, hash: felt252, signature: Array<felt252>,
+        ) -> felt252 {
+            if signature.len() != 2 {
+                return 0;
+            }
+            if check_ecdsa_signature(hash, self.public_key.read(), *signature.at(0), *signature.at(1)) {
+                starknet::VALIDATED
+            } else {
+                0
+            }
+        }
+
+        fn get_last_nonce(self: @ContractState) -> felt252 {
+            self.last_nonce.read()
+        }
+    }
+}
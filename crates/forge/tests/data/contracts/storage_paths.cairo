@@ -0,0 +1,61 @@
+#[starknet::contract]
+mod StoragePaths {
+    use starknet::ContractAddress;
+    use starknet::storage::{
+        Map, MutableVecTrait, StoragePathEntry, StoragePointerReadAccess, StoragePointerWriteAccess,
+        Vec, VecTrait,
+    };
+
+    #[derive(Drop, Serde, starknet::Store)]
+    struct Balance {
+        amount: u256,
+        locked: bool,
+    }
+
+    #[starknet::storage_node]
+    struct Account {
+        balance: Balance,
+        nonces: Map<felt252, u64>,
+    }
+
+    #[storage]
+    struct Storage {
+        accounts: Map<ContractAddress, Account>,
+        owners: Vec<ContractAddress>,
+    }
+
+    #[external(v0)]
+    fn set_balance(ref self: ContractState, account: ContractAddress, amount: u256, locked: bool) {
+        self.accounts.entry(account).balance.write(Balance { amount, locked });
+    }
+
+    #[external(v0)]
+    fn get_balance(self: @ContractState, account: ContractAddress) -> Balance {
+        self.accounts.entry(account).balance.read()
+    }
+
+    #[external(v0)]
+    fn set_nonce(ref self: ContractState, account: ContractAddress, key: felt252, nonce: u64) {
+        self.accounts.entry(account).nonces.entry(key).write(nonce);
+    }
+
+    #[external(v0)]
+    fn get_nonce(self: @ContractState, account: ContractAddress, key: felt252) -> u64 {
+        self.accounts.entry(account).nonces.entry(key).read()
+    }
+
+    #[external(v0)]
+    fn add_owner(ref self: ContractState, owner: ContractAddress) {
+        self.owners.push(owner);
+    }
+
+    #[external(v0)]
+    fn get_owner(self: @ContractState, index: u64) -> ContractAddress {
+        self.owners.at(index).read()
+    }
+
+    #[external(v0)]
+    fn get_owners_count(self: @ContractState) -> u64 {
+        self.owners.len()
+    }
+}
@@ -0,0 +1,62 @@
+use super::common::runner::{setup_package, test_runner};
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[test]
+fn invoke_as_oz_account() {
+    let temp = setup_package("account_presets");
+    let output = test_runner(&temp).arg("test_oz_account").assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Collected 3 test(s) from account_presets package
+        Running 3 test(s) from tests/
+        [..]
+        [..]
+        [..]
+        Tests: 3 passed, 0 failed, 0 ignored, 5 filtered out
+        "},
+    );
+}
+
+#[test]
+fn invoke_as_ready_account() {
+    let temp = setup_package("account_presets");
+    let output = test_runner(&temp)
+        .arg("test_ready_account")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Collected 3 test(s) from account_presets package
+        Running 3 test(s) from tests/
+        [..]
+        [..]
+        [..]
+        Tests: 3 passed, 0 failed, 0 ignored, 5 filtered out
+        "},
+    );
+}
+
+#[test]
+fn invoke_as_account_with_custom_validation() {
+    let temp = setup_package("account_presets");
+    let output = test_runner(&temp)
+        .arg("test_custom_account")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Collected 2 test(s) from account_presets package
+        Running 2 test(s) from tests/
+        [..]
+        [..]
+        Tests: 2 passed, 0 failed, 0 ignored, 6 filtered out
+        "},
+    );
+}
@@ -3,7 +3,7 @@ use super::common::runner::{
 };
 use forge_runner::CACHE_DIR;
 use indoc::{formatdoc, indoc};
-use shared::test_utils::node_url::node_rpc_url;
+use shared::test_utils::node_url::{node_rpc_url, node_url};
 use shared::test_utils::output_assert::assert_stdout_contains;
 
 #[test]
@@ -158,3 +158,62 @@ fn with_skip_fork_tests_env() {
         "},
     );
 }
+
+#[test]
+fn export_and_import_fixture() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+
+    test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .assert()
+        .code(0);
+
+    let node_rpc_url = node_rpc_url().to_string();
+    runner(&temp)
+        .args([
+            "fork",
+            "export",
+            "--url",
+            &node_rpc_url,
+            "--block-number",
+            "54060",
+            "--output",
+            "fork.json",
+        ])
+        .assert()
+        .code(0);
+
+    let fixture = std::fs::read_to_string(temp.join("fork.json")).unwrap();
+    assert!(!fixture.contains(node_url().host_str().unwrap()));
+
+    runner(&temp).arg("clean-cache").assert().code(0);
+
+    let output = runner(&temp)
+        .args(["fork", "import", "fork.json", "--url", &node_rpc_url])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        "Imported fork fixture of chain [..] and block number = 54060 to the fork cache",
+    );
+
+    let output = test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple", "--offline"])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from forking package
+        Running 1 test(s) from src/
+        [PASS] forking::tests::test_fork_simple [..]
+        Tests: 1 passed, 0 failed, 0 ignored, other filtered out
+        "},
+    );
+}
@@ -22,7 +22,7 @@ fn fuzzing() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], shrunk arguments: ["0", "0"])
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -67,7 +67,7 @@ fn fuzzing_set_runs() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 10, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 10, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], shrunk arguments: ["0", "0"])
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -112,7 +112,7 @@ fn fuzzing_set_seed() {
         [PASS] fuzzing::tests::fuzzed_argument (runs: 256, [..]
         [PASS] fuzzing::tests::fuzzed_both_arguments (runs: 256, [..]
         [PASS] fuzzing::tests::passing [..]
-        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]])
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: [[..], [..]], shrunk arguments: ["0", "0"])
 
         Failure data:
             0x726573756c74203d3d2061202b2062 ('result == a + b')
@@ -191,7 +191,7 @@ fn fuzzing_exit_first() {
 
         Collected 2 test(s) from fuzzing package
         Running 2 test(s) from tests/
-        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [[..]])
+        [FAIL] fuzzing_integrationtest::exit_first_fuzz::exit_first_fails_test (runs: 1, arguments: [[..]], shrunk arguments: ["0"])
 
         Failure data:
             0x32202b2062203d3d2032202b2062 ('2 + b == 2 + b')
@@ -206,6 +206,95 @@ fn fuzzing_exit_first() {
     );
 }
 
+#[test]
+fn fuzzing_replays_failing_inputs_from_corpus() {
+    let temp = setup_package("fuzzing");
+
+    test_runner(&temp)
+        .arg("fuzzing::tests::failing_fuzz")
+        .assert()
+        .code(1);
+
+    assert!(
+        temp.child(".snfoundry_cache/.fuzzer_corpus.json")
+            .path()
+            .exists()
+    );
+
+    let output = test_runner(&temp)
+        .arg("fuzzing::tests::failing_fuzz")
+        .assert()
+        .code(1);
+
+    // Shrunk input saved in the previous run is replayed first, so there is nothing left to shrink
+    assert_stdout_contains(
+        output,
+        indoc! {r#"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 1 test(s) from fuzzing package
+        Running 1 test(s) from src/
+        [FAIL] fuzzing::tests::failing_fuzz (runs: 1, arguments: ["0", "0"])
+
+        Failure data:
+            0x726573756c74203d3d2061202b2062 ('result == a + b')
+
+        Running 0 test(s) from tests/
+        Tests: 0 passed, 1 failed, 0 ignored, 23 filtered out
+        Fuzzer seed: [..]
+
+        Failures:
+            fuzzing::tests::failing_fuzz
+        "#},
+    );
+}
+
+#[test]
+fn fuzzing_guided_strategy() {
+    let temp = setup_package("fuzzing");
+    let test_file = temp.child("tests/guided.cairo");
+
+    test_file.touch().unwrap();
+    test_file
+        .write_str(indoc! {r"
+        #[test]
+        #[fuzzer(seed: 1234)]
+        fn equal_arguments(a: u64, b: u64) {
+            assert(a != b, 'a == b');
+        }
+        "})
+        .unwrap();
+
+    let output = test_runner(&temp)
+        .arg("guided::equal_arguments")
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [PASS] fuzzing_integrationtest::guided::equal_arguments (runs: 256, [..]
+        "},
+    );
+
+    let output = test_runner(&temp)
+        .args(["guided::equal_arguments", "--fuzzer-strategy", "guided"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] fuzzing_integrationtest::guided::equal_arguments (runs: [..], arguments: [..])
+
+        Failure data:
+            0x61203d3d2062 ('a == b')
+        "},
+    );
+}
+
 #[test]
 fn fuzzing_exit_first_single_fail() {
     let temp = setup_package("fuzzing");
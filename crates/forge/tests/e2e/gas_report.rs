@@ -0,0 +1,22 @@
+use super::common::runner::{setup_package, test_runner};
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[test]
+fn prints_gas_report() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["call_and_invoke", "--gas-report"])
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Gas report (l2_gas):
+        Contract      | Function         | Calls | Min[..]| Mean[..]| Median[..]| Max
+        HelloStarknet | get_balance      | 2     | [..]
+        HelloStarknet | increase_balance | 1     | [..]
+        "},
+    );
+}
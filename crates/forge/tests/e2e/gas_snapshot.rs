@@ -0,0 +1,61 @@
+use super::common::runner::{setup_package, test_runner};
+use indoc::indoc;
+use serde_json::Value;
+use shared::test_utils::output_assert::assert_stdout_contains;
+use std::fs;
+
+#[test]
+fn saves_and_checks_gas_snapshot() {
+    let temp = setup_package("simple_package");
+    test_runner(&temp)
+        .args(["test_two", "--gas-snapshot"])
+        .assert()
+        .success();
+
+    let snapshot_path = temp.join(".gas-snapshot");
+    let mut snapshot: Value =
+        serde_json::from_str(&fs::read_to_string(&snapshot_path).unwrap()).unwrap();
+    assert!(
+        snapshot["simple_package_integrationtest::test_simple::test_two"]["l2_gas"]
+            .as_u64()
+            .unwrap()
+            > 0
+    );
+    assert!(snapshot["simple_package_integrationtest::test_simple::test_two_and_two"].is_object());
+
+    test_runner(&temp)
+        .args(["test_two", "--gas-snapshot-check"])
+        .assert()
+        .success();
+
+    snapshot["simple_package_integrationtest::test_simple::test_two"]["l2_gas"] = 1.into();
+    fs::write(&snapshot_path, snapshot.to_string()).unwrap();
+
+    let output = test_runner(&temp)
+        .args(["test_two", "--gas-snapshot-check"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Gas usage differs from the gas snapshot (tolerance: 0%):
+        Test[..]| Resource[..]| Snapshot[..]| Current[..]| Change
+        simple_package_integrationtest::test_simple::test_two[..]| l2_gas[..]| 1[..]| [..]| [..] (regression)
+        "},
+    );
+}
+
+#[test]
+fn gas_snapshot_check_without_snapshot() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .arg("--gas-snapshot-check")
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        "[ERROR] Gas snapshot not found at [..].gas-snapshot, create it by running `snforge test --gas-snapshot`",
+    );
+}
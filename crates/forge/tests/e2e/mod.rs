@@ -1,5 +1,6 @@
 pub(crate) mod common;
 
+mod account_presets;
 mod backtrace;
 mod build_profile;
 mod build_trace_data;
@@ -18,13 +19,18 @@ mod features;
 mod fork_warning;
 mod forking;
 mod fuzzing;
+mod gas_report;
+mod gas_snapshot;
 mod io_operations;
 mod new;
+mod output_format;
 mod profiles;
+mod report;
 mod requirements;
 mod running;
 mod steps;
 mod templates;
 mod trace_print;
 mod trace_resources;
+mod watch;
 mod workspaces;
@@ -0,0 +1,66 @@
+use super::common::runner::{setup_package, test_runner};
+use serde_json::Value;
+
+#[test]
+fn json_output_is_stream_of_events() {
+    let temp = setup_package("simple_package");
+    let output = test_runner(&temp)
+        .args(["--format", "json"])
+        .assert()
+        .code(1);
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let events = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("Every line must be a JSON object"))
+        .collect::<Vec<_>>();
+
+    let count = |message_type: &str| {
+        events
+            .iter()
+            .filter(|event| event["message_type"] == message_type)
+            .count()
+    };
+    assert_eq!(count("tests_collected"), 1);
+    assert_eq!(count("tests_summary"), 1);
+    // Ignored tests are not started
+    assert_eq!(count("test_started"), 11);
+    assert_eq!(count("test_result"), 13);
+
+    let failing = events
+        .iter()
+        .find(|event| {
+            event["message_type"] == "test_result"
+                && event["name"] == "simple_package_integrationtest::test_simple::test_failing"
+        })
+        .unwrap();
+    assert_eq!(failing["status"], "failed");
+    assert!(failing["msg"].as_str().unwrap().contains("failing check"));
+    assert!(failing["duration"].as_f64().is_some());
+}
+
+#[test]
+fn json_output_reports_fuzzer_progress() {
+    let temp = setup_package("fuzzing");
+    let output = test_runner(&temp)
+        .args([
+            "fuzzing::tests::adding",
+            "--exact",
+            "--format",
+            "json",
+            "--fuzzer-runs",
+            "10",
+        ])
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let progress = stdout
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .filter(|event| event["message_type"] == "fuzzer_progress")
+        .collect::<Vec<_>>();
+
+    assert_eq!(progress.len(), 10);
+    assert!(progress.iter().all(|event| event["total_runs"] == 10));
+}
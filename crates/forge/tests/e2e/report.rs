@@ -0,0 +1,83 @@
+use super::common::runner::{setup_hello_workspace, setup_package, test_runner};
+use serde_json::Value;
+use std::fs;
+
+#[test]
+fn saves_junit_and_json_reports() {
+    let temp = setup_package("simple_package");
+    test_runner(&temp)
+        .args([
+            "--report",
+            "junit=reports/junit.xml",
+            "--report",
+            "json=report.json",
+        ])
+        .assert()
+        .code(1);
+
+    let junit = fs::read_to_string(temp.join("reports/junit.xml")).unwrap();
+    assert!(junit.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(junit.contains(
+        r#"<testsuite name="simple_package" tests="13" failures="2" skipped="2" time=""#
+    ));
+    assert!(junit.contains(
+        r#"<testcase name="test_failing" classname="simple_package_integrationtest::test_simple" time=""#
+    ));
+    assert!(junit.contains(r#"<property name="l2_gas" value=""#));
+
+    let json: Value =
+        serde_json::from_str(&fs::read_to_string(temp.join("report.json")).unwrap()).unwrap();
+    let package = &json["packages"][0];
+    assert_eq!(package["name"], "simple_package");
+
+    let test_cases = package["test_cases"].as_array().unwrap();
+    let count = |status: &str| {
+        test_cases
+            .iter()
+            .filter(|test_case| test_case["status"] == status)
+            .count()
+    };
+    assert_eq!(count("passed"), 9);
+    assert_eq!(count("failed"), 2);
+    assert_eq!(count("ignored"), 2);
+
+    let failing = test_cases
+        .iter()
+        .find(|test_case| {
+            test_case["name"] == "simple_package_integrationtest::test_simple::test_failing"
+        })
+        .unwrap();
+    assert!(failing["duration"].as_f64().unwrap() > 0.0);
+    assert!(
+        failing["message"]
+            .as_str()
+            .unwrap()
+            .contains("failing check")
+    );
+}
+
+#[test]
+fn saves_report_relative_to_current_dir_for_workspace() {
+    let temp = setup_hello_workspace();
+    let current_dir = temp.join("crates");
+    test_runner(&temp)
+        .current_dir(&current_dir)
+        .args(["--workspace", "--report", "json=reports/report.json"])
+        .assert()
+        .code(1);
+
+    let json: Value =
+        serde_json::from_str(&fs::read_to_string(current_dir.join("reports/report.json")).unwrap())
+            .unwrap();
+    let package_names = json["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|package| package["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(package_names, ["addition", "fibonacci", "hello_workspaces"]);
+
+    assert!(!temp.join("reports").exists());
+    assert!(!current_dir.join("addition/reports").exists());
+    assert!(!current_dir.join("fibonacci/reports").exists());
+}
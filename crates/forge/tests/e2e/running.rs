@@ -297,6 +297,34 @@ fn with_skip_filter_matching_full_module_path() {
     );
 }
 
+#[test]
+fn with_partition() {
+    let temp = setup_package("simple_package");
+
+    let mut collected = 0;
+    for partition in ["hash:1/2", "hash:2/2"] {
+        let output = test_runner(&temp).args(["--partition", partition]).assert();
+        let stdout = output.as_stdout();
+
+        assert!(stdout.contains(&format!(
+            "Partition: {}",
+            partition.trim_start_matches("hash:")
+        )));
+        collected += stdout
+            .lines()
+            .find_map(|line| {
+                line.strip_prefix("Collected ")?
+                    .split_once(' ')?
+                    .0
+                    .parse::<usize>()
+                    .ok()
+            })
+            .unwrap();
+    }
+
+    assert_eq!(collected, 13);
+}
+
 #[test]
 fn with_skip_filter_matching_test_name() {
     let temp = setup_package("simple_package");
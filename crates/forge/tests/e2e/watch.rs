@@ -0,0 +1,97 @@
+use super::common::runner::{setup_package, snforge_test_bin_path};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{Receiver, channel};
+use std::thread;
+use std::time::Duration;
+
+const RUN_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Kills the watching `snforge` even if the test fails, as it never exits on its own
+struct WatchProcess(Child);
+
+impl Drop for WatchProcess {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Lines printed during a single run, up to the moment `snforge` starts waiting for changes
+fn read_run(lines: &Receiver<String>) -> Vec<String> {
+    let mut run = vec![];
+    loop {
+        let line = lines
+            .recv_timeout(RUN_TIMEOUT)
+            .expect("Tests were not run in time");
+        if line == "Watching for changes..." {
+            return run;
+        }
+        run.push(line);
+    }
+}
+
+fn position(run: &[String], text: &str) -> usize {
+    run.iter()
+        .position(|line| line.contains(text))
+        .unwrap_or_else(|| panic!("`{text}` not found in the output:\n{}", run.join("\n")))
+}
+
+#[test]
+fn reruns_failed_tests_first_after_change() {
+    let temp = setup_package("simple_package");
+
+    let mut snforge = WatchProcess(
+        Command::new(snforge_test_bin_path())
+            .current_dir(&temp)
+            .args(["test", "--watch"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap(),
+    );
+    let stdout = snforge.0.stdout.take().unwrap();
+    let (sender, lines) = channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let first_run = read_run(&lines);
+    position(
+        &first_run,
+        "[FAIL] simple_package_integrationtest::test_simple::test_failing",
+    );
+    assert!(
+        !first_run
+            .iter()
+            .any(|line| line.contains("Running tests that failed during the last run"))
+    );
+
+    // Let `snforge` take the snapshot of the sources before changing them
+    thread::sleep(Duration::from_secs(2));
+    let mut source = OpenOptions::new()
+        .append(true)
+        .open(temp.path().join("tests/test_simple.cairo"))
+        .unwrap();
+    writeln!(source, "// changed").unwrap();
+
+    let second_run = read_run(&lines);
+    let failed_stage = position(&second_run, "Running tests that failed during the last run");
+    let failed_test = position(
+        &second_run,
+        "[FAIL] simple_package_integrationtest::test_simple::test_failing",
+    );
+    let remaining_stage = position(&second_run, "Running remaining tests");
+    let passed_test = position(
+        &second_run,
+        "[PASS] simple_package_integrationtest::test_simple::test_simple ",
+    );
+
+    assert!(failed_stage < failed_test);
+    assert!(failed_test < remaining_stage);
+    assert!(remaining_stage < passed_test);
+}
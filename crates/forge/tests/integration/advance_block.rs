@@ -0,0 +1,177 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use indoc::indoc;
+use starknet_api::transaction::fields::Fee;
+use std::path::Path;
+use test_utils::runner::{
+    Contract, TestCase, assert_case_output_contains, assert_failed, assert_passed,
+};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn roll_and_warp() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::get_block_info;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, roll, warp,
+                start_cheat_block_number_global, start_cheat_block_timestamp_global
+            };
+
+            #[starknet::interface]
+            trait IBlockInfoChecker<TContractState> {
+                fn read_block_number(self: @TContractState) -> u64;
+                fn read_block_timestamp(self: @TContractState) -> u64;
+            }
+
+            #[test]
+            fn roll_and_warp() {
+                let contract = declare("BlockInfoChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IBlockInfoCheckerDispatcher { contract_address };
+
+                let block_info = get_block_info().unbox();
+                let block_number = block_info.block_number;
+                let block_timestamp = block_info.block_timestamp;
+
+                roll(10, 6);
+
+                assert(dispatcher.read_block_number() == block_number + 10, 'wrong number after roll');
+                assert(dispatcher.read_block_timestamp() == block_timestamp + 60, 'wrong time after roll');
+                assert(get_block_info().unbox().block_number == block_number + 10, 'test not rolled');
+
+                warp(100);
+
+                assert(dispatcher.read_block_number() == block_number + 11, 'wrong number after warp');
+                assert(dispatcher.read_block_timestamp() == block_timestamp + 160, 'wrong time after warp');
+
+                start_cheat_block_number_global(1000);
+                start_cheat_block_timestamp_global(5000);
+                roll(2, 30);
+
+                assert(dispatcher.read_block_number() == 1002, 'cheated number not rolled');
+                assert(dispatcher.read_block_timestamp() == 5060, 'cheated time not rolled');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "BlockInfoChecker".to_string(),
+            Path::new("tests/data/contracts/block_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn roll_block_hashes() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::get_block_info;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, roll, start_cheat_block_hash_global
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockHashChecker<TContractState> {
+                fn get_block_hash(ref self: TContractState, block_number: u64) -> felt252;
+            }
+
+            #[test]
+            fn roll_block_hashes() {
+                let contract = declare("CheatBlockHashChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = ICheatBlockHashCheckerDispatcher { contract_address };
+
+                let block_number = get_block_info().unbox().block_number;
+                roll(100, 6);
+
+                let passed_block_number = block_number + 50;
+                let default_hash = core::pedersen::pedersen(
+                    'SNFORGE_BLOCK_HASH', passed_block_number.into(),
+                );
+                assert(default_hash != 0, 'zero default hash');
+                assert(dispatcher.get_block_hash(passed_block_number) == default_hash, 'wrong default hash');
+                assert(
+                    dispatcher.get_block_hash(passed_block_number + 1) != default_hash,
+                    'repeated default hash',
+                );
+
+                start_cheat_block_hash_global(passed_block_number, 1234);
+                assert(dispatcher.get_block_hash(passed_block_number) == 1234, 'wrong cheated hash');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockHashChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_hash_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn roll_keeps_gas_prices() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::{GasPrices, cheat_gas_prices, roll};
+
+        #[test]
+        fn rolled_gas_prices() {
+            cheat_gas_prices(
+                GasPrices { l1_gas_price: 3, l1_data_gas_price: 5, l2_gas_price: 4800 },
+            );
+            roll(2, 6);
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, fee, .. }) =
+        &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+
+    let expected_fee = u128::from(gas_info.l1_gas.0) * 3
+        + u128::from(gas_info.l1_data_gas.0) * 5
+        + u128::from(gas_info.l2_gas.0) * 4800;
+    assert_eq!(*fee, Some(Fee(expected_fee)));
+}
+
+#[test]
+fn roll_timestamp_overflow() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::roll;
+
+        #[test]
+        fn overflowing_roll() {
+            roll(0x8000000000000000, 2);
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "overflowing_roll",
+        "Block timestamp overflowed after advancing by 9223372036854775808 blocks of 2 seconds",
+    );
+}
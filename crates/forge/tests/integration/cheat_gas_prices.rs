@@ -0,0 +1,102 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use indoc::indoc;
+use starknet_api::transaction::fields::Fee;
+use test_utils::runner::{TestCase, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn cheat_gas_prices_changes_fee() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::{GasPrices, cheat_gas_prices};
+
+        #[test]
+        fn cheated_gas_prices() {
+            cheat_gas_prices(
+                GasPrices { l1_gas_price: 3, l1_data_gas_price: 5, l2_gas_price: 7 },
+            );
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, fee, .. }) =
+        &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+
+    let expected_fee = u128::from(gas_info.l1_gas.0) * 3
+        + u128::from(gas_info.l1_data_gas.0) * 5
+        + u128::from(gas_info.l2_gas.0) * 7;
+    assert_eq!(*fee, Some(Fee(expected_fee)));
+}
+
+#[test]
+fn stop_cheat_gas_prices_restores_fee() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::{GasPrices, cheat_gas_prices, stop_cheat_gas_prices};
+
+        #[test]
+        fn cheated_and_stopped() {
+            cheat_gas_prices(
+                GasPrices { l1_gas_price: 1, l1_data_gas_price: 1, l2_gas_price: 1 },
+            );
+            stop_cheat_gas_prices();
+        }
+
+        #[test]
+        fn not_cheated() {}
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    for summary in &test_target_summary.test_case_summaries {
+        let AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, fee, .. }) = summary
+        else {
+            panic!()
+        };
+
+        // Block gas prices are much greater than 1 FRI
+        let gas_amount = u128::from(gas_info.l1_gas.0)
+            + u128::from(gas_info.l1_data_gas.0)
+            + u128::from(gas_info.l2_gas.0);
+        assert!(fee.unwrap().0 > gas_amount);
+    }
+}
+
+#[test]
+fn cheat_gas_prices_zero_price() {
+    let test = test_case!(indoc!(
+        r"
+        use snforge_std::{GasPrices, cheat_gas_prices};
+
+        #[test]
+        fn zero_price() {
+            cheat_gas_prices(
+                GasPrices { l1_gas_price: 1, l1_data_gas_price: 0, l2_gas_price: 1 },
+            );
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "zero_price",
+        "`l1_data_gas_price` must be greater than 0",
+    );
+}
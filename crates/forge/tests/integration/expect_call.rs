@@ -0,0 +1,178 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn expect_call_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_call};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn expect_call_met() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_call(contract_address, selector!("increase_balance"), array![5], 2);
+                expect_call(contract_address, selector!("increase_balance"), array![7], 0);
+
+                dispatcher.increase_balance(5);
+                dispatcher.increase_balance(3);
+                dispatcher.increase_balance(5);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_call_not_met() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_call};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn expect_call_not_met() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_call(contract_address, selector!("increase_balance"), array![5], 1);
+
+                dispatcher.increase_balance(3);
+                dispatcher.increase_balance(4);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_call_not_met",
+        "was made 0 time(s), expected 1",
+    );
+    assert_case_output_contains(&result, "expect_call_not_met", "- calldata: [0x5]");
+    assert_case_output_contains(&result, "expect_call_not_met", "+ calldata: [0x3]");
+    assert_case_output_contains(&result, "expect_call_not_met", "+ calldata: [0x4]");
+}
+
+#[test]
+fn expect_call_ignores_calls_made_before() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_call};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn expect_call_ignores_calls_made_before() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                dispatcher.increase_balance(5);
+
+                expect_call(contract_address, selector!("increase_balance"), array![5], 1);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_call_ignores_calls_made_before",
+        "was made 0 time(s), expected 1",
+    );
+}
+
+#[test]
+fn expect_call_not_met_in_should_panic_test() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_call};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            #[should_panic(expected: 'expected panic')]
+            fn expect_call_not_met_in_should_panic_test() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_call(contract_address, selector!("increase_balance"), array![5], 1);
+
+                dispatcher.increase_balance(3);
+                core::panic_with_felt252('expected panic');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_call_not_met_in_should_panic_test",
+        "was made 0 time(s), expected 1",
+    );
+}
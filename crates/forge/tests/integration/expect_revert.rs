@@ -0,0 +1,255 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn expect_revert_matching() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+                fn do_a_panic(self: @TContractState);
+                fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+            }
+
+            #[test]
+            fn expect_revert_matching() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_revert(array!['PANIC', 'DAYTAH']);
+                dispatcher.do_a_panic();
+
+                dispatcher.increase_balance(5);
+
+                expect_revert(array!['capybara']);
+                dispatcher.do_a_panic_with(array!['capybara']);
+
+                assert(dispatcher.get_balance() == 5, 'wrong balance');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_revert_different_panic_data() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn do_a_panic_with(self: @TContractState, panic_data: Array<felt252>);
+            }
+
+            #[test]
+            fn expect_revert_different_panic_data() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_revert(array!['capybara']);
+                dispatcher.do_a_panic_with(array!['wombat']);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_revert_different_panic_data",
+        "Expected the call to revert with panic data 0x6361707962617261 ('capybara'), but it reverted with panic data 0x776f6d626174 ('wombat')",
+    );
+}
+
+#[test]
+fn expect_revert_call_succeeded() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+            }
+
+            #[test]
+            fn expect_revert_call_succeeded() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_revert(array!['capybara']);
+                dispatcher.increase_balance(5);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_revert_call_succeeded",
+        "Expected the call to revert with panic data 0x6361707962617261 ('capybara'), but it succeeded",
+    );
+}
+
+#[test]
+fn expect_revert_without_call() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::expect_revert;
+
+            #[test]
+            fn expect_revert_without_call() {
+                expect_revert(array!['capybara']);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_revert_without_call",
+        "but no call was made after `expect_revert`",
+    );
+}
+
+#[test]
+fn expect_revert_byte_array() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn do_a_panic_with_bytearray(self: @TContractState);
+            }
+
+            #[test]
+            fn expect_revert_byte_array() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                // Panic data of `ByteArray`s starts with a magic value
+                let mut expected_panic_data = array![
+                    0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3
+                ];
+                let expected: ByteArray = "multiline message";
+                expected.serialize(ref expected_panic_data);
+
+                expect_revert(expected_panic_data);
+                dispatcher.do_a_panic_with_bytearray();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_revert_value_returning_function() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IReverting<TContractState> {
+                fn get_value(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn expect_revert_value_returning_function() {
+                let contract = declare("Reverting").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IRevertingDispatcher { contract_address };
+
+                expect_revert(array!['reverted']);
+                dispatcher.get_value();
+            }
+        "#
+        ),
+        Contract::new(
+            "Reverting",
+            indoc!(
+                r"
+                #[starknet::interface]
+                trait IReverting<TContractState> {
+                    fn get_value(self: @TContractState) -> felt252;
+                }
+
+                #[starknet::contract]
+                mod Reverting {
+                    #[storage]
+                    struct Storage {}
+
+                    #[abi(embed_v0)]
+                    impl RevertingImpl of super::IReverting<ContractState> {
+                        fn get_value(self: @ContractState) -> felt252 {
+                            panic_with_felt252('reverted')
+                        }
+                    }
+                }
+                "
+            )
+        )
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "expect_revert_value_returning_function",
+        "`expect_revert` can't be used for the call to `get_value`, as it returns a value which can't be decoded after the call reverts. Use a safe dispatcher to check the revert instead",
+    );
+}
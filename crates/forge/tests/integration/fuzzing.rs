@@ -91,3 +91,31 @@ fn fuzzed_while_loop() {
     assert!(gas_info.l2_gas.mean > 0.0);
     assert!(gas_info.l2_gas.std_deviation > 0.0);
 }
+
+#[test]
+fn shrinks_failing_arguments() {
+    let test = test_case!(indoc!(
+        r"
+        #[test]
+        #[fuzzer(seed: 100)]
+        fn shrinks_failing_arguments(a: u64, b: felt252) {
+            assert(a < 1000, 'a >= 1000');
+        }
+    "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+        shrunk_fuzzer_args, ..
+    }) = &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+
+    assert_eq!(
+        shrunk_fuzzer_args,
+        &Some(vec!["1000".to_string(), "0".to_string()])
+    );
+}
@@ -0,0 +1,133 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
+use indoc::indoc;
+use test_utils::runner::{Contract, TestCase, assert_case_output_contains, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+const COUNTER_CONTRACT: &str = indoc!(
+    r"
+    #[starknet::interface]
+    trait ICounter<TContractState> {
+        fn increase(ref self: TContractState, amount: u8);
+        fn decrease(ref self: TContractState, amount: u8);
+        fn get(self: @TContractState) -> u64;
+    }
+
+    #[starknet::contract]
+    mod Counter {
+        use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+        #[storage]
+        struct Storage {
+            value: u64,
+        }
+
+        #[abi(embed_v0)]
+        impl CounterImpl of super::ICounter<ContractState> {
+            fn increase(ref self: ContractState, amount: u8) {
+                self.value.write(self.value.read() + amount.into());
+            }
+
+            fn decrease(ref self: ContractState, amount: u8) {
+                self.value.write(self.value.read() - amount.into());
+            }
+
+            fn get(self: @ContractState) -> u64 {
+                self.value.read()
+            }
+        }
+    }
+    "
+);
+
+const COUNTER_SETUP: &str = indoc!(
+    r#"
+    use snforge_std::{declare, ContractClassTrait, DeclareResultTrait};
+    use starknet::ContractAddress;
+
+    #[starknet::interface]
+    trait ICounter<TContractState> {
+        fn increase(ref self: TContractState, amount: u8);
+        fn decrease(ref self: TContractState, amount: u8);
+        fn get(self: @TContractState) -> u64;
+    }
+
+    fn deploy_counter() -> ContractAddress {
+        let contract = declare("Counter").unwrap().contract_class();
+        let (contract_address, _) = contract.deploy(@array![]).unwrap();
+        contract_address
+    }
+    "#
+);
+
+#[test]
+fn invariant_holds() {
+    let test = test_case!(
+        &format!(
+            "{COUNTER_SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                #[invariant(target: deploy_counter, runs: 20, depth: 10)]
+                fn value_is_bounded(target: ContractAddress) {
+                    let counter = ICounterDispatcher { contract_address: target };
+                    assert(counter.get() <= 2550, 'value > 2550');
+                }
+                "
+            )
+        ),
+        Contract::new("Counter", COUNTER_CONTRACT)
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn reports_shrunk_call_sequence() {
+    let test = test_case!(
+        &format!(
+            "{COUNTER_SETUP}{}",
+            indoc!(
+                r"
+                #[test]
+                #[invariant(target: deploy_counter, seed: 100, depth: 5)]
+                fn value_is_small(target: ContractAddress) {
+                    let counter = ICounterDispatcher { contract_address: target };
+                    assert(counter.get() < 300, 'value >= 300');
+                }
+                "
+            )
+        ),
+        Contract::new("Counter", COUNTER_CONTRACT)
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_case_output_contains(&result, "value_is_small", "value >= 300");
+
+    let test_target_summary = TestCase::find_test_result(&result);
+    let AnyTestCaseSummary::Fuzzing(TestCaseSummary::Failed {
+        shrunk_fuzzer_args: Some(calls),
+        ..
+    }) = &test_target_summary.test_case_summaries[0]
+    else {
+        panic!()
+    };
+
+    // A single call increases the value by at most 255, and calls that do not increase it are removed
+    let amounts: Vec<u64> = calls
+        .iter()
+        .map(|call| {
+            call.strip_prefix("increase(")
+                .and_then(|call| call.strip_suffix(')'))
+                .unwrap()
+                .parse()
+                .unwrap()
+        })
+        .collect();
+    assert!(amounts.len() >= 2);
+    assert!(amounts.iter().sum::<u64>() >= 300);
+}
@@ -0,0 +1,180 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn invoke_as_account_multicall() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::account::Call;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, invoke_as_account, InvokeOptions};
+            use snforge_std::signature::KeyPairTrait;
+            use snforge_std::signature::stark_curve::{StarkCurveKeyPairImpl, StarkCurveSignerImpl};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[starknet::interface]
+            trait ISimpleAccount<TContractState> {
+                fn get_last_nonce(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn invoke_as_account_multicall() {
+                let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+                let account_class = declare("SimpleAccount").unwrap().contract_class();
+                let (account, _) = account_class.deploy(@array![key_pair.public_key]).unwrap();
+
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let calls = array![
+                    Call { to: contract_address, selector: selector!("increase_balance"), calldata: array![2].span() },
+                    Call { to: contract_address, selector: selector!("increase_balance"), calldata: array![3].span() },
+                ];
+                let transaction_hash = 'first transaction';
+                let (r, s) = key_pair.sign(transaction_hash).unwrap();
+                let options = InvokeOptions {
+                    transaction_hash, signature: array![r, s], skip_signature_verification: false,
+                };
+                invoke_as_account(account, calls, options).unwrap();
+
+                assert(IHelloStarknetDispatcher { contract_address }.get_balance() == 5, 'calls not executed');
+                assert(ISimpleAccountDispatcher { contract_address: account }.get_last_nonce() == 0, 'wrong first nonce');
+
+                let calls = array![
+                    Call { to: contract_address, selector: selector!("increase_balance"), calldata: array![1].span() },
+                ];
+                let options = InvokeOptions {
+                    transaction_hash: 'second transaction', signature: array![r, s], skip_signature_verification: false,
+                };
+                let result = invoke_as_account(account, calls, options);
+                assert(*result.unwrap_err().at(0) == 'invalid signature', 'signature not verified');
+
+                let calls = array![
+                    Call { to: contract_address, selector: selector!("increase_balance"), calldata: array![1].span() },
+                ];
+                let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+                invoke_as_account(account, calls, options).unwrap();
+
+                assert(IHelloStarknetDispatcher { contract_address }.get_balance() == 6, 'call not executed');
+                assert(ISimpleAccountDispatcher { contract_address: account }.get_last_nonce() == 1, 'wrong second nonce');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn invoke_as_account_execution_failure() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::account::Call;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, invoke_as_account, InvokeOptions};
+
+            #[starknet::interface]
+            trait ISimpleAccount<TContractState> {
+                fn get_last_nonce(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn invoke_as_account_execution_failure() {
+                let account_class = declare("SimpleAccount").unwrap().contract_class();
+                let (account, _) = account_class.deploy(@array![0]).unwrap();
+
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let calls = array![
+                    Call { to: contract_address, selector: selector!("do_a_panic"), calldata: array![].span() },
+                ];
+                let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+                let panic_data = invoke_as_account(account, calls, options).unwrap_err();
+                assert(*panic_data.at(0) == 'PANIC', *panic_data.at(0));
+
+                let calls = array![
+                    Call { to: contract_address, selector: selector!("get_balance"), calldata: array![].span() },
+                ];
+                let options = InvokeOptions { skip_signature_verification: true, ..Default::default() };
+                invoke_as_account(account, calls, options).unwrap();
+
+                // Nonce was incremented by the failed transaction
+                assert(ISimpleAccountDispatcher { contract_address: account }.get_last_nonce() == 1, 'wrong nonce');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn invoke_as_account_skipped_signature_verification_runs_validate() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, invoke_as_account, InvokeOptions};
+            use snforge_std::signature::KeyPairTrait;
+            use snforge_std::signature::stark_curve::{StarkCurveKeyPairImpl, StarkCurveSignerImpl};
+
+            #[test]
+            fn invoke_as_account_skipped_signature_verification_runs_validate() {
+                let key_pair = KeyPairTrait::<felt252, felt252>::generate();
+                let account_class = declare("SimpleAccount").unwrap().contract_class();
+                let (account, _) = account_class.deploy(@array![key_pair.public_key]).unwrap();
+
+                let transaction_hash = 'transaction hash';
+                let (r, s) = key_pair.sign(transaction_hash).unwrap();
+                let options = InvokeOptions {
+                    transaction_hash, signature: array![r, s], skip_signature_verification: true,
+                };
+                let panic_data = invoke_as_account(account, array![], options).unwrap_err();
+                assert(*panic_data.at(0) == 'no calls', *panic_data.at(0));
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
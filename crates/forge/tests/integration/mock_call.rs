@@ -1,7 +1,7 @@
 use forge_runner::forge_config::ForgeTrackedResource;
 use indoc::indoc;
 use std::path::Path;
-use test_utils::runner::{Contract, assert_passed};
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
 use test_utils::running_tests::run_test_case;
 use test_utils::test_case;
 
@@ -208,3 +208,129 @@ fn mock_calls() {
     let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
     assert_passed(&result);
 }
+
+#[test]
+fn mock_call_when_calldata() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use result::ResultTrait;
+            use starknet::syscalls::call_contract_syscall;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, start_mock_call_when, MockCalldata};
+
+            #[test]
+            fn mock_call_when_calldata() {
+                let calldata = array![420];
+                let contract = declare("MockChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@calldata).unwrap();
+                let selector = selector!("mocked_entry_point");
+
+                start_mock_call_when(contract_address, selector, MockCalldata::Any, 0);
+                start_mock_call_when(contract_address, selector, MockCalldata::Prefix(array![2]), 20);
+                start_mock_call_when(contract_address, selector, MockCalldata::Exact(array![1]), 10);
+
+                let result = call_contract_syscall(contract_address, selector, array![1].span()).unwrap();
+                assert(*result.at(0) == 10, 'exact mock not used');
+
+                let result = call_contract_syscall(contract_address, selector, array![2, 5].span()).unwrap();
+                assert(*result.at(0) == 20, 'prefix mock not used');
+
+                let result = call_contract_syscall(contract_address, selector, array![3].span()).unwrap();
+                assert(*result.at(0) == 0, 'any mock not used');
+
+                let result = call_contract_syscall(contract_address, selector, array![1, 1].span()).unwrap();
+                assert(*result.at(0) == 0, 'exact mock used for longer data');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_call_revert() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use result::ResultTrait;
+            use starknet::syscalls::call_contract_syscall;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, mock_call_revert, MockCalldata};
+
+            #[test]
+            fn mock_call_revert() {
+                let calldata = array![420];
+                let contract = declare("MockChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@calldata).unwrap();
+                let selector = selector!("get_thing");
+
+                mock_call_revert(contract_address, selector, MockCalldata::Any, array!['mocked error'], 1);
+
+                match call_contract_syscall(contract_address, selector, array![].span()) {
+                    Result::Ok(_) => panic!("call should revert"),
+                    Result::Err(panic_data) => {
+                        assert(*panic_data.at(0) == 'mocked error', *panic_data.at(0));
+                    }
+                }
+
+                let result = call_contract_syscall(contract_address, selector, array![].span()).unwrap();
+                assert(*result.at(0) == 420, 'mock not expired');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_call_revert_uncaught() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, start_mock_call_revert, MockCalldata};
+
+            #[starknet::interface]
+            trait IMockChecker<TContractState> {
+                fn get_thing(ref self: TContractState) -> felt252;
+            }
+
+            #[test]
+            fn mock_call_revert_uncaught() {
+                let calldata = array![420];
+                let contract = declare("MockChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@calldata).unwrap();
+                let dispatcher = IMockCheckerDispatcher { contract_address };
+
+                start_mock_call_revert(contract_address, selector!("get_thing"), MockCalldata::Any, array!['mocked error']);
+
+                dispatcher.get_thing();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "MockChecker".to_string(),
+            Path::new("tests/data/contracts/mock_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "mock_call_revert_uncaught", "mocked error");
+}
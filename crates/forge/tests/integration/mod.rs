@@ -1,3 +1,4 @@
+mod advance_block;
 mod available_gas;
 mod builtins;
 mod cheat_block_hash;
@@ -6,6 +7,7 @@ mod cheat_block_timestamp;
 mod cheat_caller_address;
 mod cheat_execution_info;
 mod cheat_fork;
+mod cheat_gas_prices;
 mod cheat_sequencer_address;
 mod declare;
 mod deploy;
@@ -13,11 +15,15 @@ mod deploy_at;
 mod dict;
 mod dispatchers;
 mod env;
+mod expect_call;
+mod expect_revert;
 mod fuzzing;
 mod gas;
 mod generate_random_felt;
 mod get_class_hash;
 mod interact_with_state;
+mod invariant;
+mod invoke_as_account;
 mod l1_handler_executor;
 mod message_to_l1;
 mod mock_call;
@@ -30,9 +36,13 @@ mod set_balance;
 mod setup_fork;
 mod should_panic;
 mod signing;
+mod spy_calls;
 mod spy_events;
+mod state_snapshot;
+mod storage_access;
 mod store_load;
 mod syscalls;
 mod test_state;
+mod timeout;
 mod too_many_events;
 mod trace;
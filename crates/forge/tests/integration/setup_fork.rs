@@ -7,7 +7,7 @@ use std::sync::Arc;
 
 use camino::Utf8PathBuf;
 use forge::block_number_map::BlockNumberMap;
-use forge::run_tests::package::run_for_package;
+use forge::run_tests::package::{prepare_package, run_for_package};
 use forge::scarb::config::ForkTarget;
 use forge::test_filter::TestsFilter;
 use tempfile::tempdir;
@@ -20,7 +20,8 @@ use forge::shared_cache::FailedTestsCache;
 use forge_runner::CACHE_DIR;
 use forge_runner::forge_config::ForgeTrackedResource;
 use forge_runner::forge_config::{
-    ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
+    ExecutionDataToSave, ForgeConfig, ForkMode, FuzzerCorpus, FuzzerStrategy, OutputConfig,
+    TestRunnerConfig,
 };
 use scarb_api::ScarbCommand;
 use scarb_api::metadata::MetadataCommandExt;
@@ -131,50 +132,55 @@ fn fork_aliased_decorator() {
         load_test_artifacts(&test.path().unwrap().join("target/dev"), package).unwrap();
 
     let ui = Arc::new(UI::default());
+    let args = RunForPackageArgs {
+        test_targets: raw_test_targets,
+        package_name: "test_package".to_string(),
+        tests_filter: TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            false,
+            false,
+            false,
+            None,
+            FailedTestsCache::default(),
+        ),
+        forge_config: Arc::new(ForgeConfig {
+            test_runner_config: Arc::new(TestRunnerConfig {
+                exit_first: false,
+                fuzzer_runs: NonZeroU32::new(256).unwrap(),
+                fuzzer_seed: 12345,
+                fuzzer_corpus: FuzzerCorpus::default(),
+                fuzzer_strategy: FuzzerStrategy::Random,
+                max_n_steps: None,
+                test_timeout: None,
+                is_vm_trace_needed: false,
+                gas_report: false,
+                cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
+                    .unwrap()
+                    .join(CACHE_DIR),
+                fork_mode: ForkMode::Online,
+                contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap()).unwrap(),
+                tracked_resource: ForgeTrackedResource::CairoSteps,
+                environment_variables: test.env().clone(),
+            }),
+            output_config: Arc::new(OutputConfig {
+                detailed_resources: false,
+                execution_data_to_save: ExecutionDataToSave::default(),
+            }),
+        }),
+        fork_targets: vec![ForkTarget {
+            name: "FORK_NAME_FROM_SCARB_TOML".to_string(),
+            url: node_rpc_url().as_str().parse().unwrap(),
+            block_id: BlockId::BlockTag,
+        }],
+    };
     let result = rt
-        .block_on(run_for_package(
-            RunForPackageArgs {
-                test_targets: raw_test_targets,
-                package_name: "test_package".to_string(),
-                tests_filter: TestsFilter::from_flags(
-                    None,
-                    false,
-                    Vec::new(),
-                    false,
-                    false,
-                    false,
-                    FailedTestsCache::default(),
-                ),
-                forge_config: Arc::new(ForgeConfig {
-                    test_runner_config: Arc::new(TestRunnerConfig {
-                        exit_first: false,
-                        fuzzer_runs: NonZeroU32::new(256).unwrap(),
-                        fuzzer_seed: 12345,
-                        max_n_steps: None,
-                        is_vm_trace_needed: false,
-                        cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
-                            .unwrap()
-                            .join(CACHE_DIR),
-                        contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap())
-                            .unwrap(),
-                        tracked_resource: ForgeTrackedResource::CairoSteps,
-                        environment_variables: test.env().clone(),
-                    }),
-                    output_config: Arc::new(OutputConfig {
-                        detailed_resources: false,
-                        execution_data_to_save: ExecutionDataToSave::default(),
-                    }),
-                }),
-                fork_targets: vec![ForkTarget {
-                    name: "FORK_NAME_FROM_SCARB_TOML".to_string(),
-                    url: node_rpc_url().as_str().parse().unwrap(),
-                    block_id: BlockId::BlockTag,
-                }],
-            },
-            &mut BlockNumberMap::default(),
-            Option::default(),
-            ui,
-        ))
+        .block_on(async {
+            let prepared_package =
+                prepare_package(args, &mut BlockNumberMap::default(), ui.clone()).await?;
+            run_for_package(prepared_package, Option::default(), ui).await
+        })
         .expect("Runner fail")
         .summaries();
 
@@ -223,50 +229,55 @@ fn fork_aliased_decorator_overrding() {
         load_test_artifacts(&test.path().unwrap().join("target/dev"), package).unwrap();
 
     let ui = Arc::new(UI::default());
+    let args = RunForPackageArgs {
+        test_targets: raw_test_targets,
+        package_name: "test_package".to_string(),
+        tests_filter: TestsFilter::from_flags(
+            None,
+            false,
+            Vec::new(),
+            false,
+            false,
+            false,
+            None,
+            FailedTestsCache::default(),
+        ),
+        forge_config: Arc::new(ForgeConfig {
+            test_runner_config: Arc::new(TestRunnerConfig {
+                exit_first: false,
+                fuzzer_runs: NonZeroU32::new(256).unwrap(),
+                fuzzer_seed: 12345,
+                fuzzer_corpus: FuzzerCorpus::default(),
+                fuzzer_strategy: FuzzerStrategy::Random,
+                max_n_steps: None,
+                test_timeout: None,
+                is_vm_trace_needed: false,
+                gas_report: false,
+                cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
+                    .unwrap()
+                    .join(CACHE_DIR),
+                fork_mode: ForkMode::Online,
+                contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap()).unwrap(),
+                tracked_resource: ForgeTrackedResource::CairoSteps,
+                environment_variables: test.env().clone(),
+            }),
+            output_config: Arc::new(OutputConfig {
+                detailed_resources: false,
+                execution_data_to_save: ExecutionDataToSave::default(),
+            }),
+        }),
+        fork_targets: vec![ForkTarget {
+            name: "FORK_NAME_FROM_SCARB_TOML".to_string(),
+            url: node_rpc_url().as_str().parse().unwrap(),
+            block_id: BlockId::BlockNumber(12_341_234),
+        }],
+    };
     let result = rt
-        .block_on(run_for_package(
-            RunForPackageArgs {
-                test_targets: raw_test_targets,
-                package_name: "test_package".to_string(),
-                tests_filter: TestsFilter::from_flags(
-                    None,
-                    false,
-                    Vec::new(),
-                    false,
-                    false,
-                    false,
-                    FailedTestsCache::default(),
-                ),
-                forge_config: Arc::new(ForgeConfig {
-                    test_runner_config: Arc::new(TestRunnerConfig {
-                        exit_first: false,
-                        fuzzer_runs: NonZeroU32::new(256).unwrap(),
-                        fuzzer_seed: 12345,
-                        max_n_steps: None,
-                        is_vm_trace_needed: false,
-                        cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().keep())
-                            .unwrap()
-                            .join(CACHE_DIR),
-                        contracts_data: ContractsData::try_from(test.contracts(&ui).unwrap())
-                            .unwrap(),
-                        tracked_resource: ForgeTrackedResource::CairoSteps,
-                        environment_variables: test.env().clone(),
-                    }),
-                    output_config: Arc::new(OutputConfig {
-                        detailed_resources: false,
-                        execution_data_to_save: ExecutionDataToSave::default(),
-                    }),
-                }),
-                fork_targets: vec![ForkTarget {
-                    name: "FORK_NAME_FROM_SCARB_TOML".to_string(),
-                    url: node_rpc_url().as_str().parse().unwrap(),
-                    block_id: BlockId::BlockNumber(12_341_234),
-                }],
-            },
-            &mut BlockNumberMap::default(),
-            Option::default(),
-            ui,
-        ))
+        .block_on(async {
+            let prepared_package =
+                prepare_package(args, &mut BlockNumberMap::default(), ui.clone()).await?;
+            run_for_package(prepared_package, Option::default(), ui).await
+        })
         .expect("Runner fail")
         .summaries();
 
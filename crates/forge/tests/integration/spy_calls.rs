@@ -0,0 +1,149 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn spy_calls_regular_and_library_calls() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::{ContractAddress, ClassHash};
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_calls, CallSpyTrait,
+                CallsFilterTrait, CallSpyAssertionsTrait, SpiedCall, test_address
+            };
+            use snforge_std::trace::{CallResult, CallType};
+
+            #[starknet::interface]
+            trait ITraceInfoProxy<T> {
+                fn with_libcall(self: @T, class_hash: ClassHash) -> felt252;
+                fn regular_call(self: @T, contract_address: ContractAddress) -> felt252;
+            }
+
+            #[test]
+            fn spy_calls_regular_and_library_calls() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                let (proxy_address, _) = proxy.deploy(@array![checker_address.into()]).unwrap();
+                let dispatcher = ITraceInfoProxyDispatcher { contract_address: proxy_address };
+
+                let mut spy = spy_calls();
+
+                dispatcher.with_libcall(*checker.class_hash);
+                dispatcher.regular_call(checker_address);
+
+                let calls = spy.get_calls();
+                assert(calls.calls.len() == 4, 'wrong number of calls');
+
+                let library_calls = calls.library_calls_to(*checker.class_hash);
+                assert(
+                    library_calls.calls == array![
+                        SpiedCall {
+                            caller_address: test_address(),
+                            contract_address: proxy_address,
+                            class_hash: Option::Some(*checker.class_hash),
+                            entry_point_selector: selector!("from_proxy"),
+                            calldata: array![3],
+                            call_type: CallType::Delegate,
+                            result: CallResult::Success(array![103]),
+                        },
+                    ],
+                    'wrong library calls',
+                );
+
+                let proxy_calls = calls.made_by(proxy_address);
+                assert(
+                    proxy_calls.calls == array![
+                        SpiedCall {
+                            caller_address: proxy_address,
+                            contract_address: checker_address,
+                            class_hash: Option::None,
+                            entry_point_selector: selector!("from_proxy"),
+                            calldata: array![2],
+                            call_type: CallType::Call,
+                            result: CallResult::Success(array![102]),
+                        },
+                    ],
+                    'wrong calls made by proxy',
+                );
+
+                spy.assert_called(checker_address, selector!("from_proxy"), array![2].span());
+                spy.assert_not_called(checker_address, selector!("from_proxy"), array![1].span());
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "TraceInfoProxy".to_string(),
+            Path::new("tests/data/contracts/trace_info_proxy.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "TraceInfoChecker".to_string(),
+            Path::new("tests/data/contracts/trace_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn spy_calls_assert_called_fails() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_calls, CallSpyAssertionsTrait
+            };
+
+            #[starknet::interface]
+            trait ITraceInfoProxy<T> {
+                fn regular_call(self: @T, contract_address: ContractAddress) -> felt252;
+            }
+
+            #[test]
+            fn spy_calls_assert_called_fails() {
+                let checker = declare("TraceInfoChecker").unwrap().contract_class();
+                let (checker_address, _) = checker.deploy(@array![]).unwrap();
+
+                let proxy = declare("TraceInfoProxy").unwrap().contract_class();
+                let (proxy_address, _) = proxy.deploy(@array![checker_address.into()]).unwrap();
+
+                let mut spy = spy_calls();
+
+                ITraceInfoProxyDispatcher { contract_address: proxy_address }
+                    .regular_call(checker_address);
+
+                spy.assert_called(checker_address, selector!("from_proxy"), array![5].span());
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "TraceInfoProxy".to_string(),
+            Path::new("tests/data/contracts/trace_info_proxy.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "TraceInfoChecker".to_string(),
+            Path::new("tests/data/contracts/trace_info_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "spy_calls_assert_called_fails",
+        "Call with matching selector and calldata was not made to",
+    );
+}
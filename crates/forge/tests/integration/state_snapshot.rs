@@ -0,0 +1,328 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn revert_storage_and_deployments() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                get_class_hash
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn revert_storage_and_deployments() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+                dispatcher.increase_balance(100);
+
+                let snapshot = snapshot_state();
+
+                dispatcher.increase_balance(50);
+                let (second_address, _) = contract.deploy(@array![]).unwrap();
+                assert(dispatcher.get_balance() == 150, 'wrong balance before revert');
+
+                revert_to_snapshot(snapshot);
+
+                assert(dispatcher.get_balance() == 100, 'storage not reverted');
+                let second_class_hash: felt252 = get_class_hash(second_address).into();
+                assert(second_class_hash == 0, 'deploy not reverted');
+
+                dispatcher.increase_balance(1);
+                assert(dispatcher.get_balance() == 101, 'wrong balance after revert');
+
+                revert_to_snapshot(snapshot);
+
+                assert(dispatcher.get_balance() == 100, 'second revert failed');
+                let (address_after_revert, _) = contract.deploy(@array![]).unwrap();
+                assert(address_after_revert == second_address, 'wrong address after revert');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_cheats_and_mocks() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                start_cheat_block_number, start_mock_call
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockNumberChecker<TContractState> {
+                fn get_block_number(ref self: TContractState) -> u64;
+            }
+
+            #[test]
+            fn revert_cheats_and_mocks() {
+                let contract = declare("CheatBlockNumberChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = ICheatBlockNumberCheckerDispatcher { contract_address };
+
+                start_cheat_block_number(contract_address, 123);
+                let snapshot = snapshot_state();
+
+                start_cheat_block_number(contract_address, 456);
+                assert(dispatcher.get_block_number() == 456, 'wrong block number');
+                start_mock_call(contract_address, selector!("get_block_number"), 789_u64);
+                assert(dispatcher.get_block_number() == 789, 'call not mocked');
+
+                revert_to_snapshot(snapshot);
+
+                assert(dispatcher.get_block_number() == 123, 'cheats not reverted');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockNumberChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_number_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_spied_events() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                spy_events, EventSpyTrait
+            };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+            }
+
+            #[test]
+            fn revert_spied_events() {
+                let contract = declare("SpyEventsChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = ISpyEventsCheckerDispatcher { contract_address };
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(1);
+                let snapshot = snapshot_state();
+
+                dispatcher.emit_one_event(2);
+                let mut later_spy = spy_events();
+                dispatcher.emit_one_event(3);
+                assert(spy.get_events().events.len() == 3, 'wrong events before revert');
+
+                revert_to_snapshot(snapshot);
+
+                assert(spy.get_events().events.len() == 1, 'events not reverted');
+                assert(later_spy.get_events().events.len() == 0, 'later spy not reverted');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_to_nonexistent_snapshot() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::{snapshot_state, revert_to_snapshot, SnapshotId};
+
+            #[test]
+            fn revert_to_nonexistent_snapshot() {
+                let snapshot = snapshot_state();
+                revert_to_snapshot(snapshot);
+
+                let mut data = array![];
+                snapshot.serialize(ref data);
+                let mut invalid_data = array![*data.at(0) + 1].span();
+                let invalid_snapshot: SnapshotId = Serde::deserialize(ref invalid_data).unwrap();
+                revert_to_snapshot(invalid_snapshot);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "revert_to_nonexistent_snapshot",
+        "Snapshot with id = 1 does not exist",
+    );
+}
+
+#[test]
+fn revert_expected_calls() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                expect_call
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn revert_expected_calls() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+
+                let snapshot = snapshot_state();
+                expect_call(contract_address, selector!("increase_balance"), array![5], 1);
+
+                revert_to_snapshot(snapshot);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_expected_revert() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                expect_revert
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn revert_expected_revert() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                let snapshot = snapshot_state();
+                expect_revert(array!['capybara']);
+
+                revert_to_snapshot(snapshot);
+
+                dispatcher.increase_balance(5);
+                assert(dispatcher.get_balance() == 5, 'wrong balance');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn revert_storage_accesses() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, snapshot_state, revert_to_snapshot,
+                start_record_storage_access, stop_record_storage_access, get_storage_accesses
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn revert_storage_accesses() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                start_record_storage_access(contract_address);
+                dispatcher.get_balance();
+                let snapshot = snapshot_state();
+
+                dispatcher.increase_balance(5);
+                stop_record_storage_access(contract_address);
+
+                revert_to_snapshot(snapshot);
+
+                let (_, storage_accesses) = get_storage_accesses().pop_front().unwrap();
+                assert(storage_accesses.reads == array![selector!("balance")], 'reads not reverted');
+                assert(storage_accesses.writes == array![], 'writes not reverted');
+
+                dispatcher.increase_balance(5);
+
+                let (_, storage_accesses) = get_storage_accesses().pop_front().unwrap();
+                assert(storage_accesses.writes == array![selector!("balance")], 'recording not reverted');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
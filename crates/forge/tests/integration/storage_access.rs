@@ -0,0 +1,117 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn record_storage_access() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, start_record_storage_access,
+                stop_record_storage_access, get_storage_accesses
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn record_storage_access() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let (other_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                start_record_storage_access(contract_address);
+
+                dispatcher.get_balance();
+                IHelloStarknetDispatcher { contract_address: other_address }.increase_balance(1);
+
+                let mut accesses = get_storage_accesses();
+                assert(accesses.len() == 1, 'wrong number of contracts');
+                let (address, storage_accesses) = accesses.pop_front().unwrap();
+                assert(address == contract_address, 'wrong contract');
+                assert(storage_accesses.reads == array![selector!("balance")], 'wrong reads');
+                assert(storage_accesses.writes == array![], 'wrong writes');
+
+                dispatcher.increase_balance(5);
+                stop_record_storage_access(contract_address);
+
+                let (_, storage_accesses) = get_storage_accesses().pop_front().unwrap();
+                assert(storage_accesses.reads == array![selector!("balance")], 'reads not deduplicated');
+                assert(storage_accesses.writes == array![selector!("balance")], 'write not recorded');
+
+                dispatcher.increase_balance(5);
+                start_record_storage_access(contract_address);
+
+                let (_, storage_accesses) = get_storage_accesses().pop_front().unwrap();
+                assert(storage_accesses.reads == array![], 'accesses not cleared');
+                assert(storage_accesses.writes == array![], 'accesses not cleared');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn record_storage_access_map_entries() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, start_record_storage_access,
+                get_storage_accesses, map_entry_address, load,
+            };
+
+            #[starknet::interface]
+            trait IStorageTester<TContractState> {
+                fn insert_felt_to_felt(ref self: TContractState, key: felt252, value: felt252);
+            }
+
+            #[test]
+            fn record_storage_access_map_entries() {
+                let contract = declare("StorageTester").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let dispatcher = IStorageTesterDispatcher { contract_address };
+
+                start_record_storage_access(contract_address);
+
+                dispatcher.insert_felt_to_felt(1, 10);
+                dispatcher.insert_felt_to_felt(2, 20);
+
+                let (_, storage_accesses) = get_storage_accesses().pop_front().unwrap();
+                let first_key = map_entry_address(selector!("felt_to_felt"), array![1].span());
+                let second_key = map_entry_address(selector!("felt_to_felt"), array![2].span());
+                assert(storage_accesses.writes == array![first_key, second_key], 'wrong writes');
+
+                let value = load(contract_address, *storage_accesses.writes.at(1), 1);
+                assert(value == array![20], 'wrong value');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "StorageTester".to_string(),
+            Path::new("tests/data/contracts/storage_tester.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
@@ -599,3 +599,131 @@ fn fork_store_load() {
 
     assert_passed(&result);
 }
+
+#[test]
+fn store_load_at_path() {
+    let test = test_utils::test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, load_at_path, store_at_path,
+                storage_path_address, map_entry_address
+            };
+
+            #[derive(Drop, Serde, PartialEq, Debug, starknet::Store)]
+            struct Balance {
+                amount: u256,
+                locked: bool,
+            }
+
+            #[starknet::interface]
+            trait IStoragePaths<TContractState> {
+                fn set_balance(
+                    ref self: TContractState, account: ContractAddress, amount: u256, locked: bool,
+                );
+                fn get_balance(self: @TContractState, account: ContractAddress) -> Balance;
+                fn get_nonce(self: @TContractState, account: ContractAddress, key: felt252) -> u64;
+                fn add_owner(ref self: TContractState, owner: ContractAddress);
+                fn get_owner(self: @TContractState, index: u64) -> ContractAddress;
+                fn get_owners_count(self: @TContractState) -> u64;
+            }
+
+            fn deploy_contract() -> IStoragePathsDispatcher {
+                let contract = declare("StoragePaths").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                IStoragePathsDispatcher { contract_address }
+            }
+
+            #[test]
+            fn load_storage_node_member() {
+                let dispatcher = deploy_contract();
+                let account: ContractAddress = 0x123.try_into().unwrap();
+                dispatcher.set_balance(account, 1000, true);
+
+                let balance: Balance = load_at_path(
+                    dispatcher.contract_address, "accounts[0x123].balance",
+                );
+                assert(balance == Balance { amount: 1000, locked: true }, 'wrong balance');
+            }
+
+            #[test]
+            fn store_storage_node_member() {
+                let dispatcher = deploy_contract();
+                let account: ContractAddress = 0x123.try_into().unwrap();
+
+                store_at_path(dispatcher.contract_address, "accounts[291].nonces[7]", 5_u64);
+                assert(dispatcher.get_nonce(account, 7) == 5, 'wrong nonce');
+
+                store_at_path(
+                    dispatcher.contract_address,
+                    "accounts[0x123].balance",
+                    Balance { amount: 10, locked: false },
+                );
+                assert(
+                    dispatcher.get_balance(account) == Balance { amount: 10, locked: false },
+                    'wrong balance',
+                );
+            }
+
+            #[test]
+            fn load_store_vec() {
+                let dispatcher = deploy_contract();
+                dispatcher.add_owner(0x1.try_into().unwrap());
+                dispatcher.add_owner(0x2.try_into().unwrap());
+
+                let len: u64 = load_at_path(dispatcher.contract_address, "owners");
+                assert(len == 2, 'wrong length');
+                let owner: ContractAddress = load_at_path(dispatcher.contract_address, "owners[1]");
+                assert(owner == 0x2.try_into().unwrap(), 'wrong owner');
+
+                let new_owner: ContractAddress = 0x3.try_into().unwrap();
+                store_at_path(dispatcher.contract_address, "owners[0]", new_owner);
+                assert(dispatcher.get_owner(0) == new_owner, 'wrong owner stored');
+            }
+
+            #[test]
+            fn path_address_of_map_entry() {
+                assert(
+                    storage_path_address("values[1, 0x2]") == map_entry_address(
+                        selector!("values"), array![1, 2].span(),
+                    ),
+                    'wrong address',
+                );
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "StoragePaths".to_string(),
+            Path::new("tests/data/contracts/storage_paths.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn store_load_at_invalid_path() {
+    let test = test_utils::test_case!(indoc!(
+        r#"
+            use snforge_std::storage_path_address;
+
+            #[test]
+            fn invalid_path() {
+                storage_path_address("balances[0x123");
+            }
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "invalid_path",
+        "Invalid storage path `balances[0x123`: missing closing `]`",
+    );
+}
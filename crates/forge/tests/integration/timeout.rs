@@ -0,0 +1,72 @@
+use forge_runner::forge_config::ForgeTrackedResource;
+use indoc::{formatdoc, indoc};
+use std::net::TcpListener;
+use test_utils::runner::{TestCase, assert_case_output_contains, assert_timed_out};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn timeout_while_blocked_on_fork_request() {
+    // Accepts connections, but never responds to the requests sent by the fork client
+    let unresponsive_node = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/rpc", unresponsive_node.local_addr().unwrap());
+
+    let test = test_case!(formatdoc!(
+        r#"
+            #[test]
+            #[timeout(1)]
+            #[fork(url: "{url}", block_number: 1)]
+            fn blocked_on_fork_request() {{
+                starknet::get_block_info();
+            }}
+        "#
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+
+    assert_timed_out(&result);
+    assert_case_output_contains(
+        &result,
+        "blocked_on_fork_request",
+        "Test did not finish within the timeout of 1s",
+    );
+}
+
+#[test]
+fn timeout_while_executing_steps() {
+    let test = test_case!(indoc!(
+        r"
+            #[test]
+            #[timeout(1)]
+            fn infinite_loop() {
+                let mut i: felt252 = 0;
+                loop {
+                    i += 1;
+                }
+            }
+
+            #[test]
+            fn finishes() {
+                assert(1 + 1 == 2, 'wrong sum');
+            }
+        "
+    ));
+
+    let result = run_test_case(&test, ForgeTrackedResource::CairoSteps);
+    let summaries = &TestCase::find_test_result(&result).test_case_summaries;
+    let summary = |test_name: &str| {
+        summaries
+            .iter()
+            .find(|summary| summary.name().unwrap().ends_with(test_name))
+            .unwrap()
+    };
+
+    // The loop is stopped by the runtime once the timeout is reached, so it doesn't block other tests
+    assert!(summary("::infinite_loop").is_timed_out());
+    assert!(summary("::finishes").is_passed());
+    assert_case_output_contains(
+        &result,
+        "infinite_loop",
+        "Test did not finish within the timeout of 1s",
+    );
+}
@@ -28,6 +28,12 @@ impl UI {
         Self { output_format }
     }
 
+    /// Output format configured for this instance.
+    #[must_use]
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+
     /// Create a [`String`] representation of the given message based on the configured output format.
     fn format_message(&self, message: &impl Message) -> String {
         match self.output_format {
@@ -41,13 +47,24 @@ impl UI {
         println!("{}", self.format_message(message));
     }
 
+    /// Print the given message to stdout only if the output format is JSON.
+    ///
+    /// Used for events that are useful for tools consuming the output, but too noisy for humans.
+    pub fn println_json_only(&self, message: &impl Message) {
+        if self.output_format == OutputFormat::Json {
+            self.println(message);
+        }
+    }
+
     /// Print the given message to stderr using the configured output format.
     pub fn eprintln(&self, message: &impl Message) {
         eprintln!("{}", self.format_message(message));
     }
 
-    /// Print a blank line to stdout.
+    /// Print a blank line to stdout, unless the output format is JSON.
     pub fn print_blank_line(&self) {
-        println!();
+        if self.output_format == OutputFormat::Human {
+            println!();
+        }
     }
 }
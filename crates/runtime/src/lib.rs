@@ -365,7 +365,7 @@ impl<Extension: ExtensionLogic> SyscallPtrAccess for ExtendedRuntime<Extension>
 
 impl<Extension: ExtensionLogic> ResourceTracker for ExtendedRuntime<Extension> {
     fn consumed(&self) -> bool {
-        self.extended_runtime.consumed()
+        self.extension.is_interrupted() || self.extended_runtime.consumed()
     }
 
     fn consume_step(&mut self) {
@@ -443,6 +443,11 @@ pub trait ExtensionLogic {
         _extended_runtime: &mut Self::Runtime,
     ) {
     }
+
+    /// Checked before every step of the VM, execution stops with an error once it returns `true`
+    fn is_interrupted(&self) -> bool {
+        false
+    }
 }
 
 // All errors that can be thrown from the hint executor have to be added here,
@@ -8,8 +8,10 @@ pub mod fork;
 pub mod fuzzer;
 pub mod ignore;
 pub mod internal_config_statement;
+pub mod invariant;
 pub mod should_panic;
 pub mod test;
+pub mod timeout;
 
 pub trait AttributeInfo {
     const ATTR_NAME: &'static str;
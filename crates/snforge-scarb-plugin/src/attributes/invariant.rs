@@ -0,0 +1,106 @@
+use super::{AttributeCollector, AttributeInfo, AttributeTypeData, ErrorExt};
+use crate::args::Arguments;
+use crate::asserts::assert_is_used_once;
+use crate::attributes::invariant::wrapper::InvariantWrapperCollector;
+use crate::cairo_expression::CairoExpression;
+use crate::common::into_proc_macro_result;
+use crate::config_statement::extend_with_config_cheatcodes;
+use crate::parse::parse;
+use crate::types::{Number, ParseFromExpr};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_utils::Upcast;
+use indoc::formatdoc;
+use num_bigint::BigInt;
+
+pub mod wrapper;
+
+pub struct InvariantConfigCollector;
+
+impl AttributeInfo for InvariantConfigCollector {
+    const ATTR_NAME: &'static str = "__invariant_config";
+}
+
+pub struct InvariantCollector;
+
+impl AttributeInfo for InvariantCollector {
+    const ATTR_NAME: &'static str = "invariant";
+}
+
+impl AttributeTypeData for InvariantCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_invariant";
+}
+
+impl AttributeCollector for InvariantCollector {
+    fn args_into_config_expression(
+        db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        let named_args = args.named_only::<Self>()?;
+
+        let seed = named_args
+            .as_once_optional("seed")?
+            .map(|arg| Number::parse_from_expr::<Self>(db, arg, "seed"))
+            .transpose()?;
+
+        let runs = named_args
+            .as_once_optional("runs")?
+            .map(|arg| Number::parse_from_expr::<Self>(db, arg, "runs"))
+            .transpose()?;
+
+        if let Some(Number(ref runs)) = runs {
+            if runs <= &BigInt::from(0) {
+                Err(Self::error("runs must be greater than 0"))?;
+            }
+        }
+
+        let seed = seed.as_cairo_expression();
+        let runs = runs.as_cairo_expression();
+
+        Ok(format!(
+            "snforge_std::_internals::config_types::InvariantConfig {{ seed: {seed}, runs: {runs} }}"
+        ))
+    }
+}
+
+#[must_use]
+pub fn invariant(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, invariant_internal)
+}
+
+#[must_use]
+pub fn invariant_config(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<InvariantCollector>(args, item)
+}
+
+#[expect(clippy::ptr_arg)]
+fn invariant_internal(
+    args: &TokenStream,
+    item: &TokenStream,
+    _warns: &mut Vec<Diagnostic>,
+) -> Result<String, Diagnostics> {
+    let item = item.to_string();
+    let (db, func) = parse::<InvariantCollector>(&item)?;
+    let db = db.upcast();
+
+    assert_is_used_once::<InvariantCollector>(db, &func)?;
+
+    let attrs = func.attributes(db).as_syntax_node().get_text(db);
+    let body = func.body(db).as_syntax_node().get_text(db);
+    let declaration = func.declaration(db).as_syntax_node().get_text(db);
+    let args = args.to_string();
+
+    // Both attributes get all arguments and read only the ones they need
+    Ok(formatdoc!(
+        "
+            {attrs}
+            #[{}{args}]
+            #[{}{args}]
+            {declaration} {body}
+        ",
+        InvariantConfigCollector::ATTR_NAME,
+        InvariantWrapperCollector::ATTR_NAME
+    ))
+}
@@ -0,0 +1,141 @@
+use crate::args::Arguments;
+use crate::attributes::internal_config_statement::InternalConfigStatementCollector;
+use crate::attributes::invariant::InvariantCollector;
+use crate::attributes::test::TestCollector;
+use crate::attributes::{AttributeInfo, ErrorExt};
+use crate::cairo_expression::CairoExpression;
+use crate::common::{into_proc_macro_result, with_parsed_values};
+use crate::types::{Number, ParseFromExpr};
+use crate::utils::{get_statements, TypedSyntaxNodeAsText};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::ast::{Expr, FunctionWithBody};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use indoc::formatdoc;
+use num_bigint::BigInt;
+
+/// Number of calls made to the target contract in a single run when `depth` is not specified.
+const DEFAULT_DEPTH: u32 = 15;
+
+pub struct InvariantWrapperCollector;
+
+impl AttributeInfo for InvariantWrapperCollector {
+    const ATTR_NAME: &'static str = "__invariant_wrapper";
+}
+
+#[must_use]
+pub fn invariant_wrapper(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    into_proc_macro_result(args, item, |args, item, warns| {
+        with_parsed_values::<InvariantWrapperCollector>(
+            args,
+            item,
+            warns,
+            invariant_wrapper_internal,
+        )
+    })
+}
+
+#[expect(clippy::ptr_arg)]
+#[expect(clippy::needless_pass_by_value)]
+fn invariant_wrapper_internal(
+    db: &dyn SyntaxGroup,
+    func: &FunctionWithBody,
+    args_db: &dyn SyntaxGroup,
+    args: Arguments,
+    _warns: &mut Vec<Diagnostic>,
+) -> Result<String, Diagnostics> {
+    let named_args = args.named_only::<InvariantCollector>()?;
+
+    let setup = match named_args.as_once("target")? {
+        Expr::Path(path) => path.as_text(args_db),
+        _ => Err(InvariantCollector::error(
+            "<target> argument must be a name of a function deploying the target contract",
+        ))?,
+    };
+
+    let depth = named_args
+        .as_once_optional("depth")?
+        .map(|arg| Number::parse_from_expr::<InvariantCollector>(args_db, arg, "depth"))
+        .transpose()?
+        .unwrap_or(Number(DEFAULT_DEPTH.into()));
+
+    if depth.0 <= BigInt::from(0) {
+        Err(InvariantCollector::error("depth must be greater than 0"))?;
+    }
+
+    let parameters = func
+        .declaration(db)
+        .signature(db)
+        .parameters(db)
+        .elements(db);
+    let [target] = parameters.as_slice() else {
+        return Err(InvariantCollector::error(
+            "function must have a single parameter with the target contract address",
+        )
+        .into());
+    };
+    let target = target.name(db).as_text(db);
+
+    let attr_list = func.attributes(db);
+    let test_or_executable_attrs =
+        if let Some(test_attr) = attr_list.find_attr(db, TestCollector::ATTR_NAME) {
+            vec![test_attr]
+        } else {
+            attr_list.query_attr(db, InternalConfigStatementCollector::ATTR_NAME)
+        };
+
+    let actual_body_fn_attrs = attr_list
+        .elements(db)
+        .into_iter()
+        .filter(|attr| !test_or_executable_attrs.contains(attr))
+        .map(|attr| attr.as_text(db))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let test_or_executable_attrs = test_or_executable_attrs
+        .iter()
+        .map(|attr| attr.as_text(db))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let vis = func.visibility(db).as_text(db);
+    let name = func.declaration(db).name(db).as_text(db);
+    let signature = func.declaration(db).signature(db).as_text(db);
+    let depth = depth.as_cairo_expression();
+
+    let internal_config_attr = InternalConfigStatementCollector::ATTR_NAME;
+    let actual_body_fn_name = format!("{name}_actual_body");
+
+    let (statements, if_content) = get_statements(db, func);
+
+    // The invariant is checked after the setup and after every call made to the target contract
+    Ok(formatdoc!(
+        "
+            {test_or_executable_attrs}
+            {vis} fn {name}() {{
+                if snforge_std::_internals::is_config_run() {{
+                    {if_content}
+
+                    {actual_body_fn_name}(core::num::traits::Zero::zero());
+
+                    return;
+                }}
+                let {target} = {setup}();
+                {actual_body_fn_name}({target});
+
+                let mut step: u32 = 0;
+                while step != {depth} {{
+                    snforge_std::_internals::invariant_call({target});
+                    {actual_body_fn_name}({target});
+                    step += 1;
+                };
+            }}
+
+            {actual_body_fn_attrs}
+            #[{internal_config_attr}]
+            fn {actual_body_fn_name}{signature} {{
+                {statements}
+            }}
+        "
+    ))
+}
@@ -1,6 +1,8 @@
 use super::{internal_config_statement::InternalConfigStatementCollector, AttributeInfo, ErrorExt};
 use crate::attributes::fuzzer::wrapper::FuzzerWrapperCollector;
 use crate::attributes::fuzzer::{FuzzerCollector, FuzzerConfigCollector};
+use crate::attributes::invariant::wrapper::InvariantWrapperCollector;
+use crate::attributes::invariant::{InvariantCollector, InvariantConfigCollector};
 use crate::utils::TypedSyntaxNodeAsText;
 use crate::{
     args::Arguments,
@@ -117,10 +119,14 @@ fn has_parameters(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
 }
 
 fn no_fuzzer_attribute(db: &dyn SyntaxGroup, func: &FunctionWithBody) -> bool {
-    const FUZZER_ATTRIBUTES: [&str; 3] = [
+    // Invariant tests take the address of the target contract created by the wrapper
+    const FUZZER_ATTRIBUTES: [&str; 6] = [
         FuzzerCollector::ATTR_NAME,
         FuzzerWrapperCollector::ATTR_NAME,
         FuzzerConfigCollector::ATTR_NAME,
+        InvariantCollector::ATTR_NAME,
+        InvariantWrapperCollector::ATTR_NAME,
+        InvariantConfigCollector::ATTR_NAME,
     ];
 
     func.attributes(db)
@@ -0,0 +1,48 @@
+use crate::{
+    args::Arguments,
+    attributes::{AttributeCollector, AttributeInfo, AttributeTypeData, ErrorExt},
+    cairo_expression::CairoExpression,
+    config_statement::extend_with_config_cheatcodes,
+    types::{Number, ParseFromExpr},
+};
+use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, TokenStream};
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use num_bigint::BigInt;
+
+pub struct TimeoutCollector;
+
+impl AttributeInfo for TimeoutCollector {
+    const ATTR_NAME: &'static str = "timeout";
+}
+
+impl AttributeTypeData for TimeoutCollector {
+    const CHEATCODE_NAME: &'static str = "set_config_timeout";
+}
+
+impl AttributeCollector for TimeoutCollector {
+    fn args_into_config_expression(
+        db: &dyn SyntaxGroup,
+        args: Arguments,
+        _warns: &mut Vec<Diagnostic>,
+    ) -> Result<String, Diagnostics> {
+        let &[arg] = args.unnamed_only::<Self>()?.of_length::<1, Self>()?;
+
+        let seconds = Number::parse_from_expr::<Self>(db, arg.1, arg.0.to_string().as_str())?;
+
+        if seconds.0 <= BigInt::from(0) {
+            Err(Self::error("seconds must be greater than 0"))?;
+        }
+        seconds.validate_in_gas_range::<Self>("seconds")?;
+
+        let seconds = seconds.as_cairo_expression();
+
+        Ok(format!(
+            "snforge_std::_internals::config_types::TimeoutConfig {{ seconds: {seconds} }}"
+        ))
+    }
+}
+
+#[must_use]
+pub fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    extend_with_config_cheatcodes::<TimeoutCollector>(args, item)
+}
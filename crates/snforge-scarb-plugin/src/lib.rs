@@ -1,10 +1,11 @@
 #![warn(rust_2024_compatibility)]
-use attributes::fuzzer;
 use attributes::{
     available_gas::available_gas, disable_predeployed_contracts::disable_predeployed_contracts,
     fork::fork, fuzzer::fuzzer, ignore::ignore,
-    internal_config_statement::internal_config_statement, should_panic::should_panic, test::test,
+    internal_config_statement::internal_config_statement, invariant::invariant,
+    should_panic::should_panic, test::test, timeout::timeout,
 };
+use attributes::{fuzzer, invariant};
 use cairo_lang_macro::{attribute_macro, executable_attribute, ProcMacroResult, TokenStream};
 
 mod args;
@@ -34,6 +35,16 @@ fn __fuzzer_wrapper(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     fuzzer::wrapper::fuzzer_wrapper(args, item)
 }
 
+#[attribute_macro]
+fn __invariant_config(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    invariant::invariant_config(args, item)
+}
+
+#[attribute_macro]
+fn __invariant_wrapper(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    invariant::wrapper::invariant_wrapper(args, item)
+}
+
 #[attribute_macro]
 fn test(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     test(args, item)
@@ -49,6 +60,11 @@ fn fuzzer(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     fuzzer(args, item)
 }
 
+#[attribute_macro]
+fn invariant(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    invariant(args, item)
+}
+
 #[attribute_macro]
 fn fork(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     fork(args, item)
@@ -68,3 +84,8 @@ fn should_panic(args: TokenStream, item: TokenStream) -> ProcMacroResult {
 fn disable_predeployed_contracts(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     disable_predeployed_contracts(args, item)
 }
+
+#[attribute_macro]
+fn timeout(args: TokenStream, item: TokenStream) -> ProcMacroResult {
+    timeout(args, item)
+}
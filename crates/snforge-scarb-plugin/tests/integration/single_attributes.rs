@@ -4,5 +4,7 @@ mod fork;
 mod fuzzer;
 mod ignore;
 mod internal_config_statement;
+mod invariant;
 mod should_panic;
 mod test;
+mod timeout;
@@ -0,0 +1,190 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use snforge_scarb_plugin::attributes::invariant::wrapper::invariant_wrapper;
+use snforge_scarb_plugin::attributes::invariant::{invariant, invariant_config};
+
+const FN_WITH_TARGET_PARAM: &str = "fn check(target: ContractAddress) { assert(true, 'ok'); }";
+
+#[test]
+fn work_with_args() {
+    let item = TokenStream::new(FN_WITH_TARGET_PARAM.into());
+    let args = TokenStream::new("(target: setup, runs: 20, depth: 5)".into());
+
+    let result = invariant(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            #[__invariant_config(target: setup, runs: 20, depth: 5)]
+            #[__invariant_wrapper(target: setup, runs: 20, depth: 5)]
+            fn check(target: ContractAddress) { assert(true, 'ok'); }
+        ",
+    );
+}
+
+#[test]
+fn config_works_with_both_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(target: setup, runs: 655, seed: 32872357)".into());
+
+    let result = invariant_config(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_internals::config_types::InvariantConfig {
+                        seed: Option::Some(0x1f597a5),
+                        runs: Option::Some(0x28f)
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_invariant'>(data.span());
+
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn config_wrapper_work_with_target_param() {
+    let item = TokenStream::new(FN_WITH_TARGET_PARAM.into());
+    let args = TokenStream::new("(target: setup)".into());
+
+    let result = invariant_config(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    let item = result.token_stream;
+    let args = TokenStream::new("(target: setup)".into());
+
+    let result = invariant_wrapper(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn check() {
+                if snforge_std::_internals::is_config_run() {
+                    let mut data = array![];
+
+                    snforge_std::_internals::config_types::InvariantConfig {
+                        seed: Option::None,
+                        runs: Option::None
+                    }
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_invariant'>(data.span());
+
+                    check_actual_body(core::num::traits::Zero::zero());
+
+                    return;
+                }
+                let target = setup();
+                check_actual_body(target);
+
+                let mut step: u32 = 0;
+                while step != 0xf {
+                    snforge_std::_internals::invariant_call(target);
+                    check_actual_body(target);
+                    step += 1;
+                };
+            }
+
+            #[__internal_config_statement]
+            fn check_actual_body(target: ContractAddress) {
+                assert(true, 'ok');
+            }
+        ",
+    );
+}
+
+#[test]
+fn wrapper_uses_depth() {
+    let item = TokenStream::new(FN_WITH_TARGET_PARAM.into());
+    let args = TokenStream::new("(target: setup, depth: 100)".into());
+
+    let result = invariant_wrapper(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn check() {
+                if snforge_std::_internals::is_config_run() {
+                    check_actual_body(core::num::traits::Zero::zero());
+
+                    return;
+                }
+                let target = setup();
+                check_actual_body(target);
+
+                let mut step: u32 = 0;
+                while step != 0x64 {
+                    snforge_std::_internals::invariant_call(target);
+                    check_actual_body(target);
+                    step += 1;
+                };
+            }
+
+            #[__internal_config_statement]
+            fn check_actual_body(target: ContractAddress) {
+                assert(true, 'ok');
+            }
+        ",
+    );
+}
+
+#[test]
+fn fail_without_target() {
+    let item = TokenStream::new(FN_WITH_TARGET_PARAM.into());
+    let args = TokenStream::new("(runs: 10)".into());
+
+    let result = invariant_wrapper(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error("<target> argument is missing")],
+    );
+}
+
+#[test]
+fn fail_with_zero_depth() {
+    let item = TokenStream::new(FN_WITH_TARGET_PARAM.into());
+    let args = TokenStream::new("(target: setup, depth: 0)".into());
+
+    let result = invariant_wrapper(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[invariant] depth must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn fail_without_target_param() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(target: setup)".into());
+
+    let result = invariant_wrapper(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[invariant] function must have a single parameter with the target contract address",
+        )],
+    );
+}
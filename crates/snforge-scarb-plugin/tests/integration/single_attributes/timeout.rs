@@ -0,0 +1,88 @@
+use crate::utils::{assert_diagnostics, assert_output, EMPTY_FN};
+use cairo_lang_macro::{Diagnostic, TokenStream};
+use snforge_scarb_plugin::attributes::timeout::timeout;
+
+#[test]
+fn works_with_seconds() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(30)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        "
+            fn empty_fn() {
+                if snforge_std::_internals::is_config_run() {
+                    let mut data = array![];
+                    snforge_std::_internals::config_types::TimeoutConfig { seconds: 0x1e }
+                    .serialize(ref data);
+                    starknet::testing::cheatcode::<'set_config_timeout'>(data.span());
+                    return;
+                }
+            }
+        ",
+    );
+}
+
+#[test]
+fn fails_without_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(String::new());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] expected arguments: 1, got: 0",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_named_args() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(seconds: 30)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] can be used with unnamed arguments only",
+        )],
+    );
+}
+
+#[test]
+fn fails_with_zero() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(0)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] seconds must be greater than 0",
+        )],
+    );
+}
+
+#[test]
+fn handles_number_overflow() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new("(18446744073709551616)".into());
+
+    let result = timeout(args, item);
+
+    assert_diagnostics(
+        &result,
+        &[Diagnostic::error(
+            "#[timeout] seconds it too large (max permissible value is 18446744073709551615)",
+        )],
+    );
+}
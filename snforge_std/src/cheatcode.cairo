@@ -39,3 +39,9 @@ pub fn save_fuzzer_arg<T, +core::fmt::Debug<T>>(input: @T) {
     input.serialize(ref serialized);
     execute_cheatcode::<'save_fuzzer_arg'>(serialized.span());
 }
+
+// Do not use this function directly.
+// It is an internal part of the snforge invariant testing logic used by macros.
+pub fn invariant_call(target: starknet::ContractAddress) {
+    execute_cheatcode::<'invariant_call'>(array![target.into()].span());
+}
@@ -2,6 +2,7 @@ use starknet::{ContractAddress, ClassHash, contract_address_const};
 use super::cheatcode::execute_cheatcode_and_deserialize;
 
 pub mod events;
+pub mod calls;
 pub mod l1_handler;
 pub mod contract_class;
 pub mod storage;
@@ -11,6 +12,13 @@ pub mod message_to_l1;
 pub mod generate_random_felt;
 pub mod generate_arg;
 pub mod block_hash;
+pub mod advance_block;
+pub mod gas_prices;
+pub mod state_snapshot;
+pub mod expect_call;
+pub mod expect_revert;
+pub mod account;
+pub mod storage_access;
 
 /// Enum used to specify how long the target should be cheated for.
 #[derive(Copy, Drop, Serde, PartialEq, Clone, Debug)]
@@ -33,6 +41,24 @@ pub fn test_address() -> ContractAddress {
     contract_address_const::<469394814521890341860918960550914>()
 }
 
+/// Calldata of calls affected by the mock.
+/// When many mocks of the function match the call, the most specific one is used.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub enum MockCalldata {
+    /// Any calldata
+    Any,
+    /// Calldata equal to the given one
+    Exact: Array<felt252>,
+    /// Calldata starting with the given values
+    Prefix: Array<felt252>,
+}
+
+#[derive(Drop, Serde)]
+enum MockResult {
+    Success: Array<felt252>,
+    Panic: Array<felt252>,
+}
+
 /// Mocks contract call to a `function_selector` of a contract at the given address, for `n_times`
 /// first calls that are made to the contract.
 /// A call to function `function_selector` will return data provided in `ret_data` argument.
@@ -48,20 +74,7 @@ pub fn test_address() -> ContractAddress {
 pub fn mock_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
     contract_address: ContractAddress, function_selector: felt252, ret_data: T, n_times: u32,
 ) {
-    assert!(n_times > 0, "cannot `mock_call` 0 times, `n_times` argument must be greater than 0");
-
-    let contract_address_felt: felt252 = contract_address.into();
-    let mut inputs = array![contract_address_felt, function_selector];
-
-    CheatSpan::TargetCalls(n_times.try_into().expect('`n_times` must be > 0'))
-        .serialize(ref inputs);
-
-    let mut ret_data_arr = ArrayTrait::new();
-    ret_data.serialize(ref ret_data_arr);
-
-    ret_data_arr.serialize(ref inputs);
-
-    execute_cheatcode_and_deserialize::<'mock_call', ()>(inputs.span());
+    mock_call_when(contract_address, function_selector, MockCalldata::Any, ret_data, n_times);
 }
 
 
@@ -74,21 +87,138 @@ pub fn mock_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct
 pub fn start_mock_call<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
     contract_address: ContractAddress, function_selector: felt252, ret_data: T,
 ) {
-    let contract_address_felt: felt252 = contract_address.into();
-    let mut inputs = array![contract_address_felt, function_selector];
+    start_mock_call_when(contract_address, function_selector, MockCalldata::Any, ret_data);
+}
+
+/// Mocks contract call to a `function_selector` of a contract at the given address, for `n_times`
+/// first calls with calldata matching `calldata`.
+/// See `mock_call` for comprehensive definition of how it can be used.
+/// - `contract_address` - target contract address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of calls to mock
+/// - `ret_data` - data to return by the function `function_selector`
+/// - `n_times` - number of calls to mock the function for
+pub fn mock_call_when<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: MockCalldata,
+    ret_data: T,
+    n_times: u32,
+) {
+    assert!(n_times > 0, "cannot `mock_call` 0 times, `n_times` argument must be greater than 0");
+
+    let mut ret_data_arr = ArrayTrait::new();
+    ret_data.serialize(ref ret_data_arr);
 
-    CheatSpan::Indefinite.serialize(ref inputs);
+    add_mock(
+        contract_address,
+        function_selector,
+        calldata,
+        CheatSpan::TargetCalls(n_times.try_into().expect('`n_times` must be > 0')),
+        MockResult::Success(ret_data_arr),
+    );
+}
 
+/// Mocks contract call to a function of a contract at the given address with calldata matching
+/// `calldata`, indefinitely.
+/// See `mock_call` for comprehensive definition of how it can be used.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of calls to mock
+/// - `ret_data` - data to be returned by the function
+pub fn start_mock_call_when<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: MockCalldata,
+    ret_data: T,
+) {
     let mut ret_data_arr = ArrayTrait::new();
     ret_data.serialize(ref ret_data_arr);
 
-    ret_data_arr.serialize(ref inputs);
+    add_mock(
+        contract_address,
+        function_selector,
+        calldata,
+        CheatSpan::Indefinite,
+        MockResult::Success(ret_data_arr),
+    );
+}
+
+/// Makes `n_times` first calls to a `function_selector` of a contract at the given address, with
+/// calldata matching `calldata`, panic with `panic_data`.
+/// The panic can be caught by the caller like a panic of the called contract.
+/// See `mock_call` for comprehensive definition of how it can be used.
+/// - `contract_address` - target contract address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of calls to mock
+/// - `panic_data` - data the function `function_selector` panics with
+/// - `n_times` - number of calls to mock the function for
+pub fn mock_call_revert(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: MockCalldata,
+    panic_data: Array<felt252>,
+    n_times: u32,
+) {
+    assert!(
+        n_times > 0,
+        "cannot `mock_call_revert` 0 times, `n_times` argument must be greater than 0",
+    );
+
+    add_mock(
+        contract_address,
+        function_selector,
+        calldata,
+        CheatSpan::TargetCalls(n_times.try_into().expect('`n_times` must be > 0')),
+        MockResult::Panic(panic_data),
+    );
+}
+
+/// Makes calls to a function of a contract at the given address, with calldata matching
+/// `calldata`, panic with `panic_data`, indefinitely.
+/// See `mock_call_revert` for comprehensive definition of how it can be used.
+/// - `contract_address` - targeted contracts' address
+/// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
+/// macro)
+/// - `calldata` - calldata of calls to mock
+/// - `panic_data` - data the function panics with
+pub fn start_mock_call_revert(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: MockCalldata,
+    panic_data: Array<felt252>,
+) {
+    add_mock(
+        contract_address,
+        function_selector,
+        calldata,
+        CheatSpan::Indefinite,
+        MockResult::Panic(panic_data),
+    );
+}
+
+fn add_mock(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: MockCalldata,
+    span: CheatSpan,
+    result: MockResult,
+) {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, function_selector];
+
+    calldata.serialize(ref inputs);
+    span.serialize(ref inputs);
+    result.serialize(ref inputs);
 
     execute_cheatcode_and_deserialize::<'mock_call', ()>(inputs.span());
 }
 
-/// Cancels the `mock_call` / `start_mock_call` for the function with given name and contract
-/// address.
+/// Cancels all mocks of the function with given name and contract address, regardless of the
+/// calldata they apply to.
 /// - `contract_address` - targeted contracts' address
 /// - `function_selector` - hashed name of the target function (can be obtained with `selector!`
 /// macro)
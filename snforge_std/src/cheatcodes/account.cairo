@@ -0,0 +1,36 @@
+use starknet::{ContractAddress, SyscallResult};
+use starknet::account::Call;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Options of the INVOKE transaction sent by `invoke_as_account`.
+#[derive(Drop, Serde, Clone, Debug, PartialEq, Default)]
+pub struct InvokeOptions {
+    /// Hash of the transaction, seen by the account in the transaction info
+    pub transaction_hash: felt252,
+    /// Signature of the transaction, seen by the account in the transaction info
+    pub signature: Array<felt252>,
+    /// If `true`, failures of `__validate__` of the account are ignored when `is_valid_signature`
+    /// of the account rejects the signature, so only the signature is not verified
+    pub skip_signature_verification: bool,
+}
+
+/// Sends an INVOKE transaction with the given calls from a deployed account contract.
+/// The transaction runs through `__validate__` and `__execute__` entry points of the account, called
+/// by the protocol (caller address 0), with version 3 transaction info containing the current nonce
+/// of the account. The nonce is incremented once the transaction is validated.
+/// - `account` - address of the account sending the transaction
+/// - `calls` - calls passed to `__validate__` and `__execute__` of the account
+/// - `options` - transaction hash, signature and signature verification settings
+/// Returns data returned by `__execute__` or panic data if validation or execution failed
+pub fn invoke_as_account(
+    account: ContractAddress, calls: Array<Call>, options: InvokeOptions,
+) -> SyscallResult<Span<felt252>> {
+    let mut execute_calldata = array![];
+    calls.serialize(ref execute_calldata);
+
+    let mut inputs = array![account.into()];
+    execute_calldata.serialize(ref inputs);
+    options.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'invoke_as_account'>(inputs.span())
+}
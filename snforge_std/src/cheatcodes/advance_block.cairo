@@ -0,0 +1,25 @@
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Moves the block forward by `blocks` blocks, each produced `block_time` seconds after the
+/// previous one.
+/// The block number and timestamp are changed globally, like with
+/// `start_cheat_block_number_global` and `start_cheat_block_timestamp_global`.
+/// - `blocks` - number of blocks which pass
+/// - `block_time` - time between consecutive blocks, in seconds
+pub fn roll(blocks: u64, block_time: u64) {
+    advance_block(blocks, block_time);
+}
+
+/// Moves to the next block, produced `seconds` after the current one.
+/// The block number and timestamp are changed globally, like with
+/// `start_cheat_block_number_global` and `start_cheat_block_timestamp_global`.
+/// - `seconds` - time which passes, in seconds
+pub fn warp(seconds: u64) {
+    advance_block(1, seconds);
+}
+
+fn advance_block(blocks: u64, block_time: u64) {
+    execute_cheatcode_and_deserialize::<
+        'advance_block', (),
+    >(array![blocks.into(), block_time.into()].span());
+}
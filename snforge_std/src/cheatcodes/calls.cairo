@@ -0,0 +1,158 @@
+use starknet::{ClassHash, ContractAddress};
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+use crate::trace::{CallResult, CallType};
+
+/// Creates `CallSpy` instance that spies on all calls made by contracts after its creation,
+/// including library calls.
+pub fn spy_calls() -> CallSpy {
+    execute_cheatcode_and_deserialize::<'spy_calls'>(array![].span())
+}
+
+/// A call made during the test, along with its result.
+#[derive(Drop, Clone, Serde, Debug, PartialEq)]
+pub struct SpiedCall {
+    /// Address that the call originates from
+    pub caller_address: ContractAddress,
+    /// Address of the contract whose storage is used by the call
+    pub contract_address: ContractAddress,
+    /// Class whose code is executed by a library call, `None` for regular calls
+    pub class_hash: Option<ClassHash>,
+    /// Hashed selector of the invoked function
+    pub entry_point_selector: felt252,
+    pub calldata: Array<felt252>,
+    pub call_type: CallType,
+    pub result: CallResult,
+}
+
+/// A call spy structure allowing to get calls made only after its creation.
+#[derive(Drop, Serde)]
+pub struct CallSpy {
+    call_offset: usize,
+}
+
+/// A wrapper structure on an array of calls to handle filtering smoothly.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct Calls {
+    pub calls: Array<SpiedCall>,
+}
+
+pub trait CallSpyTrait {
+    /// Gets all calls given [`CallSpy`] spies for, in the order they were made.
+    fn get_calls(ref self: CallSpy) -> Calls;
+}
+
+impl CallSpyTraitImpl of CallSpyTrait {
+    fn get_calls(ref self: CallSpy) -> Calls {
+        execute_cheatcode_and_deserialize::<'get_calls'>(array![self.call_offset.into()].span())
+    }
+}
+
+pub trait CallsFilterTrait {
+    /// Filter calls made by a given [`ContractAddress`].
+    fn made_by(self: @Calls, caller_address: ContractAddress) -> Calls;
+    /// Filter calls using storage of a given [`ContractAddress`].
+    fn made_to(self: @Calls, contract_address: ContractAddress) -> Calls;
+    /// Filter library calls executing code of a given [`ClassHash`].
+    fn library_calls_to(self: @Calls, class_hash: ClassHash) -> Calls;
+}
+
+impl CallsFilterTraitImpl of CallsFilterTrait {
+    fn made_by(self: @Calls, caller_address: ContractAddress) -> Calls {
+        let mut new_calls = array![];
+
+        for call in self.calls.span() {
+            if *call.caller_address == caller_address {
+                new_calls.append(call.clone());
+            };
+        };
+        Calls { calls: new_calls }
+    }
+
+    fn made_to(self: @Calls, contract_address: ContractAddress) -> Calls {
+        let mut new_calls = array![];
+
+        for call in self.calls.span() {
+            if *call.contract_address == contract_address {
+                new_calls.append(call.clone());
+            };
+        };
+        Calls { calls: new_calls }
+    }
+
+    fn library_calls_to(self: @Calls, class_hash: ClassHash) -> Calls {
+        let mut new_calls = array![];
+
+        for call in self.calls.span() {
+            if *call.class_hash == Option::Some(class_hash) {
+                new_calls.append(call.clone());
+            };
+        };
+        Calls { calls: new_calls }
+    }
+}
+
+/// Allows to assert the expected calls (or lack thereof), in the scope of [`CallSpy`] structure.
+pub trait CallSpyAssertionsTrait {
+    /// Asserts a call to `entry_point_selector` with `calldata` was made using storage of
+    /// `contract_address`.
+    fn assert_called(
+        ref self: CallSpy,
+        contract_address: ContractAddress,
+        entry_point_selector: felt252,
+        calldata: Span<felt252>,
+    );
+    /// Asserts no call to `entry_point_selector` with `calldata` was made using storage of
+    /// `contract_address`.
+    fn assert_not_called(
+        ref self: CallSpy,
+        contract_address: ContractAddress,
+        entry_point_selector: felt252,
+        calldata: Span<felt252>,
+    );
+}
+
+impl CallSpyAssertionsTraitImpl of CallSpyAssertionsTrait {
+    fn assert_called(
+        ref self: CallSpy,
+        contract_address: ContractAddress,
+        entry_point_selector: felt252,
+        calldata: Span<felt252>,
+    ) {
+        if !is_called(@self.get_calls(), contract_address, entry_point_selector, calldata) {
+            let contract_address: felt252 = contract_address.into();
+            panic!(
+                "Call with matching selector and calldata was not made to {}", contract_address,
+            );
+        }
+    }
+
+    fn assert_not_called(
+        ref self: CallSpy,
+        contract_address: ContractAddress,
+        entry_point_selector: felt252,
+        calldata: Span<felt252>,
+    ) {
+        if is_called(@self.get_calls(), contract_address, entry_point_selector, calldata) {
+            let contract_address: felt252 = contract_address.into();
+            panic!("Call with matching selector and calldata was made to {}", contract_address);
+        }
+    }
+}
+
+fn is_called(
+    calls: @Calls,
+    contract_address: ContractAddress,
+    entry_point_selector: felt252,
+    calldata: Span<felt252>,
+) -> bool {
+    let mut is_called = false;
+    for call in calls.calls.span() {
+        if *call.contract_address == contract_address
+            && *call.entry_point_selector == entry_point_selector
+            && call.calldata.span() == calldata {
+            is_called = true;
+            break;
+        };
+    };
+    is_called
+}
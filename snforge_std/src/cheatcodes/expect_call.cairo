@@ -0,0 +1,24 @@
+use starknet::ContractAddress;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Expects the test to call the function of the contract with exactly the given calldata `count`
+/// times. Calls made after registering the expectation, both directly from the test and from other
+/// contracts, are counted.
+/// Expectations are verified once the test finishes as expected, and the test fails listing the
+/// calls that were actually made to the function if any of them is not met.
+/// - `contract_address` - address of the contract expected to be called
+/// - `function_selector` - selector of the function expected to be called
+/// - `calldata` - serialized arguments of the expected call
+/// - `count` - number of expected calls, `0` expects the call not to be made at all
+pub fn expect_call(
+    contract_address: ContractAddress,
+    function_selector: felt252,
+    calldata: Array<felt252>,
+    count: u32,
+) {
+    let mut inputs = array![contract_address.into(), function_selector];
+    calldata.serialize(ref inputs);
+    inputs.append(count.into());
+
+    execute_cheatcode_and_deserialize::<'expect_call', ()>(inputs.span());
+}
@@ -0,0 +1,15 @@
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Expects the next call made by the test to revert with `expected_panic_data`.
+/// If the call reverts with the expected data, it returns successfully with no return data and the
+/// test continues. If it succeeds or reverts with other data, the test fails.
+/// Only calls made directly from the test are affected, e.g. through a dispatcher.
+/// Calls to functions returning values fail the test, as their dispatchers can't decode the missing return data,
+/// use a safe dispatcher to check reverts of such functions.
+/// - `expected_panic_data` - panic data the call is expected to revert with
+pub fn expect_revert(expected_panic_data: Array<felt252>) {
+    let mut inputs = array![];
+    expected_panic_data.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'expect_revert', ()>(inputs.span());
+}
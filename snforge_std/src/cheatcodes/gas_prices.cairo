@@ -0,0 +1,23 @@
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Prices of the block gas, in FRI per unit of gas
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub struct GasPrices {
+    pub l1_gas_price: u128,
+    pub l1_data_gas_price: u128,
+    pub l2_gas_price: u128,
+}
+
+/// Changes the gas prices of the block the test runs in, used for calculating the fee of the test.
+/// - `gas_prices` - new gas prices, all of them must be greater than 0
+pub fn cheat_gas_prices(gas_prices: GasPrices) {
+    let mut inputs = array![];
+    gas_prices.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'cheat_gas_prices', ()>(inputs.span());
+}
+
+/// Restores the gas prices of the block the test runs in.
+pub fn stop_cheat_gas_prices() {
+    execute_cheatcode_and_deserialize::<'stop_cheat_gas_prices', ()>(array![].span());
+}
@@ -0,0 +1,24 @@
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Identifier of a snapshot of the test state, returned by `snapshot_state`.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct SnapshotId {
+    id: usize,
+}
+
+/// Saves the current state of the test and returns identifier of the snapshot.
+/// The snapshot captures storage, nonces, deployed contracts, declared classes, active cheats,
+/// mocked calls and events and messages to L1 collected by spies.
+pub fn snapshot_state() -> SnapshotId {
+    execute_cheatcode_and_deserialize::<'snapshot_state'>(array![].span())
+}
+
+/// Restores the state of the test saved in the given snapshot.
+/// The snapshot is kept, so the state can be reverted to it multiple times, e.g. to check different
+/// scenarios starting from the same setup.
+/// - `snapshot_id` - identifier of the snapshot returned by `snapshot_state`
+pub fn revert_to_snapshot(snapshot_id: SnapshotId) {
+    execute_cheatcode_and_deserialize::<
+        'revert_to_snapshot', (),
+    >(array![snapshot_id.id.into()].span());
+}
@@ -1,4 +1,5 @@
-use starknet::{ContractAddress, StorageAddress};
+use starknet::storage_access::storage_base_address_from_felt252;
+use starknet::{ContractAddress, StorageAddress, SyscallResultTrait};
 use crate::cheatcode::execute_cheatcode_and_deserialize;
 use crate::cheatcodes::execution_info::contract_address::{
     start_cheat_contract_address, stop_cheat_contract_address,
@@ -62,6 +63,38 @@ pub fn map_entry_address(map_selector: felt252, keys: Span<felt252>) -> felt252
     execute_cheatcode_and_deserialize::<'map_entry_address'>(inputs.span())
 }
 
+/// Calculates the address of a storage variable from its path, e.g. `balances[0x123].amount`.
+/// - `path` - name of the variable, followed by `.member` segments for members of storage nodes
+/// and `[key_part, ...]` segments for entries of `Map`s (with serialized keys)
+/// and elements of `Vec`s
+pub fn storage_path_address(path: ByteArray) -> felt252 {
+    let mut inputs = array![];
+    path.serialize(ref inputs);
+    execute_cheatcode_and_deserialize::<'storage_path_address'>(inputs.span())
+}
+
+/// Reads a value of type `T` from `target` contract's storage, at the address of the storage path.
+/// - `target` - address of the contract, which storage you want to read
+/// - `path` - storage path of the value, see `storage_path_address`
+pub fn load_at_path<T, +starknet::Store<T>>(target: ContractAddress, path: ByteArray) -> T {
+    let address = storage_base_address_from_felt252(storage_path_address(path));
+    start_cheat_contract_address(target);
+    let value = starknet::Store::<T>::read(0, address).unwrap_syscall();
+    stop_cheat_contract_address();
+    value
+}
+
+/// Writes a value of type `T` to `target` contract's storage, at the address of the storage path.
+/// - `target` - address of the contract, which storage you want to modify
+/// - `path` - storage path of the value, see `storage_path_address`
+/// - `value` - value which will be written
+pub fn store_at_path<T, +starknet::Store<T>>(target: ContractAddress, path: ByteArray, value: T) {
+    let address = storage_base_address_from_felt252(storage_path_address(path));
+    start_cheat_contract_address(target);
+    starknet::Store::<T>::write(0, address, value).unwrap_syscall();
+    stop_cheat_contract_address();
+}
+
 pub fn interact_with_state<F, +Drop<F>, impl func: core::ops::FnOnce<F, ()>, +Drop<func::Output>>(
     contract_address: ContractAddress, f: F,
 ) -> func::Output {
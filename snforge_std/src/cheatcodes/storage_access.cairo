@@ -0,0 +1,31 @@
+use starknet::ContractAddress;
+use crate::cheatcode::execute_cheatcode_and_deserialize;
+
+/// Storage keys read and written by a contract, in order of their first access.
+#[derive(Drop, Serde, Clone, Debug, PartialEq)]
+pub struct StorageAccesses {
+    pub reads: Array<felt252>,
+    pub writes: Array<felt252>,
+}
+
+/// Starts recording storage keys read and written by the given contract.
+/// Accesses recorded for the contract before are discarded.
+/// - `target` - address of the contract whose storage accesses are recorded
+pub fn start_record_storage_access(target: ContractAddress) {
+    execute_cheatcode_and_deserialize::<
+        'start_record_storage_access', (),
+    >(array![target.into()].span());
+}
+
+/// Stops recording storage accesses of the given contract. Accesses recorded so far are kept.
+/// - `target` - address of the contract whose storage accesses were recorded
+pub fn stop_record_storage_access(target: ContractAddress) {
+    execute_cheatcode_and_deserialize::<
+        'stop_record_storage_access', (),
+    >(array![target.into()].span());
+}
+
+/// Returns storage accesses recorded for every contract passed to `start_record_storage_access`.
+pub fn get_storage_accesses() -> Array<(ContractAddress, StorageAccesses)> {
+    execute_cheatcode_and_deserialize::<'get_storage_accesses'>(array![].span())
+}
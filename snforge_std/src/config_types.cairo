@@ -43,6 +43,12 @@ pub struct FuzzerConfig {
     pub seed: Option<felt252>,
 }
 
+#[derive(Drop, Serde)]
+pub struct InvariantConfig {
+    pub runs: Option<felt252>,
+    pub seed: Option<felt252>,
+}
+
 #[derive(Drop, Serde)]
 pub enum Expected {
     ShortString: felt252,
@@ -65,3 +71,8 @@ pub struct IgnoreConfig {
 pub struct PredeployedContractsConfig {
     pub is_disabled: bool,
 }
+
+#[derive(Drop, Serde)]
+pub struct TimeoutConfig {
+    pub seconds: felt252,
+}
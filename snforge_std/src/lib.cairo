@@ -18,6 +18,10 @@ pub use cheatcodes::events::EventsFilterTrait;
 pub use cheatcodes::events::spy_events;
 pub use cheatcodes::events::IsEmitted;
 
+pub use cheatcodes::calls::{
+    spy_calls, SpiedCall, CallSpy, CallSpyTrait, Calls, CallsFilterTrait, CallSpyAssertionsTrait,
+};
+
 pub use cheatcodes::message_to_l1::{
     spy_messages_to_l1, MessageToL1, MessageToL1Spy, MessageToL1SpyTrait, MessageToL1FilterTrait,
     MessageToL1SpyAssertionsTrait,
@@ -26,6 +30,9 @@ pub use cheatcodes::message_to_l1::{
 pub use cheatcodes::storage::store;
 pub use cheatcodes::storage::load;
 pub use cheatcodes::storage::map_entry_address;
+pub use cheatcodes::storage::storage_path_address;
+pub use cheatcodes::storage::load_at_path;
+pub use cheatcodes::storage::store_at_path;
 pub use cheatcodes::storage::interact_with_state;
 
 pub use cheatcodes::erc20::set_balance;
@@ -40,6 +47,11 @@ pub use cheatcodes::test_address;
 pub use cheatcodes::test_selector;
 pub use cheatcodes::mock_call;
 pub use cheatcodes::start_mock_call;
+pub use cheatcodes::mock_call_when;
+pub use cheatcodes::start_mock_call_when;
+pub use cheatcodes::mock_call_revert;
+pub use cheatcodes::start_mock_call_revert;
+pub use cheatcodes::MockCalldata;
 pub use cheatcodes::stop_mock_call;
 pub use cheatcodes::replace_bytecode;
 
@@ -137,6 +149,28 @@ pub use cheatcodes::block_hash::stop_cheat_block_hash;
 pub use cheatcodes::block_hash::stop_cheat_block_hash_global;
 pub use cheatcodes::block_hash::start_cheat_block_hash;
 
+pub use cheatcodes::advance_block::roll;
+pub use cheatcodes::advance_block::warp;
+
+pub use cheatcodes::gas_prices::cheat_gas_prices;
+pub use cheatcodes::gas_prices::stop_cheat_gas_prices;
+pub use cheatcodes::gas_prices::GasPrices;
+
+pub use cheatcodes::state_snapshot::snapshot_state;
+pub use cheatcodes::state_snapshot::revert_to_snapshot;
+pub use cheatcodes::state_snapshot::SnapshotId;
+
+pub use cheatcodes::expect_call::expect_call;
+pub use cheatcodes::expect_revert::expect_revert;
+
+pub use cheatcodes::account::invoke_as_account;
+pub use cheatcodes::account::InvokeOptions;
+
+pub use cheatcodes::storage_access::start_record_storage_access;
+pub use cheatcodes::storage_access::stop_record_storage_access;
+pub use cheatcodes::storage_access::get_storage_accesses;
+pub use cheatcodes::storage_access::StorageAccesses;
+
 pub mod fuzzable;
 
 pub mod fs;
@@ -159,6 +193,7 @@ pub mod _internals {
 
     pub use cheatcode::is_config_run;
     pub use cheatcode::save_fuzzer_arg;
+    pub use cheatcode::invariant_call;
 
     pub use super::config_types;
 }